@@ -1,24 +1,63 @@
 use super::super::get_context;
-use crate::models::http_error::HttpErrorKind;
-use async_graphql::{Context, Object};
+use crate::{models::http_error::HttpErrorKind, utils::read_upload::read_upload};
+use async_graphql::{Context, Object, Upload};
 use qbittorrent_api::AddTorrentOptions;
+use utils::magnet::torrent_name;
 
 #[derive(Default)]
 pub struct AddTorrentsMutation;
 
 #[Object]
 impl AddTorrentsMutation {
+    /// Only supported with qBittorrent, without `client` the routing rules pick a qBittorrent client from the first url
     async fn add_torrents<'ctx>(
         &self,
         context: &Context<'ctx>,
         urls: Vec<String>,
         options: Option<AddTorrentOptions>,
+        client: Option<String>,
+        size: Option<u64>,
     ) -> Result<String, HttpErrorKind> {
+        let name = urls
+            .first()
+            .and_then(|url| torrent_name(url).ok())
+            .unwrap_or_default();
+
         get_context(context)
-            .qbittorrent_client()
+            .route_qbittorrent(client.as_deref(), &name, size)?
+            .qbittorrent()?
             .add_torrents(&urls, options.unwrap_or_default())
             .await?;
 
         Ok("Ok".into())
     }
+
+    /// Adds `.torrent` files sent as a GraphQL multipart upload, and optionally urls
+    async fn upload_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        files: Vec<Upload>,
+        #[graphql(default)] urls: Vec<String>,
+        options: Option<AddTorrentOptions>,
+        client: Option<String>,
+        size: Option<u64>,
+    ) -> Result<String, HttpErrorKind> {
+        let files = files
+            .iter()
+            .map(|file| read_upload(context, file))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let name = files
+            .first()
+            .map(|file| file.filename().to_owned())
+            .unwrap_or_default();
+
+        get_context(context)
+            .route_qbittorrent(client.as_deref(), &name, size)?
+            .qbittorrent()?
+            .upload_torrents(&urls, &files, options.unwrap_or_default())
+            .await?;
+
+        Ok("Ok".into())
+    }
 }
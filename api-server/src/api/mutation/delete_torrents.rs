@@ -7,16 +7,40 @@ pub struct DeleteTorrentsMutation;
 
 #[Object]
 impl DeleteTorrentsMutation {
+    /// Without `client`, each torrent is deleted from the download client which has it
     async fn delete_torrents<'ctx>(
         &self,
         context: &Context<'ctx>,
         hashes: Vec<String>,
         delete_files: bool,
+        client: Option<String>,
     ) -> Result<String, HttpErrorKind> {
-        get_context(context)
-            .qbittorrent_client()
-            .delete_torrents(hashes, delete_files)
-            .await?;
+        let context = get_context(context);
+
+        if client.is_some() {
+            context
+                .download_client(client.as_deref())?
+                .client()
+                .delete_torrents(hashes, delete_files)
+                .await?;
+
+            return Ok("Ok".into());
+        }
+
+        let torrents = context.torrents().await;
+
+        for hash in hashes {
+            let torrent = torrents
+                .iter()
+                .find(|torrent| torrent.get_hash() == &hash)
+                .ok_or_else(|| HttpErrorKind::TorrentNotFound(hash.clone()))?;
+
+            context
+                .download_client(Some(torrent.get_client()))?
+                .client()
+                .delete_torrents(vec![hash], delete_files)
+                .await?;
+        }
 
         Ok("Ok".into())
     }
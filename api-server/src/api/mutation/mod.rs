@@ -1,3 +1,10 @@
 pub mod add_torrents;
 pub mod delete_torrents;
+pub mod preferences;
+pub mod rss;
+pub mod speed_limits;
+pub mod torrent_control;
+pub mod torrent_files;
+pub mod torrent_tags;
+pub mod torrent_trackers;
 pub mod track_movie;
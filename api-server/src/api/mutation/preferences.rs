@@ -0,0 +1,32 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Json, Object};
+use qbittorrent_api::{Preferences, PreferencesUpdate};
+use serde_json::{Map, Value};
+
+#[derive(Default)]
+pub struct PreferencesMutation;
+
+#[Object]
+impl PreferencesMutation {
+    /// Updates the qBittorrent preferences, `other` sets preferences by their qBittorrent name
+    async fn set_preferences<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        #[graphql(default)] preferences: PreferencesUpdate,
+        other: Option<Json<Map<String, Value>>>,
+        client: Option<String>,
+    ) -> Result<Preferences, HttpErrorKind> {
+        let mut preferences = preferences;
+
+        for (key, value) in other.map(|other| other.0).unwrap_or_default() {
+            preferences.set_value(key, value);
+        }
+
+        let qb = get_context(context).qbittorrent_client(client.as_deref())?;
+
+        qb.set_preferences(&preferences).await?;
+
+        Ok(qb.preferences().await?)
+    }
+}
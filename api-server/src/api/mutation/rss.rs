@@ -0,0 +1,202 @@
+use super::super::get_context;
+use crate::{
+    models::http_error::HttpErrorKind,
+    utils::movie_rss_rule::{movie_must_contain, movie_rss_rule},
+};
+use async_graphql::{Context, Object};
+use movie_info::TmdbId;
+use qbittorrent_api::RssRule;
+
+#[derive(Default)]
+pub struct RssMutation;
+
+#[Object]
+impl RssMutation {
+    /// Folders in `path` are separated by `\`
+    async fn add_rss_folder<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .add_rss_folder(&path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn add_rss_feed<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        url: String,
+        path: Option<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .add_rss_feed(&url, path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn remove_rss_item<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .remove_rss_item(&path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn move_rss_item<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        item_path: String,
+        dest_path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .move_rss_item(&item_path, &dest_path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn refresh_rss_item<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        item_path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .refresh_rss_item(&item_path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Marks every article of the item without `articleId`
+    async fn mark_rss_as_read<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        item_path: String,
+        article_id: Option<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .mark_rss_as_read(&item_path, article_id)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Creates or replaces the rule `name`, fields of an existing rule the input has no field for are kept
+    async fn set_rss_rule<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        name: String,
+        mut rule: RssRule,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        let qb = get_context(context).qbittorrent_client(client.as_deref())?;
+
+        if let Some(existing) = qb.rss_rule(&name).await? {
+            rule.merge_other(existing.get_other().clone());
+        }
+
+        qb.set_rss_rule(&name, &rule).await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn rename_rss_rule<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        name: String,
+        new_name: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rename_rss_rule(&name, &new_name)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn remove_rss_rule<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        name: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .remove_rss_rule(&name)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Creates a rule named after the movie which downloads its first match for movie tracking, on every feed when `feeds` is empty.
+    ///
+    /// `mustContain` defaults to the words of the title and the year. Returns the name of the rule.
+    async fn add_movie_rss_rule<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        tmdb: TmdbId,
+        #[graphql(default)] feeds: Vec<String>,
+        must_contain: Option<String>,
+        #[graphql(default)] must_not_contain: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        let context = get_context(context);
+        let instance = context.qbittorrent_instance(client.as_deref())?;
+        let qb = instance.qbittorrent()?;
+
+        let movie = context
+            .movie_info_client()
+            .from_tmdb(tmdb)
+            .await?
+            .ok_or_else(|| HttpErrorKind::tmdb_not_found(tmdb))?;
+
+        let feeds = if feeds.is_empty() {
+            let items = qb.rss_items(false).await?;
+            items
+                .get_feeds()
+                .iter()
+                .map(|feed| feed.get_url().to_owned())
+                .collect()
+        } else {
+            feeds
+        };
+
+        let must_contain = must_contain
+            .unwrap_or_else(|| movie_must_contain(movie.get_title(), *movie.get_year()));
+        let rule = movie_rss_rule(
+            tmdb,
+            must_contain,
+            must_not_contain,
+            feeds,
+            instance.category(),
+        );
+
+        let name = movie.format();
+        qb.set_rss_rule(&name, &rule).await?;
+
+        context.enable_movie_tracking().await;
+
+        Ok(name)
+    }
+}
@@ -0,0 +1,56 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::SpeedLimits;
+
+#[derive(Default)]
+pub struct SpeedLimitsMutation;
+
+#[Object]
+impl SpeedLimitsMutation {
+    async fn set_alternative_speed_limits_enabled<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        enabled: bool,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_alternative_speed_limits_enabled(enabled)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Limits are in bytes per second, `0` removes the limit
+    async fn set_global_speed_limits<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        download: i64,
+        upload: i64,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_global_speed_limits(SpeedLimits::new(download, upload))
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Limits are in bytes per second, `0` removes the limit
+    async fn set_alternative_speed_limits<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        download: i64,
+        upload: i64,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_alternative_speed_limits(SpeedLimits::new(download, upload))
+            .await?;
+
+        Ok("Ok".into())
+    }
+}
@@ -0,0 +1,172 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::{QueuePosition, ShareLimits};
+
+#[derive(Default)]
+pub struct TorrentControlMutation;
+
+#[Object]
+impl TorrentControlMutation {
+    async fn pause_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .pause_torrents(hashes)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn resume_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .resume_torrents(hashes)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn set_force_start<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        value: bool,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_force_start(hashes, value)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn recheck_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .recheck_torrents(hashes)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn reannounce_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .reannounce_torrents(hashes)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn set_location<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        location: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_location(hashes, &location)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn set_queue_position<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        position: QueuePosition,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_queue_position(hashes, position)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn toggle_sequential_download<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .toggle_sequential_download(hashes)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Limit in bytes per second, 0 removes the limit
+    async fn set_download_limit<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        limit: i64,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_download_limit(hashes, limit)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Limit in bytes per second, 0 removes the limit
+    async fn set_upload_limit<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        limit: i64,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_upload_limit(hashes, limit)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn set_share_limits<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        limits: ShareLimits,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_share_limits(hashes, limits)
+            .await?;
+
+        Ok("Ok".into())
+    }
+}
@@ -0,0 +1,59 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::FilePriority;
+
+#[derive(Default)]
+pub struct TorrentFilesMutation;
+
+#[Object]
+impl TorrentFilesMutation {
+    /// `ids` are the indexes of the files in the torrent
+    async fn set_file_priority<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        ids: Vec<usize>,
+        priority: FilePriority,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .set_file_priority(&hash, &ids, priority)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn rename_file<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        old_path: String,
+        new_path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rename_file(&hash, &old_path, &new_path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn rename_folder<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        old_path: String,
+        new_path: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rename_folder(&hash, &old_path, &new_path)
+            .await?;
+
+        Ok("Ok".into())
+    }
+}
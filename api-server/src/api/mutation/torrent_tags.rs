@@ -0,0 +1,67 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+
+#[derive(Default)]
+pub struct TorrentTagsMutation;
+
+#[Object]
+impl TorrentTagsMutation {
+    async fn create_tags<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        tags: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .create_tags(tags)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn delete_tags<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        tags: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .delete_tags(tags)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn add_torrent_tags<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .add_tags(hashes, tags)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn remove_torrent_tags<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .remove_tags(hashes, tags)
+            .await?;
+
+        Ok("Ok".into())
+    }
+}
@@ -0,0 +1,55 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+
+#[derive(Default)]
+pub struct TorrentTrackersMutation;
+
+#[Object]
+impl TorrentTrackersMutation {
+    async fn add_trackers<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        urls: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .add_trackers(&hash, urls)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn edit_tracker<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        orig_url: String,
+        new_url: String,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .edit_tracker(&hash, &orig_url, &new_url)
+            .await?;
+
+        Ok("Ok".into())
+    }
+
+    async fn remove_trackers<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        hash: String,
+        urls: Vec<String>,
+        client: Option<String>,
+    ) -> Result<String, HttpErrorKind> {
+        get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .remove_trackers(&hash, urls)
+            .await?;
+
+        Ok("Ok".into())
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
     models::{context::ContextPointer, http_error::HttpErrorKind},
-    utils::track_movie::track_movie,
+    utils::{
+        read_upload::read_upload,
+        track_movie::{track_movie, track_movie_file, TrackRoute},
+    },
 };
-use async_graphql::{Context, Object};
+use async_graphql::{Context, Object, Upload};
 use movie_info::TmdbId;
 
 #[derive(Default)]
@@ -10,13 +13,37 @@ pub struct TrackMovieMutation;
 
 #[Object]
 impl TrackMovieMutation {
+    /// `url` is a magnet link or the url of a `.torrent` file, e.g. the `torrentUrl` of a search result.
+    ///
+    /// Without `client`, the download client is picked by the routing rules from the quality in the name and `size`
     async fn track_movie<'ctx>(
         &self,
         context: &Context<'ctx>,
         url: String,
         tmdb: TmdbId,
+        client: Option<String>,
+        size: Option<u64>,
     ) -> Result<String, HttpErrorKind> {
-        track_movie(context.data::<ContextPointer>().unwrap(), url, tmdb).await?;
+        let route = TrackRoute { client, size };
+
+        track_movie(context.data::<ContextPointer>().unwrap(), url, tmdb, route).await?;
+
+        Ok("Ok".into())
+    }
+
+    /// Same as `trackMovie` for a `.torrent` file sent as a GraphQL multipart upload
+    async fn track_movie_file<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        file: Upload,
+        tmdb: TmdbId,
+        client: Option<String>,
+        size: Option<u64>,
+    ) -> Result<String, HttpErrorKind> {
+        let file = read_upload(context, &file)?;
+        let route = TrackRoute { client, size };
+
+        track_movie_file(context.data::<ContextPointer>().unwrap(), file, tmdb, route).await?;
 
         Ok("Ok".into())
     }
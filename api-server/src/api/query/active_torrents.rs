@@ -1,7 +1,8 @@
 use super::super::get_context;
 use crate::{
-    models::http_error::HttpErrorKind, models::torrent_movie_info::TorrentMovieInfo,
-    utils::get_tmdb::get_tmdb,
+    models::download_torrent::DownloadTorrent, models::http_error::HttpErrorKind,
+    models::torrent_movie_info::TorrentMovieInfo, utils::get_tmdb::get_tmdb,
+    utils::torrent_log::add_log_messages,
 };
 use async_graphql::{ComplexObject, Context, Object, SimpleObject};
 use movie_info::TmdbId;
@@ -11,7 +12,7 @@ use std::collections::{HashMap, HashSet};
 #[derive(SimpleObject)]
 #[graphql(complex)]
 struct ActiveTorrentsResponse {
-    torrents: Vec<Torrent>,
+    torrents: Vec<DownloadTorrent>,
 }
 
 #[ComplexObject]
@@ -22,11 +23,7 @@ impl ActiveTorrentsResponse {
     ) -> Result<Vec<TorrentMovieInfo>, HttpErrorKind> {
         let mut torrent_movie_info: HashMap<TmdbId, TorrentMovieInfo> = HashMap::new();
 
-        let tmdb_ids: HashSet<TmdbId> = self
-            .torrents
-            .iter()
-            .filter_map(|torrent| get_tmdb(torrent.get_name()))
-            .collect();
+        let tmdb_ids: HashSet<TmdbId> = self.torrents.iter().filter_map(get_tmdb).collect();
 
         let movie_info = get_context(context)
             .movie_info_client()
@@ -35,7 +32,7 @@ impl ActiveTorrentsResponse {
 
         movie_info.iter().for_each(|info| {
             let torrents = self.torrents.iter().filter_map(|torrent| {
-                get_tmdb(torrent.get_name()).as_ref().and_then(|tmdb| {
+                get_tmdb(torrent).as_ref().and_then(|tmdb| {
                     if info.get_tmdb_id() == tmdb {
                         Some(torrent)
                     } else {
@@ -45,7 +42,7 @@ impl ActiveTorrentsResponse {
             });
 
             torrents.for_each(|torrent| {
-                if let Some(tmdb) = get_tmdb(torrent.get_name()) {
+                if let Some(tmdb) = get_tmdb(torrent) {
                     if let Some(info) = torrent_movie_info.get_mut(&tmdb) {
                         info.add_torrent(torrent.get_hash().to_owned());
                     } else {
@@ -68,15 +65,43 @@ pub struct ActiveTorrentsQuery;
 
 #[Object]
 impl ActiveTorrentsQuery {
+    /// The torrents of every reachable download client, optionally only those in `category` or of `client`.
+    ///
+    /// Errored qBittorrent torrents come with the recent log messages about them.
     async fn active_torrents<'ctx>(
         &self,
         context: &Context<'ctx>,
-        #[graphql(default)] params: GetTorrentsParameters,
+        category: Option<String>,
+        client: Option<String>,
     ) -> Result<ActiveTorrentsResponse, HttpErrorKind> {
-        let ctx = get_context(context);
+        let context = get_context(context);
+
+        let mut torrents = match client {
+            Some(client) => context.download_client(Some(&client))?.torrents().await?,
+            None => context.torrents().await,
+        };
+
+        if let Some(category) = category {
+            torrents.retain(|torrent| torrent.get_category() == &category);
+        }
 
-        let torrents = ctx.qbittorrent_client().torrents(&params).await?;
+        add_log_messages(context, &mut torrents).await;
 
         Ok(ActiveTorrentsResponse { torrents })
     }
+
+    /// The torrents with every qBittorrent field, only supported with qBittorrent
+    async fn qbittorrent_torrents<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        #[graphql(default)] params: GetTorrentsParameters,
+        client: Option<String>,
+    ) -> Result<Vec<Torrent>, HttpErrorKind> {
+        let torrents = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .torrents(&params)
+            .await?;
+
+        Ok(torrents)
+    }
 }
@@ -0,0 +1,29 @@
+use super::super::get_context;
+use crate::models::config::DownloadClientKind;
+use async_graphql::{Context, Object, SimpleObject};
+
+/// A configured download client, for choosing one explicitly in `trackMovie` and `addTorrents`
+#[derive(SimpleObject)]
+struct DownloadClientInfo {
+    name: String,
+    kind: DownloadClientKind,
+    category: String,
+}
+
+#[derive(Default)]
+pub struct DownloadClientsQuery;
+
+#[Object]
+impl DownloadClientsQuery {
+    async fn download_clients<'ctx>(&self, context: &Context<'ctx>) -> Vec<DownloadClientInfo> {
+        get_context(context)
+            .download_clients()
+            .iter()
+            .map(|client| DownloadClientInfo {
+                name: client.name().to_owned(),
+                kind: client.conf().settings().kind(),
+                category: client.category().to_owned(),
+            })
+            .collect()
+    }
+}
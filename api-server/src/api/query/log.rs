@@ -0,0 +1,54 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::{LogEntry, LogParameters, LogType, PeerLogEntry};
+
+#[derive(Default)]
+pub struct LogQuery;
+
+#[Object]
+impl LogQuery {
+    /// The messages of qBittorrent after `lastKnownId`, of every type when `types` is empty
+    async fn qbittorrent_log<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        #[graphql(default)] types: Vec<LogType>,
+        last_known_id: Option<i64>,
+        client: Option<String>,
+    ) -> Result<Vec<LogEntry>, HttpErrorKind> {
+        let mut params = LogParameters::default();
+
+        if !types.is_empty() {
+            params
+                .set_normal(types.contains(&LogType::Normal))
+                .set_info(types.contains(&LogType::Info))
+                .set_warning(types.contains(&LogType::Warning))
+                .set_critical(types.contains(&LogType::Critical));
+        }
+        if let Some(last_known_id) = last_known_id {
+            params.set_last_known_id(last_known_id);
+        }
+
+        let entries = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .main_log(&params)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// The peers qBittorrent blocked or banned after `lastKnownId`
+    async fn qbittorrent_peer_log<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        last_known_id: Option<i64>,
+        client: Option<String>,
+    ) -> Result<Vec<PeerLogEntry>, HttpErrorKind> {
+        let entries = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .peer_log(last_known_id)
+            .await?;
+
+        Ok(entries)
+    }
+}
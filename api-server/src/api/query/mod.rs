@@ -1,8 +1,16 @@
 pub mod active_torrents;
+pub mod download_clients;
+pub mod log;
 pub mod movie_info;
 pub mod popular_movies;
+pub mod preferences;
+pub mod rss;
 pub mod search_filters;
 pub mod search_movies;
+pub mod search_series;
 pub mod search_torrents;
+pub mod series_info;
+pub mod tags;
 pub mod tmdb_bulk;
+pub mod transfer;
 pub mod trending_movies;
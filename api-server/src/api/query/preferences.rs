@@ -0,0 +1,24 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::Preferences;
+
+#[derive(Default)]
+pub struct PreferencesQuery;
+
+#[Object]
+impl PreferencesQuery {
+    /// The qBittorrent application preferences
+    async fn preferences<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        client: Option<String>,
+    ) -> Result<Preferences, HttpErrorKind> {
+        let preferences = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .preferences()
+            .await?;
+
+        Ok(preferences)
+    }
+}
@@ -0,0 +1,53 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use qbittorrent_api::{RssItems, RssMatchingArticles, RssRule};
+
+#[derive(Default)]
+pub struct RssQuery;
+
+#[Object]
+impl RssQuery {
+    /// The RSS folders and feeds of qBittorrent, with their articles when `withData` is set
+    async fn rss_items<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        #[graphql(default = true)] with_data: bool,
+        client: Option<String>,
+    ) -> Result<RssItems, HttpErrorKind> {
+        let items = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rss_items(with_data)
+            .await?;
+
+        Ok(items)
+    }
+
+    async fn rss_rules<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        client: Option<String>,
+    ) -> Result<Vec<RssRule>, HttpErrorKind> {
+        let rules = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rss_rules()
+            .await?;
+
+        Ok(rules)
+    }
+
+    /// The articles the rule `name` matches, to preview a rule
+    async fn rss_matching_articles<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        name: String,
+        client: Option<String>,
+    ) -> Result<Vec<RssMatchingArticles>, HttpErrorKind> {
+        let articles = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .rss_matching_articles(&name)
+            .await?;
+
+        Ok(articles)
+    }
+}
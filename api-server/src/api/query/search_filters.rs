@@ -1,7 +1,7 @@
 use crate::models::filter::Filter;
 use async_graphql::Object;
 use strum::IntoEnumIterator;
-use torrent_search_client::{Codec, Provider, Quality, Source};
+use torrent_search_client::{Codec, Provider, ProviderCapabilities, Quality, Source};
 
 #[derive(Default)]
 pub struct SearchFiltersQuery;
@@ -36,4 +36,10 @@ impl SearchFiltersQuery {
             ),
         ]
     }
+
+    async fn provider_capabilities(&self) -> Vec<ProviderCapabilities> {
+        Provider::iter()
+            .map(|provider| provider.capabilities())
+            .collect()
+    }
 }
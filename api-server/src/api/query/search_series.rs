@@ -0,0 +1,22 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use movie_info::SeriesInfo;
+
+#[derive(Default)]
+pub struct SearchSeriesQuery;
+
+#[Object]
+impl SearchSeriesQuery {
+    async fn search_series<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        query: String,
+    ) -> Result<Vec<SeriesInfo>, HttpErrorKind> {
+        let ctx = get_context(context);
+
+        let series_info = ctx.movie_info_client().search_series(query).await?;
+
+        Ok(series_info)
+    }
+}
@@ -7,7 +7,7 @@ use async_graphql::{Context, Object};
 use log::error;
 use serde::Serialize;
 use std::collections::HashMap;
-use torrent_search_client::{MovieOptions, Order, SearchOptions, SortColumn, Torrent};
+use torrent_search_client::{MovieOptions, SearchOptions, Torrent};
 
 #[derive(Default)]
 pub struct SearchTorrentsQuery;
@@ -38,25 +38,43 @@ impl SearchTorrentsQuery {
             ctx.torrent_client()
                 .search(&options, params.providers())
                 .await
-        } else if let Some(imdb) = params.imdb().to_owned() {
-            let movie_info = ctx.movie_info_client().from_imdb(&imdb).await?;
-
-            if let Some(movie_info) = movie_info {
-                let options = MovieOptions::new(
-                    imdb,
+        } else {
+            let (imdb, title) = if let Some(imdb) = params.imdb().to_owned() {
+                let Some(movie_info) = ctx.movie_info_client().from_imdb(&imdb).await? else {
+                    return Err(HttpErrorKind::imdb_not_found(imdb));
+                };
+
+                (Some(imdb), Some(movie_info.format()))
+            } else if let Some(tmdb) = params.tmdb().to_owned() {
+                let Some(movie_info) = ctx.movie_info_client().from_tmdb(tmdb).await? else {
+                    return Err(HttpErrorKind::tmdb_not_found(tmdb));
+                };
+
+                (
+                    movie_info.get_imdb_id().to_owned(),
                     Some(movie_info.format()),
-                    params.sort().to_owned(),
-                    params.order().to_owned(),
-                );
-
-                ctx.torrent_client()
-                    .search_movie(&options, params.providers())
-                    .await
+                )
+            } else if let Some(title) = params.title().to_owned() {
+                let title = match params.year() {
+                    Some(year) => format!("{title} ({year})"),
+                    None => title,
+                };
+
+                (None, Some(title))
             } else {
-                return Err(HttpErrorKind::imdb_not_found(imdb));
-            }
-        } else {
-            return Err(HttpErrorKind::missing_query());
+                return Err(HttpErrorKind::missing_query());
+            };
+
+            let options = MovieOptions::new(
+                imdb,
+                title,
+                params.sort().to_owned(),
+                params.order().to_owned(),
+            );
+
+            ctx.torrent_client()
+                .search_movie(&options, params.providers())
+                .await
         };
 
         let mut grouped: HashMap<String, Torrent> = HashMap::new();
@@ -66,8 +84,13 @@ impl SearchTorrentsQuery {
             match result.torrents {
                 Ok(provider_torrents) => {
                     for torrent in provider_torrents {
+                        // Results only linking to a `.torrent` file have no hash, they are told apart by their url
+                        let key = match &torrent.torrent_url {
+                            Some(url) if torrent.info_hash.is_empty() => url.to_owned(),
+                            _ => torrent.info_hash.to_owned(),
+                        };
                         grouped
-                            .entry(torrent.info_hash.to_owned())
+                            .entry(key)
                             .and_modify(|existing| existing.merge(torrent.to_owned()))
                             .or_insert(torrent);
                     }
@@ -102,16 +125,8 @@ impl SearchTorrentsQuery {
             false
         });
 
-        torrents.sort_unstable_by(|a, b| match params.sort() {
-            SortColumn::Added => a.added.cmp(&b.added),
-            SortColumn::Leechers => a.leechers.cmp(&b.leechers),
-            SortColumn::Seeders => a.seeders.cmp(&b.seeders),
-            SortColumn::Size => a.size.cmp(&b.size),
-        });
-
-        if params.order() == &Order::Descending {
-            torrents.reverse();
-        }
+        ctx.config().ranking().score_all(&mut torrents);
+        params.sort().sort(&mut torrents, params.order());
 
         if params.limit() != &0 {
             torrents.truncate(*params.limit());
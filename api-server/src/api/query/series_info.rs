@@ -0,0 +1,34 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+use movie_info::{SeriesInfo, TmdbId, TvdbId};
+
+#[derive(Default)]
+pub struct SeriesInfoQuery;
+
+#[Object]
+impl SeriesInfoQuery {
+    /// By TVDB id, or else by IMDb id or TMDB TV id which are looked up on Skyhook
+    async fn series_info<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        tvdb: Option<TvdbId>,
+        imdb: Option<String>,
+        tmdb: Option<TmdbId>,
+    ) -> Result<Option<SeriesInfo>, HttpErrorKind> {
+        let client = get_context(context).movie_info_client();
+
+        let series_info = match (tvdb, imdb, tmdb) {
+            (Some(tvdb), _, _) => client.series_info(tvdb).await?,
+            (None, Some(imdb), _) => client.series_by_imdb(&imdb).await?,
+            (None, None, Some(tmdb)) => client.series_by_tmdb(tmdb).await?,
+            (None, None, None) => {
+                return Err(HttpErrorKind::MissingQuery(
+                    "At least `tvdb`, `imdb` or `tmdb` must be defined.".into(),
+                ))
+            }
+        };
+
+        Ok(series_info)
+    }
+}
@@ -0,0 +1,22 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object};
+
+#[derive(Default)]
+pub struct TagsQuery;
+
+#[Object]
+impl TagsQuery {
+    async fn tags<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        client: Option<String>,
+    ) -> Result<Vec<String>, HttpErrorKind> {
+        let tags = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .tags()
+            .await?;
+
+        Ok(tags)
+    }
+}
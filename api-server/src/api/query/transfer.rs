@@ -0,0 +1,44 @@
+use super::super::get_context;
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Object, SimpleObject};
+use qbittorrent_api::{SpeedLimits, TransferInfo};
+
+#[derive(SimpleObject)]
+struct SpeedLimitsResponse {
+    alternative_enabled: bool,
+    global: SpeedLimits,
+    alternative: SpeedLimits,
+}
+
+#[derive(Default)]
+pub struct TransferQuery;
+
+#[Object]
+impl TransferQuery {
+    async fn transfer_info<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        client: Option<String>,
+    ) -> Result<TransferInfo, HttpErrorKind> {
+        let transfer_info = get_context(context)
+            .qbittorrent_client(client.as_deref())?
+            .transfer_info()
+            .await?;
+
+        Ok(transfer_info)
+    }
+
+    async fn speed_limits<'ctx>(
+        &self,
+        context: &Context<'ctx>,
+        client: Option<String>,
+    ) -> Result<SpeedLimitsResponse, HttpErrorKind> {
+        let qb = get_context(context).qbittorrent_client(client.as_deref())?;
+
+        Ok(SpeedLimitsResponse {
+            alternative_enabled: qb.alternative_speed_limits_enabled().await?,
+            global: qb.global_speed_limits().await?,
+            alternative: qb.alternative_speed_limits().await?,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::models::context::ContextPointer;
+use log::{info, warn};
+use std::{sync::Arc, time::Duration};
+use tokio::{task::JoinSet, time::sleep};
+
+const RETRY_TIMEOUT: Duration = Duration::from_secs(30);
+
+async fn client_events(context: ContextPointer, index: usize) {
+    let download_client = &context.download_clients()[index];
+
+    loop {
+        match download_client.client().wait_for_completion().await {
+            Ok(Some(hash)) => {
+                info!("Download {hash} completed in {}", download_client.name());
+                context.wake_movie_tracking().await;
+            }
+            Ok(None) => return,
+            Err(error) => {
+                warn!(
+                    "Could not receive download events of {}: {error:?}",
+                    download_client.name()
+                );
+                sleep(RETRY_TIMEOUT).await;
+            }
+        }
+    }
+}
+
+/// Wakes movie tracking when a download client pushes a finished download
+pub async fn download_events(context: ContextPointer) {
+    let mut clients = JoinSet::new();
+
+    for index in 0..context.download_clients().len() {
+        clients.spawn(client_events(Arc::clone(&context), index));
+    }
+
+    clients.join_all().await;
+}
@@ -1,10 +1,23 @@
-mod movie_tracking;
+mod download_events;
+pub(crate) mod movie_tracking;
+mod speed_schedule;
+use self::download_events::download_events;
 use self::movie_tracking::movie_tracking;
+use self::speed_schedule::speed_schedule;
 use crate::models::context::ContextPointer;
 use log::error;
+use std::sync::Arc;
 
 pub async fn background(context: ContextPointer) {
-    if let Err(error) = movie_tracking(context).await {
-        error!("MovieTracking error: {error:?}");
-    }
+    let tracking = async {
+        if let Err(error) = movie_tracking(Arc::clone(&context)).await {
+            error!("MovieTracking error: {error:?}");
+        }
+    };
+
+    tokio::join!(
+        tracking,
+        speed_schedule(Arc::clone(&context)),
+        download_events(Arc::clone(&context))
+    );
 }
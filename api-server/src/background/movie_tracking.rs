@@ -1,12 +1,68 @@
 use crate::{
-    models::{context::ContextPointer, http_error::HttpErrorKind},
+    download_client::DownloadClientInstance,
+    models::{
+        context::ContextPointer, download_torrent::DownloadTorrent, http_error::HttpErrorKind,
+    },
     utils::{get_tmdb::get_tmdb, import_movie::import_movie},
 };
 use filenamify::filenamify;
 use log::{debug, info, warn};
-use std::{path::PathBuf, time::Duration};
+use std::time::Duration;
 use tokio::time::sleep;
 
+/// Imports a finished torrent into the movies folder, then deletes it or moves it to `category_after_import`
+pub(crate) async fn import_torrent(
+    context: &ContextPointer,
+    download_client: &DownloadClientInstance,
+    torrent: &DownloadTorrent,
+) -> Result<(), HttpErrorKind> {
+    let config = context.config();
+    let name = torrent.get_name();
+
+    let Some(tmdb) = get_tmdb(torrent) else {
+        warn!("No TMDB id found for {name}");
+        return Ok(());
+    };
+
+    let Some(movie) = context.movie_info_client().from_tmdb(tmdb).await? else {
+        warn!("No movie found for TMDB id: {tmdb}");
+        return Ok(());
+    };
+
+    let movie_name = movie.format();
+
+    info!("Importing {name:?} as {movie_name:?}");
+
+    let local_path = download_client
+        .conf()
+        .local_path(torrent.get_content_path());
+
+    let dest_folder = config.movies_path().join(filenamify(&movie_name));
+
+    import_movie(
+        &local_path,
+        &dest_folder,
+        *config.import_movie_max_depth(),
+        config.subtitle_language_map(),
+    )
+    .await?;
+
+    if *config.delete_torrent_after_import() {
+        download_client
+            .client()
+            .delete_torrents(
+                vec![torrent.get_hash().to_owned()],
+                *config.delete_torrent_files(),
+            )
+            .await
+    } else {
+        download_client
+            .client()
+            .set_category(torrent.get_hash(), config.category_after_import())
+            .await
+    }
+}
+
 pub async fn movie_tracking(context: ContextPointer) -> Result<(), HttpErrorKind> {
     let config = context.config();
 
@@ -21,10 +77,15 @@ pub async fn movie_tracking(context: ContextPointer) -> Result<(), HttpErrorKind
     let timeout_inactive = config.movie_tracking_timeout_inactive();
     let min_timeout = config.movie_tracking_min_timeout();
 
-    let qb = context.qbittorrent_client();
-
-    qb.ensure_category(config.qbittorrent().category(), "")
-        .await?;
+    for download_client in context.download_clients() {
+        let category = download_client.category();
+        if let Err(error) = download_client.client().ensure_category(category).await {
+            warn!(
+                "Could not create the category {category:?} in {}: {error:?}",
+                download_client.name()
+            );
+        }
+    }
 
     loop {
         let mut min_eta = *max_timeout_active;
@@ -34,20 +95,30 @@ pub async fn movie_tracking(context: ContextPointer) -> Result<(), HttpErrorKind
             context.movie_tracking_ntfy().notified().await;
         }
 
-        let category = config.qbittorrent().category();
-        let movies_path = config.movies_path();
-        let remote_download_path = config.remote_download_path();
-        let local_download_path = config.local_download_path();
-
         debug!("Checking for torrents to import");
 
-        let torrents = qb.torrents_sync().await?;
-
         let mut watching_torrents = 0;
         let mut active_torrents = 0;
 
-        for torrent in torrents {
-            if torrent.get_category() == category {
+        for download_client in context.download_clients() {
+            let category = download_client.category();
+            let torrents = match download_client.torrents().await {
+                Ok(torrents) => torrents,
+                Err(error) => {
+                    // An unreachable client does not stop the others from being tracked
+                    warn!(
+                        "Could not get the torrents of {}: {error:?}",
+                        download_client.name()
+                    );
+                    continue;
+                }
+            };
+
+            for torrent in torrents {
+                if torrent.get_category() != category {
+                    continue;
+                }
+
                 let progress = torrent.get_progress();
                 let eta = torrent.get_eta();
                 let state = torrent.get_state();
@@ -63,54 +134,18 @@ pub async fn movie_tracking(context: ContextPointer) -> Result<(), HttpErrorKind
                     min_eta = min_eta.min(*eta).min(*max_timeout_active).max(*min_timeout);
 
                     debug!(
-                        "{}: Progress: {:.2}%, ETA: {} min, State: {:?}",
+                        "{}: Progress: {:.2}%, ETA: {} min, State: {:?}, Client: {}",
                         name,
                         (progress * 100.0).round(),
                         eta / 60,
-                        state
+                        state,
+                        download_client.name()
                     );
-                } else if let Some(tmdb) = get_tmdb(name) {
-                    let movie = context.movie_info_client().from_tmdb(tmdb).await?;
-
-                    if let Some(movie) = movie {
-                        let movie_name = movie.format();
-
-                        info!("Importing {name:?} as {movie_name:?}");
-
-                        let remote_path = torrent.get_content_path();
-
-                        let local_path =
-                            remote_path.replace(remote_download_path, local_download_path);
-                        let local_path = PathBuf::from(local_path);
-
-                        let dest_folder = movies_path.join(filenamify(&movie_name));
-
-                        import_movie(
-                            &local_path,
-                            &dest_folder,
-                            *config.import_movie_max_depth(),
-                            config.subtitle_language_map(),
-                        )
-                        .await?;
-
-                        if *config.delete_torrent_after_import() {
-                            qb.delete_torrent(
-                                torrent.get_hash().to_owned(),
-                                *config.delete_torrent_files(),
-                            )
-                            .await?;
-                        } else {
-                            qb.set_category(
-                                torrent.get_hash().to_owned(),
-                                config.category_after_import().to_owned(),
-                            )
-                            .await?;
-                        }
-                    } else {
-                        warn!("No movie found for TMDB id: {tmdb}");
-                    }
-                } else {
-                    warn!("No TMDB id found for {name}");
+                } else if let Err(error) = import_torrent(&context, download_client, &torrent).await
+                {
+                    // Retried on the next check, the other torrents are still imported
+                    warn!("Could not import {name:?}: {error:?}");
+                    watching_torrents += 1;
                 }
             }
         }
@@ -126,7 +161,153 @@ pub async fn movie_tracking(context: ContextPointer) -> Result<(), HttpErrorKind
             info!("Watching {active_torrents}/{watching_torrents} torrents")
         }
 
-        info!("Waiting: {min_eta}s");
-        sleep(Duration::from_secs(min_eta as u64)).await;
+        // Finished downloads are pushed by the webhook, polling only catches the missed ones
+        let timeout = match config.webhook() {
+            Some(webhook) => *webhook.reconcile_interval(),
+            None => min_eta,
+        };
+
+        info!("Waiting: {timeout}s");
+        tokio::select! {
+            _ = sleep(Duration::from_secs(timeout as u64)) => {}
+            _ = context.movie_tracking_ntfy().notified() => debug!("Checking early"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        download_client,
+        models::{config::Config, context::Context},
+    };
+    use fake_qbittorrent::{FakeQbittorrent, PASSWORD, USERNAME};
+    use movie_info::MovieInfoClient;
+    use serde_json::json;
+    use std::sync::Arc;
+    use torrent_search_client::TorrentClient;
+
+    const HASH: &str = "c9e15763f722f23e98a29decdfae341b98d53056";
+
+    fn context(server: &FakeQbittorrent) -> ContextPointer {
+        let config: Config = serde_json::from_value(json!({
+            "qbittorrent": {
+                "username": USERNAME,
+                "password": PASSWORD,
+                "url": server.url(),
+            },
+            "remote_download_path": "/downloads",
+            "local_download_path": "/downloads",
+            "movies_path": "/movies",
+            "movie_tracking_max_timeout_active": 1,
+            "movie_tracking_timeout_inactive": 1,
+        }))
+        .unwrap();
+
+        Arc::new(Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        ))
+    }
+
+    async fn wait_until<F: AsyncFn() -> bool>(condition: F) {
+        for _ in 0..100 {
+            if condition().await {
+                return;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+        panic!("Timed out waiting for movie tracking");
+    }
+
+    #[tokio::test]
+    async fn test_movie_tracking_stops_without_torrents_to_track() {
+        let server = FakeQbittorrent::start().await;
+        server.add_torrent(HASH, "Movie", "torrent-api");
+        server.set_download_speed(HASH, 1 << 20);
+
+        let context = context(&server);
+        let tracking = tokio::spawn(movie_tracking(Arc::clone(&context)));
+
+        wait_until(async || server.categories().contains_key("torrent-api")).await;
+        sleep(Duration::from_millis(500)).await;
+        assert!(*context.movie_tracking_enabled().lock().await);
+
+        // Without a TMDB id the finished torrent is left alone and nothing is left to track
+        server.complete(HASH);
+        context.wake_movie_tracking().await;
+        wait_until(async || !*context.movie_tracking_enabled().lock().await).await;
+
+        let torrent = server.torrent(HASH).unwrap();
+        assert_eq!(torrent.category(), "torrent-api");
+
+        tracking.abort();
+    }
+
+    #[tokio::test]
+    async fn test_movie_tracking_continues_after_failed_import() {
+        let server = FakeQbittorrent::start().await;
+        // The content path does not exist, so importing fails
+        server.add_torrent(HASH, "Movie (603)", "torrent-api");
+        server.complete(HASH);
+
+        let context = context(&server);
+        let tracking = tokio::spawn(movie_tracking(Arc::clone(&context)));
+
+        wait_until(async || server.categories().contains_key("torrent-api")).await;
+        sleep(Duration::from_millis(500)).await;
+
+        assert!(!tracking.is_finished());
+        assert!(*context.movie_tracking_enabled().lock().await);
+        assert_eq!(server.torrent(HASH).unwrap().category(), "torrent-api");
+
+        tracking.abort();
+    }
+
+    #[tokio::test]
+    async fn test_movie_tracking_skips_unreachable_client() {
+        let server = FakeQbittorrent::start().await;
+        server.add_torrent(HASH, "Movie", "torrent-api");
+        server.set_download_speed(HASH, 1 << 20);
+
+        let client = |name: &str, url: &str| {
+            json!({
+                "name": name,
+                "type": "qbittorrent",
+                "username": USERNAME,
+                "password": PASSWORD,
+                "url": url,
+            })
+        };
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                client("offline", "http://127.0.0.1:1"),
+                client("home", server.url()),
+            ],
+            "movies_path": "/movies",
+            "movie_tracking_max_timeout_active": 1,
+            "movie_tracking_timeout_inactive": 1,
+        }))
+        .unwrap();
+        let context = Arc::new(Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        ));
+
+        let tracking = tokio::spawn(movie_tracking(Arc::clone(&context)));
+
+        wait_until(async || server.categories().contains_key("torrent-api")).await;
+        sleep(Duration::from_millis(500)).await;
+
+        // The torrent of the reachable client is still tracked
+        assert!(!tracking.is_finished());
+        assert!(*context.movie_tracking_enabled().lock().await);
+
+        tracking.abort();
     }
 }
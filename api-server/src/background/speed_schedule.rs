@@ -0,0 +1,58 @@
+use crate::models::{
+    context::ContextPointer, http_error::HttpErrorKind, speed_schedule::SpeedState,
+};
+use chrono::Local;
+use log::{info, warn};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Applied to every qBittorrent client
+async fn apply(context: &ContextPointer, state: &SpeedState) -> Result<(), HttpErrorKind> {
+    for qb in context.qbittorrent_clients() {
+        if let Some(enabled) = state.alternative_speed {
+            qb.set_alternative_speed_limits_enabled(enabled).await?;
+        }
+
+        if let Some(limits) = state.limits {
+            qb.set_global_speed_limits(limits).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the speed schedule whenever its state changes, so manual changes last until the next window boundary
+pub async fn speed_schedule(context: ContextPointer) {
+    let schedule = context.config().speed_schedule();
+
+    if schedule.is_empty() {
+        return;
+    }
+
+    if context.qbittorrent_clients().next().is_none() {
+        warn!("The speed schedule is only supported with qBittorrent");
+        return;
+    }
+
+    info!("Starting speed limit schedule");
+
+    let mut applied: Option<SpeedState> = None;
+
+    loop {
+        let state = schedule.state(&Local::now().naive_local());
+
+        if applied != Some(state) {
+            match apply(&context, &state).await {
+                Ok(()) => {
+                    info!("Applied speed schedule: {state:?}");
+                    applied = Some(state);
+                }
+                Err(error) => warn!("Could not apply speed schedule: {error:?}"),
+            }
+        }
+
+        sleep(CHECK_INTERVAL).await;
+    }
+}
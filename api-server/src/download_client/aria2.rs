@@ -0,0 +1,233 @@
+use super::{DownloadClient, DownloadOptions};
+use crate::models::{download_torrent::DownloadTorrent, http_error::HttpErrorKind};
+use aria2_api::{AddOptions, Aria2Client, DownloadStatus, Notification, NotificationKind};
+use async_trait::async_trait;
+use log::warn;
+use qbittorrent_api::TorrentUpload;
+use std::time::Duration;
+use tokio::{
+    sync::{broadcast, Mutex},
+    time::sleep,
+};
+
+const STOP_POLLS: usize = 20;
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// aria2 has no categories or tags, they are stored in the download directory:
+/// `{download_dir}/{category}/{tags}` with the tags separated by commas
+pub struct Aria2DownloadClient {
+    client: Aria2Client,
+    download_dir: String,
+    notifications: Mutex<Option<broadcast::Receiver<Notification>>>,
+}
+
+impl Aria2DownloadClient {
+    pub fn new(client: Aria2Client, download_dir: String) -> Self {
+        Self {
+            client,
+            download_dir: download_dir.trim_end_matches('/').to_string(),
+            notifications: Mutex::new(None),
+        }
+    }
+
+    fn dir(&self, options: &DownloadOptions) -> String {
+        let mut dir = self.download_dir.to_owned();
+
+        if let Some(category) = options.category.as_ref().filter(|c| !c.is_empty()) {
+            dir = format!("{dir}/{category}");
+
+            if !options.tags.is_empty() {
+                dir = format!("{dir}/{}", options.tags.join(","));
+            }
+        }
+
+        dir
+    }
+
+    fn add_options(&self, options: &DownloadOptions) -> AddOptions {
+        let mut add_options = AddOptions::default();
+
+        add_options.set_dir(Some(self.dir(options)));
+
+        add_options
+    }
+
+    /// Stops the download if it is still running or seeding, then removes its result
+    async fn forget(&self, gid: &str) -> Result<(), HttpErrorKind> {
+        let mut status = *self.client.tell_status(gid).await?.get_status();
+
+        if matches!(
+            status,
+            DownloadStatus::Active | DownloadStatus::Waiting | DownloadStatus::Paused
+        ) {
+            self.client.force_remove(gid).await?;
+
+            // forceRemove returns before the download is stopped
+            for _ in 0..STOP_POLLS {
+                status = *self.client.tell_status(gid).await?.get_status();
+                if status == DownloadStatus::Removed {
+                    break;
+                }
+                sleep(STOP_POLL_INTERVAL).await;
+            }
+        }
+
+        self.client.remove_download_result(gid).await?;
+
+        Ok(())
+    }
+
+    /// The category and tags stored in `dir`
+    fn labels(&self, dir: &str) -> (String, Vec<String>) {
+        let mut parts = dir
+            .strip_prefix(&self.download_dir)
+            .unwrap_or_default()
+            .split('/')
+            .filter(|part| !part.is_empty());
+
+        let category = parts.next().unwrap_or_default().to_string();
+        let tags = parts
+            .next()
+            .map(|tags| tags.split(',').map(String::from).collect())
+            .unwrap_or_default();
+
+        (category, tags)
+    }
+}
+
+#[async_trait]
+impl DownloadClient for Aria2DownloadClient {
+    /// Directories are created by aria2
+    async fn ensure_category(&self, _category: &str) -> Result<(), HttpErrorKind> {
+        Ok(())
+    }
+
+    async fn add_torrent(
+        &self,
+        url: String,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        self.client
+            .add_uri(vec![url], &self.add_options(options))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upload_torrent(
+        &self,
+        file: TorrentUpload,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        self.client
+            .add_torrent(file.content(), &self.add_options(options))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn torrents(&self) -> Result<Vec<DownloadTorrent>, HttpErrorKind> {
+        let downloads = self.client.downloads().await?;
+
+        Ok(downloads
+            .iter()
+            .filter(|download| {
+                !download.is_metadata() && *download.get_status() != DownloadStatus::Removed
+            })
+            .map(|download| {
+                let (category, tags) = self.labels(download.get_dir());
+                DownloadTorrent::from_aria2(download, category, tags)
+            })
+            .collect())
+    }
+
+    /// Files are kept, aria2 cannot delete them
+    async fn delete_torrents(
+        &self,
+        hashes: Vec<String>,
+        delete_files: bool,
+    ) -> Result<(), HttpErrorKind> {
+        if delete_files {
+            warn!("aria2 cannot delete the files of a download, they are kept");
+        }
+
+        for gid in hashes {
+            self.forget(&gid).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Files cannot be moved, so a finished download is forgotten instead, which keeps it from being imported again.
+    ///
+    /// Finished torrents keep seeding in aria2, seeding is stopped too.
+    async fn set_category(&self, hash: &str, _category: &str) -> Result<(), HttpErrorKind> {
+        let download = self.client.tell_status(hash).await?;
+        let finished = download.progress() >= 1.0
+            || matches!(
+                download.get_status(),
+                DownloadStatus::Complete | DownloadStatus::Removed
+            );
+
+        if !finished {
+            return Err(HttpErrorKind::Unsupported(
+                "aria2 can only change the category of finished downloads".into(),
+            ));
+        }
+
+        self.forget(hash).await
+    }
+
+    async fn wait_for_completion(&self) -> Result<Option<String>, HttpErrorKind> {
+        let mut notifications = self.notifications.lock().await;
+
+        loop {
+            let receiver = match notifications.as_mut() {
+                Some(receiver) => receiver,
+                None => notifications.insert(self.client.notifications().await?),
+            };
+
+            match receiver.recv().await {
+                Ok(Notification {
+                    kind: NotificationKind::DownloadComplete | NotificationKind::BtDownloadComplete,
+                    gid,
+                }) => return Ok(Some(gid)),
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => {
+                    // Connects again on the next call
+                    *notifications = None;
+                    return Err(HttpErrorKind::Aria2Error(aria2_api::Error::new(
+                        aria2_api::ErrorKind::Disconnected,
+                        "The connection to aria2 closed",
+                    )));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_labels() {
+        let client = Aria2DownloadClient::new(
+            Aria2Client::new("http://localhost:6800/jsonrpc", None),
+            "/downloads/".into(),
+        );
+
+        let options = DownloadOptions {
+            category: Some("movies".into()),
+            tags: vec!["tmdb:603".into()],
+        };
+
+        let dir = client.dir(&options);
+        assert_eq!(dir, "/downloads/movies/tmdb:603");
+        assert_eq!(
+            client.labels(&dir),
+            ("movies".to_string(), vec!["tmdb:603".to_string()])
+        );
+        assert_eq!(client.labels("/downloads"), (String::new(), Vec::new()));
+    }
+}
@@ -0,0 +1,132 @@
+mod aria2;
+mod qbittorrent;
+mod transmission;
+
+use crate::models::{
+    config::{Config, DownloadClientConf, DownloadClientSettings},
+    download_torrent::DownloadTorrent,
+    http_error::HttpErrorKind,
+};
+use aria2_api::Aria2Client;
+use async_trait::async_trait;
+use qbittorrent_api::{QbittorrentClient, TorrentUpload};
+use transmission_api::TransmissionClient;
+
+/// How a torrent is filed when it is added
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// The calls movie tracking and the GraphQL layer need from a download client
+#[async_trait]
+pub trait DownloadClient: Send + Sync {
+    /// Makes sure torrents can be added to `category`
+    async fn ensure_category(&self, category: &str) -> Result<(), HttpErrorKind>;
+
+    async fn add_torrent(
+        &self,
+        url: String,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind>;
+
+    async fn upload_torrent(
+        &self,
+        file: TorrentUpload,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind>;
+
+    async fn torrents(&self) -> Result<Vec<DownloadTorrent>, HttpErrorKind>;
+
+    async fn delete_torrents(
+        &self,
+        hashes: Vec<String>,
+        delete_files: bool,
+    ) -> Result<(), HttpErrorKind>;
+
+    async fn set_category(&self, hash: &str, category: &str) -> Result<(), HttpErrorKind>;
+
+    /// Waits until a download completes and returns its hash, `None` when the client does not push events
+    async fn wait_for_completion(&self) -> Result<Option<String>, HttpErrorKind> {
+        Ok(None)
+    }
+
+    /// The qBittorrent client, for the features only qBittorrent supports
+    fn qbittorrent(&self) -> Option<&QbittorrentClient> {
+        None
+    }
+}
+
+/// A configured download client with its name, category and routing rules
+pub struct DownloadClientInstance {
+    conf: DownloadClientConf,
+    client: Box<dyn DownloadClient>,
+}
+
+impl DownloadClientInstance {
+    pub fn new(conf: DownloadClientConf, client: Box<dyn DownloadClient>) -> Self {
+        Self { conf, client }
+    }
+
+    pub fn name(&self) -> &str {
+        self.conf.name()
+    }
+
+    pub fn category(&self) -> &str {
+        self.conf.category()
+    }
+
+    pub fn conf(&self) -> &DownloadClientConf {
+        &self.conf
+    }
+
+    pub fn client(&self) -> &dyn DownloadClient {
+        self.client.as_ref()
+    }
+
+    /// The torrents of the client, marked with its name
+    pub async fn torrents(&self) -> Result<Vec<DownloadTorrent>, HttpErrorKind> {
+        let torrents = self.client.torrents().await?;
+
+        Ok(torrents
+            .into_iter()
+            .map(|torrent| torrent.with_client(self.name()))
+            .collect())
+    }
+
+    pub fn qbittorrent(&self) -> Result<&QbittorrentClient, HttpErrorKind> {
+        self.client
+            .qbittorrent()
+            .ok_or_else(HttpErrorKind::qbittorrent_only)
+    }
+}
+
+fn connect(conf: &DownloadClientConf) -> Box<dyn DownloadClient> {
+    let proxy = conf.proxy().as_ref();
+
+    match conf.settings() {
+        DownloadClientSettings::Qbittorrent(settings) => Box::new(QbittorrentClient::with_auth(
+            settings.auth(),
+            settings.url().as_str(),
+            proxy,
+        )),
+        DownloadClientSettings::Transmission(settings) => Box::new(TransmissionClient::with_proxy(
+            settings.url().as_str(),
+            settings.credentials(),
+            proxy,
+        )),
+        DownloadClientSettings::Aria2(settings) => Box::new(aria2::Aria2DownloadClient::new(
+            Aria2Client::with_proxy(settings.url().as_str(), settings.secret().clone(), proxy),
+            settings.download_dir().to_owned(),
+        )),
+    }
+}
+
+pub fn from_config(config: &Config) -> Vec<DownloadClientInstance> {
+    config
+        .download_clients()
+        .into_iter()
+        .map(|conf| DownloadClientInstance::new(conf.clone(), connect(&conf)))
+        .collect()
+}
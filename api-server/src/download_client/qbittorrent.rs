@@ -0,0 +1,71 @@
+use super::{DownloadClient, DownloadOptions};
+use crate::models::{download_torrent::DownloadTorrent, http_error::HttpErrorKind};
+use async_trait::async_trait;
+use qbittorrent_api::{AddTorrentOptions, QbittorrentClient, TorrentUpload};
+
+fn add_torrent_options(options: &DownloadOptions) -> AddTorrentOptions {
+    let mut add_options = AddTorrentOptions::default();
+
+    add_options.set_category(options.category.clone());
+
+    if !options.tags.is_empty() {
+        add_options.set_tags(Some(options.tags.join(",")));
+    }
+
+    add_options
+}
+
+#[async_trait]
+impl DownloadClient for QbittorrentClient {
+    async fn ensure_category(&self, category: &str) -> Result<(), HttpErrorKind> {
+        QbittorrentClient::ensure_category(self, category, "").await?;
+
+        Ok(())
+    }
+
+    async fn add_torrent(
+        &self,
+        url: String,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        QbittorrentClient::add_torrent(self, url, add_torrent_options(options)).await?;
+
+        Ok(())
+    }
+
+    async fn upload_torrent(
+        &self,
+        file: TorrentUpload,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        QbittorrentClient::upload_torrent(self, file, add_torrent_options(options)).await?;
+
+        Ok(())
+    }
+
+    async fn torrents(&self) -> Result<Vec<DownloadTorrent>, HttpErrorKind> {
+        let torrents = self.torrents_sync().await?;
+
+        Ok(torrents.iter().map(DownloadTorrent::from).collect())
+    }
+
+    async fn delete_torrents(
+        &self,
+        hashes: Vec<String>,
+        delete_files: bool,
+    ) -> Result<(), HttpErrorKind> {
+        QbittorrentClient::delete_torrents(self, hashes, delete_files).await?;
+
+        Ok(())
+    }
+
+    async fn set_category(&self, hash: &str, category: &str) -> Result<(), HttpErrorKind> {
+        QbittorrentClient::set_category(self, hash.to_owned(), category.to_owned()).await?;
+
+        Ok(())
+    }
+
+    fn qbittorrent(&self) -> Option<&QbittorrentClient> {
+        Some(self)
+    }
+}
@@ -0,0 +1,94 @@
+use super::{DownloadClient, DownloadOptions};
+use crate::models::{download_torrent::DownloadTorrent, http_error::HttpErrorKind};
+use async_trait::async_trait;
+use qbittorrent_api::TorrentUpload;
+use transmission_api::{AddTorrentOptions, TransmissionClient};
+
+/// The category is stored as the first label, followed by the tags
+fn labels(category: Option<&str>, tags: &[String]) -> Vec<String> {
+    category
+        .filter(|category| !category.is_empty())
+        .map(String::from)
+        .into_iter()
+        .chain(tags.iter().cloned())
+        .collect()
+}
+
+fn add_torrent_options(options: &DownloadOptions) -> AddTorrentOptions {
+    let mut add_options = AddTorrentOptions::default();
+
+    add_options.set_labels(labels(options.category.as_deref(), &options.tags));
+
+    add_options
+}
+
+#[async_trait]
+impl DownloadClient for TransmissionClient {
+    /// Labels do not need to be created
+    async fn ensure_category(&self, _category: &str) -> Result<(), HttpErrorKind> {
+        Ok(())
+    }
+
+    async fn add_torrent(
+        &self,
+        url: String,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        TransmissionClient::add_torrent(self, url, &add_torrent_options(options)).await?;
+
+        Ok(())
+    }
+
+    async fn upload_torrent(
+        &self,
+        file: TorrentUpload,
+        options: &DownloadOptions,
+    ) -> Result<(), HttpErrorKind> {
+        TransmissionClient::upload_torrent(self, file.content(), &add_torrent_options(options))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn torrents(&self) -> Result<Vec<DownloadTorrent>, HttpErrorKind> {
+        let torrents = TransmissionClient::torrents(self).await?;
+
+        Ok(torrents.iter().map(DownloadTorrent::from).collect())
+    }
+
+    async fn delete_torrents(
+        &self,
+        hashes: Vec<String>,
+        delete_files: bool,
+    ) -> Result<(), HttpErrorKind> {
+        self.remove_torrents(hashes, delete_files).await?;
+
+        Ok(())
+    }
+
+    async fn set_category(&self, hash: &str, category: &str) -> Result<(), HttpErrorKind> {
+        let torrent = DownloadTorrent::from(&self.torrent(hash).await?);
+
+        self.set_labels(
+            vec![hash.to_owned()],
+            labels(Some(category), torrent.get_tags()),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels() {
+        let tags = vec!["tmdb:603".to_string()];
+
+        assert_eq!(labels(Some("movies"), &tags), vec!["movies", "tmdb:603"]);
+        assert_eq!(labels(None, &tags), vec!["tmdb:603"]);
+        assert_eq!(labels(Some(""), &[]), Vec::<String>::new());
+    }
+}
@@ -1,13 +1,18 @@
 use crate::api::{
     mutation::{
         add_torrents::AddTorrentsMutation, delete_torrents::DeleteTorrentsMutation,
+        preferences::PreferencesMutation, rss::RssMutation, speed_limits::SpeedLimitsMutation,
+        torrent_control::TorrentControlMutation, torrent_files::TorrentFilesMutation,
+        torrent_tags::TorrentTagsMutation, torrent_trackers::TorrentTrackersMutation,
         track_movie::TrackMovieMutation,
     },
     query::{
-        active_torrents::ActiveTorrentsQuery, movie_info::MovieInfoQuery,
-        popular_movies::PopularMoviesQuery, search_filters::SearchFiltersQuery,
-        search_movies::SearchMoviesQuery, search_torrents::SearchTorrentsQuery,
-        tmdb_bulk::TmdbBulkQuery, trending_movies::TrendingMoviesQuery,
+        active_torrents::ActiveTorrentsQuery, download_clients::DownloadClientsQuery,
+        log::LogQuery, movie_info::MovieInfoQuery, popular_movies::PopularMoviesQuery,
+        preferences::PreferencesQuery, rss::RssQuery, search_filters::SearchFiltersQuery,
+        search_movies::SearchMoviesQuery, search_series::SearchSeriesQuery,
+        search_torrents::SearchTorrentsQuery, series_info::SeriesInfoQuery, tags::TagsQuery,
+        tmdb_bulk::TmdbBulkQuery, transfer::TransferQuery, trending_movies::TrendingMoviesQuery,
     },
 };
 use async_graphql::{http::GraphiQLSource, EmptySubscription, MergedObject, Schema};
@@ -22,12 +27,20 @@ pub type SchemaType = Schema<Query, Mutation, EmptySubscription>;
 #[derive(Default, MergedObject)]
 pub struct Query(
     ActiveTorrentsQuery,
+    DownloadClientsQuery,
+    LogQuery,
     MovieInfoQuery,
     PopularMoviesQuery,
+    PreferencesQuery,
+    RssQuery,
     SearchFiltersQuery,
     SearchMoviesQuery,
+    SearchSeriesQuery,
     SearchTorrentsQuery,
+    SeriesInfoQuery,
+    TagsQuery,
     TmdbBulkQuery,
+    TransferQuery,
     TrendingMoviesQuery,
 );
 
@@ -35,6 +48,13 @@ pub struct Query(
 pub struct Mutation(
     AddTorrentsMutation,
     DeleteTorrentsMutation,
+    PreferencesMutation,
+    RssMutation,
+    SpeedLimitsMutation,
+    TorrentControlMutation,
+    TorrentFilesMutation,
+    TorrentTagsMutation,
+    TorrentTrackersMutation,
     TrackMovieMutation,
 );
 
@@ -55,3 +75,12 @@ pub async fn graphql_request(
 ) -> GraphQLResponse {
     request.execute(schema.inner()).await
 }
+
+/// GraphQL multipart requests, used to upload `.torrent` files
+#[rocket::post("/graphql", data = "<request>", format = "multipart/form-data")]
+pub async fn graphql_multipart_request(
+    schema: &State<SchemaType>,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    request.execute(schema.inner()).await
+}
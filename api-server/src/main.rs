@@ -1,16 +1,22 @@
 mod api;
 mod background;
+mod download_client;
 mod graphql;
 mod models;
 mod r#static;
 mod utils;
+mod webhook;
 
 use async_graphql::{EmptySubscription, Schema};
-use graphql::{graphiql, graphql_query, graphql_request, Mutation, Query, SchemaType};
+use graphql::{
+    graphiql, graphql_multipart_request, graphql_query, graphql_request, Mutation, Query,
+    SchemaType,
+};
 use log::error;
 use models::config::get_config;
 use models::context::{Context, ContextPointer};
-use qbittorrent_api::QbittorrentClient;
+use movie_info::MovieInfoClient;
+use rocket::fairing::AdHoc;
 use simplelog::{
     ColorChoice, ConfigBuilder as LogConfigBuilder, LevelFilter, TermLogger, TerminalMode,
 };
@@ -42,13 +48,12 @@ async fn rocket() -> _ {
         process::exit(1);
     });
 
+    let download_clients = download_client::from_config(&config);
+
     let context: ContextPointer = Arc::new(Context::new(
-        TorrentClient::new(),
-        QbittorrentClient::new(
-            config.qbittorrent().username(),
-            config.qbittorrent().password(),
-            config.qbittorrent().url().as_str(),
-        ),
+        TorrentClient::with_options(config.torrent_client_options(&download_clients)),
+        download_clients,
+        MovieInfoClient::with_options(config.movie_info_client_options()),
         config,
     ));
 
@@ -56,11 +61,30 @@ async fn rocket() -> _ {
 
     let schema: SchemaType =
         Schema::build(Query::default(), Mutation::default(), EmptySubscription)
-            .data(context)
+            .data(Arc::clone(&context))
             .finish();
 
-    rocket::build().manage(schema).mount(
-        "/",
-        rocket::routes![graphql_query, graphql_request, graphiql],
-    )
+    // Leaves room for uploaded `.torrent` files, can still be overridden with `ROCKET_LIMITS`
+    let figment = rocket::Config::figment().join(("limits.graphql", "16 MiB"));
+
+    rocket::custom(figment)
+        .manage(schema)
+        .manage(context)
+        .mount(
+            "/",
+            rocket::routes![
+                graphql_query,
+                graphql_request,
+                graphql_multipart_request,
+                graphiql,
+                webhook::torrent_finished
+            ],
+        )
+        .attach(AdHoc::on_shutdown("Save the movie cache", |rocket| {
+            Box::pin(async move {
+                if let Some(context) = rocket.state::<ContextPointer>() {
+                    context.movie_info_client().save_cache().await;
+                }
+            })
+        }))
 }
@@ -1,37 +1,311 @@
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
+    time::Duration,
 };
 
+use async_graphql::Enum;
 use figment::{
     providers::{Env, Format, Yaml},
     Error,
 };
 use getset::Getters;
-use log::{debug, error};
-use movie_info::Filters;
+use log::{debug, error, warn};
+use movie_info::{Filters, MetadataBackend, MovieInfoClientOptions, TmdbAuth};
+use qbittorrent_api::QbittorrentAuth;
 use serde::{Deserialize, Serialize};
 use serde_inline_default::serde_inline_default;
+use torrent_search_client::{Provider, QbittorrentSearchOptions, Ranking, TorrentClientOptions};
+use utils::{proxy::Proxy, rate_limiter::RateLimit};
 
-use super::serde_regex::SerdeRegex;
+use crate::download_client::DownloadClientInstance;
+
+use super::{
+    routing_rules::RoutingRules, serde_regex::SerdeRegex, speed_schedule::SpeedScheduleConf,
+};
 
 #[serde_inline_default]
 #[derive(Debug, Serialize, Deserialize, Getters, Clone)]
 #[get = "pub"]
 pub struct QbittorrentConf {
+    /// Leave empty when authentication is bypassed for api-server
+    #[serde(default)]
     username: String,
+    #[serde(default)]
     password: String,
+    #[serde(default)]
+    api_key: Option<String>,
+    url: String,
+    #[serde_inline_default("torrent-api".to_string())]
+    category: String,
+}
+
+impl QbittorrentConf {
+    pub fn auth(&self) -> QbittorrentAuth {
+        if let Some(api_key) = &self.api_key {
+            QbittorrentAuth::ApiKey(api_key.to_owned())
+        } else if self.username.is_empty() {
+            QbittorrentAuth::Bypass
+        } else {
+            QbittorrentAuth::Credentials {
+                username: self.username.to_owned(),
+                password: self.password.to_owned(),
+            }
+        }
+    }
+}
+
+#[serde_inline_default]
+#[derive(Debug, Serialize, Deserialize, Getters, Clone)]
+#[get = "pub"]
+pub struct TransmissionConf {
+    /// The full RPC url, e.g. `http://localhost:9091/transmission/rpc`
+    url: String,
+    #[serde(default)]
+    username: Option<String>,
+    #[serde(default)]
+    password: Option<String>,
+    /// Stored as the first label of the torrents
+    #[serde_inline_default("torrent-api".to_string())]
+    category: String,
+}
+
+impl TransmissionConf {
+    pub fn credentials(&self) -> Option<(String, String)> {
+        self.username.as_ref().map(|username| {
+            (
+                username.to_owned(),
+                self.password.to_owned().unwrap_or_default(),
+            )
+        })
+    }
+}
+
+#[serde_inline_default]
+#[derive(Debug, Serialize, Deserialize, Getters, Clone)]
+#[get = "pub"]
+pub struct Aria2Conf {
+    /// `http://localhost:6800/jsonrpc`, or `ws://localhost:6800/jsonrpc` to send the calls over a WebSocket
     url: String,
+    #[serde(default)]
+    secret: Option<String>,
+    /// The directory aria2 downloads to, downloads are saved in `{download_dir}/{category}/{tags}`
+    download_dir: String,
     #[serde_inline_default("torrent-api".to_string())]
     category: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Enum)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadClientKind {
+    #[default]
+    Qbittorrent,
+    Transmission,
+    Aria2,
+}
+
+/// The connection settings of a download client, picked by its `type`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum DownloadClientSettings {
+    Qbittorrent(QbittorrentConf),
+    Transmission(TransmissionConf),
+    Aria2(Aria2Conf),
+}
+
+impl DownloadClientSettings {
+    pub fn kind(&self) -> DownloadClientKind {
+        match self {
+            Self::Qbittorrent(_) => DownloadClientKind::Qbittorrent,
+            Self::Transmission(_) => DownloadClientKind::Transmission,
+            Self::Aria2(_) => DownloadClientKind::Aria2,
+        }
+    }
+
+    pub fn category(&self) -> &str {
+        match self {
+            Self::Qbittorrent(conf) => conf.category(),
+            Self::Transmission(conf) => conf.category(),
+            Self::Aria2(conf) => conf.category(),
+        }
+    }
+}
+
+/// One of the named download clients of `download_clients`
+#[derive(Debug, Serialize, Deserialize, Getters, Clone)]
+#[get = "pub"]
+pub struct DownloadClientConf {
+    name: String,
+    #[serde(flatten)]
+    settings: DownloadClientSettings,
+    /// Default to the top level `remote_download_path` and `local_download_path`
+    #[serde(default)]
+    remote_download_path: Option<String>,
+    #[serde(default)]
+    local_download_path: Option<String>,
+    #[serde(default)]
+    proxy: Option<Proxy>,
+    #[serde(default)]
+    routing: RoutingRules,
+}
+
+impl DownloadClientConf {
+    pub fn category(&self) -> &str {
+        self.settings.category()
+    }
+
+    /// Where a path reported by the download client is found locally
+    pub fn local_path(&self, remote_path: &str) -> PathBuf {
+        match (&self.remote_download_path, &self.local_download_path) {
+            (Some(remote), Some(local)) if !remote.is_empty() => {
+                PathBuf::from(remote_path.replacen(remote.as_str(), local, 1))
+            }
+            _ => PathBuf::from(remote_path),
+        }
+    }
+}
+
+/// `POST /webhook/torrent-finished?hash=` for qBittorrent's "Run external program on torrent finished"
+#[serde_inline_default]
+#[derive(Debug, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct WebhookConf {
+    /// Sent in the `X-Webhook-Secret` header
+    secret: String,
+    /// Seconds between the checks of movie tracking, which only reconciles missed calls when the webhook is used
+    #[serde_inline_default(600)]
+    reconcile_interval: usize,
+}
+
+/// Searches torrents with the plugins installed in a qBittorrent download client
+#[serde_inline_default]
+#[derive(Debug, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct QbittorrentSearchConf {
+    /// The name of a qBittorrent download client, the first one when unset
+    #[serde(default)]
+    client: Option<String>,
+    /// Plugin names, every enabled plugin when empty
+    #[serde(default)]
+    plugins: Vec<String>,
+    /// Seconds before a search is stopped with the results found so far
+    #[serde_inline_default(20)]
+    timeout: u64,
+}
+
+impl QbittorrentSearchConf {
+    fn uses(&self, client_name: &str) -> bool {
+        self.client.as_ref().is_none_or(|name| name == client_name)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataProviderKind {
+    /// The metadata proxy of Radarr, it needs no API key
+    #[default]
+    Radarr,
+    Tmdb,
+}
+
+/// Where movie metadata comes from
+#[derive(Debug, Serialize, Deserialize, Getters, Default)]
+#[get = "pub"]
+pub struct MetadataConf {
+    #[serde(default)]
+    provider: MetadataProviderKind,
+    /// `tmdb` needs either the v3 API key or the API read access token
+    #[serde(default)]
+    tmdb_api_key: Option<String>,
+    #[serde(default)]
+    tmdb_bearer_token: Option<String>,
+    /// Fills the IMDb, Metacritic and Rotten Tomatoes ratings TMDB does not have
+    #[serde(default)]
+    omdb_api_key: Option<String>,
+}
+
+impl MetadataConf {
+    /// `None` when `tmdb` is missing its credentials
+    fn backend(&self) -> Option<MetadataBackend> {
+        match self.provider {
+            MetadataProviderKind::Radarr => Some(MetadataBackend::Radarr),
+            MetadataProviderKind::Tmdb => self
+                .tmdb_bearer_token
+                .clone()
+                .map(TmdbAuth::BearerToken)
+                .or_else(|| self.tmdb_api_key.clone().map(TmdbAuth::ApiKey))
+                .map(MetadataBackend::Tmdb),
+        }
+    }
+}
+
+/// Caches the movie metadata fetched by TMDB and IMDb id
+#[serde_inline_default]
+#[derive(Debug, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct MovieInfoCacheConf {
+    /// Seconds a movie is cached, `0` disables the cache
+    #[serde_inline_default(86400)]
+    ttl: u64,
+    /// Keeps the cache across restarts in this JSON file
+    #[serde(default)]
+    path: Option<PathBuf>,
+}
+
+impl Default for MovieInfoCacheConf {
+    fn default() -> Self {
+        Self {
+            ttl: 86400,
+            path: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Getters, Default)]
+#[get = "pub"]
+pub struct RateLimitsConf {
+    #[serde(default)]
+    providers: HashMap<Provider, RateLimit>,
+    #[serde(default)]
+    hosts: HashMap<String, RateLimit>,
+    #[serde(default)]
+    movie_info: Option<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Getters, Default)]
+#[get = "pub"]
+pub struct ProxiesConf {
+    #[serde(default)]
+    providers: HashMap<Provider, Proxy>,
+    #[serde(default)]
+    movie_info: Option<Proxy>,
+    #[serde(default)]
+    qbittorrent: Option<Proxy>,
+    #[serde(default)]
+    transmission: Option<Proxy>,
+    #[serde(default)]
+    aria2: Option<Proxy>,
+}
+
 #[serde_inline_default]
 #[derive(Debug, Serialize, Deserialize, Getters)]
 #[get = "pub"]
 pub struct Config {
-    qbittorrent: QbittorrentConf,
+    /// Several named download clients, replaces `download_client` and its settings when set
+    #[serde(default)]
+    #[getset(skip)]
+    download_clients: Vec<DownloadClientConf>,
+    #[serde(default)]
+    download_client: DownloadClientKind,
+    #[serde(default)]
+    qbittorrent: Option<QbittorrentConf>,
+    #[serde(default)]
+    transmission: Option<TransmissionConf>,
+    #[serde(default)]
+    aria2: Option<Aria2Conf>,
+    #[serde(default)]
     remote_download_path: String,
+    #[serde(default)]
     local_download_path: String,
     #[serde_inline_default(vec!["US".to_string()].into_iter().collect())]
     languages: HashSet<String>,
@@ -58,6 +332,7 @@ pub struct Config {
     #[serde_inline_default(String::new())]
     category_after_import: String,
 
+    /// Hidden by default, shown movies without an IMDb id are searched by TMDB id or title
     #[serde_inline_default(true)]
     hide_movies_no_imdb: bool,
 
@@ -69,9 +344,90 @@ pub struct Config {
 
     #[serde(default)]
     subtitle_language_map: HashMap<String, SerdeRegex>,
+
+    #[serde(default)]
+    rate_limits: RateLimitsConf,
+
+    #[serde(default)]
+    proxies: ProxiesConf,
+
+    #[serde(default)]
+    ranking: Ranking,
+
+    #[serde(default)]
+    speed_schedule: SpeedScheduleConf,
+
+    #[serde(default)]
+    webhook: Option<WebhookConf>,
+
+    #[serde(default)]
+    qbittorrent_search: Option<QbittorrentSearchConf>,
+
+    #[serde(default)]
+    metadata: MetadataConf,
+
+    #[serde(default)]
+    movie_info_cache: MovieInfoCacheConf,
+}
+
+fn non_empty(path: &str) -> Option<String> {
+    (!path.is_empty()).then(|| path.to_owned())
 }
 
 impl Config {
+    /// The configured download clients, the first one is the default.
+    ///
+    /// Without `download_clients`, the single client selected by `download_client` is named after its type.
+    pub fn download_clients(&self) -> Vec<DownloadClientConf> {
+        let clients = if self.download_clients.is_empty() {
+            let (settings, proxy) = match self.download_client {
+                DownloadClientKind::Qbittorrent => (
+                    self.qbittorrent
+                        .clone()
+                        .map(DownloadClientSettings::Qbittorrent),
+                    self.proxies.qbittorrent.clone(),
+                ),
+                DownloadClientKind::Transmission => (
+                    self.transmission
+                        .clone()
+                        .map(DownloadClientSettings::Transmission),
+                    self.proxies.transmission.clone(),
+                ),
+                DownloadClientKind::Aria2 => (
+                    self.aria2.clone().map(DownloadClientSettings::Aria2),
+                    self.proxies.aria2.clone(),
+                ),
+            };
+
+            settings
+                .map(|settings| DownloadClientConf {
+                    name: serde_variant::to_variant_name(&self.download_client)
+                        .unwrap_or_default()
+                        .to_string(),
+                    settings,
+                    remote_download_path: None,
+                    local_download_path: None,
+                    proxy,
+                    routing: RoutingRules::default(),
+                })
+                .into_iter()
+                .collect()
+        } else {
+            self.download_clients.clone()
+        };
+
+        clients
+            .into_iter()
+            .map(|mut client| {
+                if client.remote_download_path.is_none() && client.local_download_path.is_none() {
+                    client.remote_download_path = non_empty(&self.remote_download_path);
+                    client.local_download_path = non_empty(&self.local_download_path);
+                }
+                client
+            })
+            .collect()
+    }
+
     pub fn filters(&self) -> Filters {
         Filters::new(
             *self.hide_movies_no_imdb(),
@@ -79,9 +435,53 @@ impl Config {
             self.languages().iter().cloned().collect(),
         )
     }
+
+    /// The qBittorrent provider searches with the client chosen in `qbittorrent_search`
+    pub fn torrent_client_options(
+        &self,
+        download_clients: &[DownloadClientInstance],
+    ) -> TorrentClientOptions {
+        let mut options = TorrentClientOptions::default();
+
+        options
+            .set_rate_limits(self.rate_limits().providers().clone())
+            .set_host_rate_limits(self.rate_limits().hosts().clone())
+            .set_proxies(self.proxies().providers().clone());
+
+        if let Some(search) = &self.qbittorrent_search {
+            let client = download_clients
+                .iter()
+                .filter(|instance| search.uses(instance.name()))
+                .find_map(|instance| instance.qbittorrent().ok());
+
+            options.set_qbittorrent_search(client.map(|client| {
+                let mut search_options = QbittorrentSearchOptions::new(client.clone());
+                search_options
+                    .set_plugins(search.plugins().clone())
+                    .set_timeout(Duration::from_secs(*search.timeout()));
+                search_options
+            }));
+        }
+
+        options
+    }
+
+    pub fn movie_info_client_options(&self) -> MovieInfoClientOptions {
+        let mut options = MovieInfoClientOptions::default();
+
+        options
+            .set_backend(self.metadata().backend().unwrap_or_default())
+            .set_omdb_api_key(self.metadata().omdb_api_key().clone())
+            .set_rate_limit(*self.rate_limits().movie_info())
+            .set_proxy(self.proxies().movie_info().clone())
+            .set_cache_ttl(Duration::from_secs(*self.movie_info_cache().ttl()))
+            .set_cache_path(self.movie_info_cache().path().clone());
+
+        options
+    }
 }
 
-pub fn get_config() -> Result<Config, Error> {
+pub fn get_config() -> Result<Config, Box<Error>> {
     let figment = figment::Figment::new()
         .merge(Env::raw())
         .merge(Env::raw().split("_"))
@@ -89,19 +489,229 @@ pub fn get_config() -> Result<Config, Error> {
 
     let config: Config = figment.extract()?;
 
-    // category != category_after_import
-    if config.category_after_import() == config.qbittorrent().category() {
-        error!("category_after_import cannot be the same as category");
+    let download_clients = config.download_clients();
+
+    if download_clients.is_empty() {
+        error!(
+            "download_client is {:?} but it is not configured",
+            config.download_client()
+        );
         std::process::exit(1);
     }
 
+    let mut names = HashSet::new();
+    for client in &download_clients {
+        if client.name().is_empty() || !names.insert(client.name()) {
+            error!("Download client names must be unique and not empty");
+            std::process::exit(1);
+        }
+
+        // category != category_after_import
+        if config.category_after_import() == client.category() {
+            error!("category_after_import cannot be the same as category");
+            std::process::exit(1);
+        }
+    }
+
     // !delete_torrent_after_import && delete_torrent_files
     if !config.delete_torrent_after_import() && *config.delete_torrent_files() {
         error!("delete_torrent_files cannot be true if delete_torrent_after_import is false");
         std::process::exit(1);
     }
 
+    if config
+        .webhook()
+        .as_ref()
+        .is_some_and(|webhook| webhook.secret().is_empty())
+    {
+        error!("webhook.secret cannot be empty");
+        std::process::exit(1);
+    }
+
+    if let Some(search) = config.qbittorrent_search() {
+        let has_client = download_clients.iter().any(|client| {
+            client.settings().kind() == DownloadClientKind::Qbittorrent
+                && search.uses(client.name())
+        });
+        if !has_client {
+            error!("qbittorrent_search needs a qBittorrent download client");
+            std::process::exit(1);
+        }
+    }
+
+    if config.metadata().backend().is_none() {
+        error!("metadata.provider tmdb needs tmdb_api_key or tmdb_bearer_token");
+        std::process::exit(1);
+    }
+
+    // aria2 cannot delete the files of a download
+    let uses_aria2 = download_clients
+        .iter()
+        .any(|client| client.settings().kind() == DownloadClientKind::Aria2);
+    if uses_aria2 && *config.delete_torrent_files() {
+        warn!(
+            "delete_torrent_files is not supported by aria2, the files of its downloads are kept"
+        );
+    }
+
     debug!("{config:#?}");
 
     Ok(config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_single_download_client() {
+        let config: Config = serde_json::from_value(json!({
+            "qbittorrent": { "url": "http://localhost:8080" },
+            "remote_download_path": "/downloads",
+            "local_download_path": "/mnt/downloads",
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+
+        let clients = config.download_clients();
+        assert_eq!(clients.len(), 1);
+        assert_eq!(clients[0].name(), "qbittorrent");
+        assert_eq!(clients[0].category(), "torrent-api");
+        assert_eq!(
+            clients[0].local_path("/downloads/Movie/movie.mkv"),
+            PathBuf::from("/mnt/downloads/Movie/movie.mkv")
+        );
+    }
+
+    #[test]
+    fn test_download_clients() {
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                {
+                    "name": "seedbox",
+                    "type": "qbittorrent",
+                    "url": "http://seedbox:8080",
+                    "category": "movies",
+                    "remote_download_path": "/home/user/downloads",
+                    "local_download_path": "/mnt/seedbox",
+                    "routing": { "qualities": ["2160p"] },
+                },
+                {
+                    "name": "home",
+                    "type": "transmission",
+                    "url": "http://localhost:9091/transmission/rpc",
+                },
+            ],
+            "remote_download_path": "/downloads",
+            "local_download_path": "/downloads",
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+
+        let clients = config.download_clients();
+        assert_eq!(clients.len(), 2);
+
+        assert_eq!(
+            clients[0].settings().kind(),
+            DownloadClientKind::Qbittorrent
+        );
+        assert_eq!(clients[0].category(), "movies");
+        assert_eq!(
+            clients[0].local_path("/home/user/downloads/Movie"),
+            PathBuf::from("/mnt/seedbox/Movie")
+        );
+
+        assert_eq!(
+            clients[1].settings().kind(),
+            DownloadClientKind::Transmission
+        );
+        assert_eq!(
+            clients[1].local_path("/downloads/Movie"),
+            PathBuf::from("/downloads/Movie")
+        );
+        assert!(clients[1].routing().is_empty());
+    }
+
+    #[test]
+    fn test_qbittorrent_search() {
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                {
+                    "name": "home",
+                    "type": "transmission",
+                    "url": "http://localhost:9091/transmission/rpc",
+                },
+                {
+                    "name": "seedbox",
+                    "type": "qbittorrent",
+                    "url": "http://seedbox:8080",
+                },
+            ],
+            "movies_path": "/movies",
+            "qbittorrent_search": { "plugins": ["piratebay"] },
+        }))
+        .unwrap();
+
+        let download_clients = crate::download_client::from_config(&config);
+        let options = config.torrent_client_options(&download_clients);
+
+        let search = options.qbittorrent_search().as_ref().unwrap();
+        assert_eq!(search.plugins(), &["piratebay"]);
+        assert_eq!(search.timeout(), &Duration::from_secs(20));
+    }
+
+    #[test]
+    fn test_movie_info_cache() {
+        let config: Config = serde_json::from_value(json!({
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+        let options = config.movie_info_client_options();
+        assert_eq!(options.cache_ttl(), &movie_info::DEFAULT_CACHE_TTL);
+        assert!(options.cache_path().is_none());
+
+        let config: Config = serde_json::from_value(json!({
+            "movies_path": "/movies",
+            "movie_info_cache": { "ttl": 0, "path": "/config/movie-cache.json" },
+        }))
+        .unwrap();
+        let options = config.movie_info_client_options();
+        assert_eq!(options.cache_ttl(), &Duration::ZERO);
+        assert_eq!(
+            options.cache_path().as_deref(),
+            Some(std::path::Path::new("/config/movie-cache.json"))
+        );
+    }
+
+    #[test]
+    fn test_metadata() {
+        let config: Config = serde_json::from_value(json!({
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+        assert!(matches!(
+            config.movie_info_client_options().backend(),
+            MetadataBackend::Radarr
+        ));
+
+        let config: Config = serde_json::from_value(json!({
+            "movies_path": "/movies",
+            "metadata": { "provider": "tmdb", "omdb_api_key": "omdb" },
+        }))
+        .unwrap();
+        assert!(config.metadata().backend().is_none());
+
+        let config: Config = serde_json::from_value(json!({
+            "movies_path": "/movies",
+            "metadata": { "provider": "tmdb", "tmdb_api_key": "key", "omdb_api_key": "omdb" },
+        }))
+        .unwrap();
+        let options = config.movie_info_client_options();
+        assert!(matches!(
+            options.backend(),
+            MetadataBackend::Tmdb(TmdbAuth::ApiKey(key)) if key == "key"
+        ));
+        assert_eq!(options.omdb_api_key().as_deref(), Some("omdb"));
+    }
+}
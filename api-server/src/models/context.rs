@@ -1,10 +1,12 @@
+use super::{download_torrent::DownloadTorrent, http_error::HttpErrorKind};
+use crate::download_client::DownloadClientInstance;
 use getset::Getters;
-use log::info;
+use log::{info, warn};
 use movie_info::MovieInfoClient;
 use qbittorrent_api::QbittorrentClient;
 use std::sync::Arc;
 use tokio::sync::{Mutex, Notify};
-use torrent_search_client::TorrentClient;
+use torrent_search_client::{Quality, TorrentClient};
 
 use super::config::Config;
 
@@ -12,7 +14,7 @@ use super::config::Config;
 #[get = "pub"]
 pub struct Context {
     torrent_client: TorrentClient,
-    qbittorrent_client: QbittorrentClient,
+    download_clients: Vec<DownloadClientInstance>,
     movie_info_client: MovieInfoClient,
     config: Config,
     movie_tracking_enabled: Mutex<bool>,
@@ -22,19 +24,149 @@ pub struct Context {
 impl Context {
     pub fn new(
         torrent_client: TorrentClient,
-        qbittorrent_client: QbittorrentClient,
+        download_clients: Vec<DownloadClientInstance>,
+        movie_info_client: MovieInfoClient,
         config: Config,
     ) -> Self {
         Self {
             torrent_client,
-            qbittorrent_client,
-            movie_info_client: MovieInfoClient::new(),
+            download_clients,
+            movie_info_client,
             config,
             movie_tracking_enabled: Mutex::new(true),
             movie_tracking_ntfy: Arc::new(Notify::new()),
         }
     }
 
+    /// The client named `name`, or the default one
+    pub fn download_client(
+        &self,
+        name: Option<&str>,
+    ) -> Result<&DownloadClientInstance, HttpErrorKind> {
+        match name {
+            Some(name) => self
+                .download_clients
+                .iter()
+                .find(|client| client.name() == name)
+                .ok_or_else(|| HttpErrorKind::unknown_download_client(name)),
+            None => self
+                .download_clients
+                .first()
+                .ok_or_else(|| HttpErrorKind::unknown_download_client("default")),
+        }
+    }
+
+    /// The first client among the `eligible` ones whose routing rules match the torrent, or else without rules
+    fn route_matching<F>(
+        &self,
+        torrent_name: &str,
+        size: Option<u64>,
+        eligible: F,
+    ) -> Option<&DownloadClientInstance>
+    where
+        F: Fn(&DownloadClientInstance) -> bool,
+    {
+        let quality = Quality::from(torrent_name);
+        let mut clients = self
+            .download_clients
+            .iter()
+            .filter(|client| eligible(client));
+
+        let matched = clients.clone().find(|client| {
+            let rules = client.conf().routing();
+            !rules.is_empty() && rules.matches(quality, size)
+        });
+
+        matched.or_else(|| clients.find(|client| client.conf().routing().is_empty()))
+    }
+
+    /// The client chosen by `name`, otherwise the first one whose routing rules match the torrent.
+    ///
+    /// Torrents no rule matches go to the first client without rules, or to the default client.
+    pub fn route(
+        &self,
+        name: Option<&str>,
+        torrent_name: &str,
+        size: Option<u64>,
+    ) -> Result<&DownloadClientInstance, HttpErrorKind> {
+        if name.is_some() {
+            return self.download_client(name);
+        }
+
+        match self.route_matching(torrent_name, size, |_| true) {
+            Some(client) => Ok(client),
+            None => self.download_client(None),
+        }
+    }
+
+    /// Same as `route` among the qBittorrent clients, for the features only qBittorrent supports
+    pub fn route_qbittorrent(
+        &self,
+        name: Option<&str>,
+        torrent_name: &str,
+        size: Option<u64>,
+    ) -> Result<&DownloadClientInstance, HttpErrorKind> {
+        if name.is_some() {
+            return self.qbittorrent_instance(name);
+        }
+
+        let is_qbittorrent =
+            |client: &DownloadClientInstance| client.client().qbittorrent().is_some();
+        match self.route_matching(torrent_name, size, is_qbittorrent) {
+            Some(client) => Ok(client),
+            None => self.qbittorrent_instance(None),
+        }
+    }
+
+    /// The torrents of every reachable download client, the others are logged and left out
+    pub async fn torrents(&self) -> Vec<DownloadTorrent> {
+        let mut torrents = Vec::new();
+
+        for client in &self.download_clients {
+            match client.torrents().await {
+                Ok(client_torrents) => torrents.extend(client_torrents),
+                Err(error) => {
+                    warn!("Could not get the torrents of {}: {error:?}", client.name())
+                }
+            }
+        }
+
+        torrents
+    }
+
+    pub fn qbittorrent_clients(&self) -> impl Iterator<Item = &QbittorrentClient> {
+        self.download_clients
+            .iter()
+            .filter_map(|client| client.client().qbittorrent())
+    }
+
+    /// The qBittorrent client named `name`, or the first qBittorrent client, with its settings
+    pub fn qbittorrent_instance(
+        &self,
+        name: Option<&str>,
+    ) -> Result<&DownloadClientInstance, HttpErrorKind> {
+        match name {
+            Some(_) => {
+                let instance = self.download_client(name)?;
+                instance.qbittorrent()?;
+                Ok(instance)
+            }
+            None => self
+                .download_clients
+                .iter()
+                .find(|client| client.client().qbittorrent().is_some())
+                .ok_or_else(HttpErrorKind::qbittorrent_only),
+        }
+    }
+
+    /// For the features only qBittorrent supports, the qBittorrent client named `name` or the first one
+    pub fn qbittorrent_client(
+        &self,
+        name: Option<&str>,
+    ) -> Result<&QbittorrentClient, HttpErrorKind> {
+        self.qbittorrent_instance(name)?.qbittorrent()
+    }
+
     pub async fn enable_movie_tracking(&self) {
         if !*self.movie_tracking_enabled.lock().await {
             info!("Enabling movie progress tracking");
@@ -43,6 +175,16 @@ impl Context {
         }
     }
 
+    /// Checks the torrents right away, e.g. when the download client reports a finished download
+    pub async fn wake_movie_tracking(&self) {
+        if *self.movie_tracking_enabled.lock().await {
+            // Stores a permit when the check is already running, so the wake up is not lost
+            self.movie_tracking_ntfy.notify_one();
+        } else {
+            self.enable_movie_tracking().await;
+        }
+    }
+
     pub async fn disable_movie_tracking(&self) {
         if *self.movie_tracking_enabled.lock().await {
             info!("Disabling movie progress tracking");
@@ -53,3 +195,200 @@ impl Context {
 }
 
 pub type ContextPointer = Arc<Context>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::download_client;
+    use fake_qbittorrent::{FakeQbittorrent, PASSWORD, USERNAME};
+    use serde_json::json;
+
+    const GIB: u64 = 1 << 30;
+
+    fn context(seedbox: &FakeQbittorrent, home: &FakeQbittorrent) -> Context {
+        let client = |name: &str, server: &FakeQbittorrent, routing| {
+            json!({
+                "name": name,
+                "type": "qbittorrent",
+                "username": USERNAME,
+                "password": PASSWORD,
+                "url": server.url(),
+                "routing": routing,
+            })
+        };
+
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                client("seedbox", seedbox, json!({ "qualities": ["2160p"], "min_size": 10 * GIB })),
+                client("home", home, json!({})),
+            ],
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+
+        Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_route() {
+        let (seedbox, home) = (
+            FakeQbittorrent::start().await,
+            FakeQbittorrent::start().await,
+        );
+        let context = context(&seedbox, &home);
+
+        let route = |name, size| context.route(None, name, size).unwrap().name().to_owned();
+        assert_eq!(route("Movie.2160p.BluRay", Some(20 * GIB)), "seedbox");
+        assert_eq!(route("Movie.2160p.BluRay", Some(5 * GIB)), "home");
+        assert_eq!(route("Movie.1080p.BluRay", Some(20 * GIB)), "home");
+
+        let chosen = context.route(Some("seedbox"), "Movie.720p", None).unwrap();
+        assert_eq!(chosen.name(), "seedbox");
+        assert!(context.route(Some("missing"), "Movie", None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_torrents_of_every_client() {
+        let (seedbox, home) = (
+            FakeQbittorrent::start().await,
+            FakeQbittorrent::start().await,
+        );
+        seedbox.add_torrent("aaaa", "Seedbox Movie", "torrent-api");
+        home.add_torrent("bbbb", "Home Movie", "torrent-api");
+        let context = context(&seedbox, &home);
+
+        let mut torrents: Vec<(String, String)> = context
+            .torrents()
+            .await
+            .into_iter()
+            .map(|torrent| {
+                (
+                    torrent.get_client().to_owned(),
+                    torrent.get_hash().to_owned(),
+                )
+            })
+            .collect();
+        torrents.sort();
+
+        assert_eq!(
+            torrents,
+            vec![
+                ("home".to_string(), "bbbb".to_string()),
+                ("seedbox".to_string(), "aaaa".to_string())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_route_qbittorrent_with_mixed_clients() {
+        let server = FakeQbittorrent::start().await;
+
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                { "name": "transmission", "type": "transmission", "url": "http://127.0.0.1:1/transmission/rpc" },
+                {
+                    "name": "home",
+                    "type": "qbittorrent",
+                    "username": USERNAME,
+                    "password": PASSWORD,
+                    "url": server.url(),
+                    "routing": { "qualities": ["2160p"] },
+                },
+            ],
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+        let context = Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        );
+
+        // The catch-all Transmission client is skipped for the qBittorrent only features
+        assert_eq!(
+            context.route(None, "Movie.1080p", None).unwrap().name(),
+            "transmission"
+        );
+        let route = |name| {
+            context
+                .route_qbittorrent(None, name, None)
+                .unwrap()
+                .name()
+                .to_owned()
+        };
+        assert_eq!(route("Movie.1080p"), "home");
+        assert_eq!(route("Movie.2160p"), "home");
+        assert!(context
+            .route_qbittorrent(Some("transmission"), "Movie", None)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_torrents_skips_unreachable_client() {
+        let home = FakeQbittorrent::start().await;
+        home.add_torrent("bbbb", "Home Movie", "torrent-api");
+
+        let client = |name: &str, url: &str| {
+            json!({
+                "name": name,
+                "type": "qbittorrent",
+                "username": USERNAME,
+                "password": PASSWORD,
+                "url": url,
+            })
+        };
+        let config: Config = serde_json::from_value(json!({
+            "download_clients": [
+                client("offline", "http://127.0.0.1:1"),
+                client("home", home.url()),
+            ],
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+        let context = Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        );
+
+        let torrents = context.torrents().await;
+
+        assert_eq!(torrents.len(), 1);
+        assert_eq!(torrents[0].get_client(), "home");
+        assert_eq!(torrents[0].get_hash(), "bbbb");
+    }
+
+    #[tokio::test]
+    async fn test_qbittorrent_client_by_name() {
+        let (seedbox, home) = (
+            FakeQbittorrent::start().await,
+            FakeQbittorrent::start().await,
+        );
+        seedbox.add_torrent("aaaa", "Seedbox Movie", "torrent-api");
+        home.add_torrent("bbbb", "Home Movie", "torrent-api");
+        let context = &context(&seedbox, &home);
+
+        let hashes = |name| async move {
+            context
+                .qbittorrent_client(name)
+                .unwrap()
+                .torrents(&Default::default())
+                .await
+                .unwrap()
+                .iter()
+                .map(|torrent| torrent.get_hash().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hashes(Some("home")).await, ["bbbb"]);
+        assert_eq!(hashes(None).await, ["aaaa"]);
+
+        assert!(context.qbittorrent_client(Some("missing")).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use async_graphql::{Enum, SimpleObject};
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use serde::Serialize;
+
+/// qBittorrent's ETA for torrents that will not finish
+pub const ETA_UNKNOWN: usize = 8640000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Enum)]
+pub enum DownloadState {
+    Downloading,
+    /// Downloading, but no connection were made
+    Stalled,
+    Queued,
+    Checking,
+    Moving,
+    Seeding,
+    /// Finished and no longer seeding
+    Completed,
+    Paused,
+    Error,
+    Unknown,
+}
+
+impl DownloadState {
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            DownloadState::Downloading | DownloadState::Checking | DownloadState::Unknown
+        )
+    }
+}
+
+/// A torrent of any download client
+#[derive(Debug, Clone, Serialize, SimpleObject, Getters)]
+#[get = "pub with_prefix"]
+pub struct DownloadTorrent {
+    /// The name of the download client the torrent is in
+    client: String,
+    hash: String,
+    name: String,
+    category: String,
+    tags: Vec<String>,
+    state: DownloadState,
+    /// Why the torrent is in the error state, from the log of qBittorrent or the error of the other clients
+    error_messages: Vec<String>,
+    progress: f64,
+    /// In seconds, `8640000` when unknown
+    eta: usize,
+    /// Path of the file, or of the folder for torrents with several files, as seen by the download client
+    content_path: String,
+    size: u64,
+    /// Speeds are in bytes per second
+    dlspeed: u64,
+    upspeed: u64,
+    added_on: DateTime<Utc>,
+}
+
+impl From<&qbittorrent_api::Torrent> for DownloadTorrent {
+    fn from(torrent: &qbittorrent_api::Torrent) -> Self {
+        use qbittorrent_api::TorrentState;
+
+        let state = match torrent.get_state() {
+            TorrentState::Allocating
+            | TorrentState::Downloading
+            | TorrentState::MetaDL
+            | TorrentState::ForcedDL => DownloadState::Downloading,
+            TorrentState::StalledDL => DownloadState::Stalled,
+            TorrentState::QueuedDL | TorrentState::QueuedUP => DownloadState::Queued,
+            TorrentState::CheckingDL
+            | TorrentState::CheckingUP
+            | TorrentState::CheckingResumeData => DownloadState::Checking,
+            TorrentState::Moving => DownloadState::Moving,
+            TorrentState::Uploading | TorrentState::StalledUP | TorrentState::ForcedUP => {
+                DownloadState::Seeding
+            }
+            TorrentState::PausedDL | TorrentState::PausedUP => DownloadState::Paused,
+            TorrentState::Error | TorrentState::MissingFiles => DownloadState::Error,
+            TorrentState::Unknown => DownloadState::Unknown,
+        };
+
+        Self {
+            client: String::new(),
+            hash: torrent.get_hash().to_owned(),
+            name: torrent.get_name().to_owned(),
+            category: torrent.get_category().to_owned(),
+            tags: torrent.tag_list().into_iter().map(String::from).collect(),
+            state,
+            // Correlated with the log by `add_log_messages`, reading the log is not needed for every use
+            error_messages: Vec::new(),
+            progress: *torrent.get_progress(),
+            eta: *torrent.get_eta(),
+            content_path: torrent.get_content_path().to_owned(),
+            size: *torrent.get_size(),
+            dlspeed: *torrent.get_dlspeed() as u64,
+            upspeed: *torrent.get_upspeed() as u64,
+            added_on: *torrent.get_added_on(),
+        }
+    }
+}
+
+/// Transmission only has labels, the first label is used as the category and the others as tags
+impl From<&transmission_api::Torrent> for DownloadTorrent {
+    fn from(torrent: &transmission_api::Torrent) -> Self {
+        use transmission_api::TorrentStatus;
+
+        let state = match torrent.get_status() {
+            _ if torrent.has_error() => DownloadState::Error,
+            TorrentStatus::Stopped => DownloadState::Paused,
+            TorrentStatus::CheckWait | TorrentStatus::Check => DownloadState::Checking,
+            TorrentStatus::DownloadWait | TorrentStatus::SeedWait => DownloadState::Queued,
+            TorrentStatus::Download if *torrent.get_rate_download() == 0 => DownloadState::Stalled,
+            TorrentStatus::Download => DownloadState::Downloading,
+            TorrentStatus::Seed => DownloadState::Seeding,
+        };
+
+        let mut labels = torrent.get_labels().iter();
+
+        Self {
+            client: String::new(),
+            hash: torrent.get_hash().to_owned(),
+            name: torrent.get_name().to_owned(),
+            category: labels.next().cloned().unwrap_or_default(),
+            tags: labels.cloned().collect(),
+            state,
+            error_messages: if torrent.has_error() {
+                vec![torrent.get_error_string().to_owned()]
+            } else {
+                Vec::new()
+            },
+            progress: *torrent.get_percent_done(),
+            eta: usize::try_from(*torrent.get_eta()).unwrap_or(ETA_UNKNOWN),
+            content_path: torrent.content_path(),
+            size: *torrent.get_size_when_done(),
+            dlspeed: *torrent.get_rate_download(),
+            upspeed: *torrent.get_rate_upload(),
+            added_on: *torrent.get_added_date(),
+        }
+    }
+}
+
+impl DownloadTorrent {
+    pub fn with_client(mut self, client: &str) -> Self {
+        self.client = client.to_owned();
+        self
+    }
+
+    pub fn set_error_messages(&mut self, error_messages: Vec<String>) {
+        self.error_messages = error_messages;
+    }
+
+    /// aria2 has no labels, the category and tags are read from the download directory by the caller
+    pub fn from_aria2(download: &aria2_api::Download, category: String, tags: Vec<String>) -> Self {
+        use aria2_api::DownloadStatus;
+
+        let progress = download.progress();
+
+        let state = match download.get_status() {
+            DownloadStatus::Active if progress >= 1.0 => DownloadState::Seeding,
+            DownloadStatus::Active if *download.get_download_speed() == 0 => DownloadState::Stalled,
+            DownloadStatus::Active => DownloadState::Downloading,
+            DownloadStatus::Waiting => DownloadState::Queued,
+            DownloadStatus::Paused => DownloadState::Paused,
+            DownloadStatus::Error => DownloadState::Error,
+            DownloadStatus::Complete => DownloadState::Completed,
+            DownloadStatus::Removed | DownloadStatus::Unknown => DownloadState::Unknown,
+        };
+
+        Self {
+            client: String::new(),
+            hash: download.get_gid().to_owned(),
+            name: download.name(),
+            category,
+            tags,
+            state,
+            error_messages: download
+                .get_error_message()
+                .iter()
+                .filter(|_| state == DownloadState::Error)
+                .cloned()
+                .collect(),
+            progress,
+            eta: download
+                .eta()
+                .and_then(|eta| usize::try_from(eta).ok())
+                .unwrap_or(ETA_UNKNOWN),
+            content_path: download.content_path(),
+            size: *download.get_total_length(),
+            dlspeed: *download.get_download_speed(),
+            upspeed: *download.get_upload_speed(),
+            // aria2 does not keep track of when downloads were added
+            added_on: DateTime::<Utc>::default(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use movie_info::TmdbId;
 use std::{fmt::Display, io::Error as IoError};
 use torrent_search_client::InvalidOptionError;
 
@@ -6,12 +7,17 @@ pub enum HttpErrorKind {
     InvalidParam(String),
     MissingQuery(String),
     QbittorrentError(qbittorrent_api::Error),
+    TransmissionError(transmission_api::Error),
+    Aria2Error(aria2_api::Error),
+    /// The feature is not supported by the configured download client
+    Unsupported(String),
     IoError(IoError),
     InvalidMagnet(String),
     MovieFileNotFound(String),
     TorrentNotFound(String),
     MovieInfoError(movie_info::Error),
     ImdbNotFound(String),
+    TmdbNotFound(String),
 }
 
 impl HttpErrorKind {
@@ -19,16 +25,39 @@ impl HttpErrorKind {
         Self::InvalidParam(format!("Incorrect param: {param}"))
     }
     pub fn missing_query() -> Self {
-        Self::MissingQuery("At least `imdb` or `query` must be defined.".into())
+        Self::MissingQuery("At least `query`, `imdb`, `tmdb` or `title` must be defined.".into())
     }
     pub fn imdb_not_found(imdb: String) -> Self {
         Self::ImdbNotFound(format!("IMDB ID not found: {imdb}"))
     }
+    pub fn qbittorrent_only() -> Self {
+        Self::Unsupported("Only supported when the download client is qBittorrent".into())
+    }
+    pub fn unknown_download_client(name: &str) -> Self {
+        Self::InvalidParam(format!("Unknown download client: {name}"))
+    }
+    pub fn tmdb_not_found(tmdb: TmdbId) -> Self {
+        Self::TmdbNotFound(format!("TMDB ID not found: {tmdb}"))
+    }
 }
 
 impl Display for HttpErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::InvalidParam(message) => write!(f, "InvalidParam: {message}"),
+            Self::MissingQuery(message) => write!(f, "MissingQuery: {message}"),
+            Self::QbittorrentError(error) => write!(f, "QbittorrentError: {error}"),
+            Self::TransmissionError(error) => write!(f, "TransmissionError: {error}"),
+            Self::Aria2Error(error) => write!(f, "Aria2Error: {error}"),
+            Self::Unsupported(message) => write!(f, "Unsupported: {message}"),
+            Self::IoError(error) => write!(f, "IoError: {error}"),
+            Self::InvalidMagnet(message) => write!(f, "InvalidMagnet: {message}"),
+            Self::MovieFileNotFound(message) => write!(f, "MovieFileNotFound: {message}"),
+            Self::TorrentNotFound(message) => write!(f, "TorrentNotFound: {message}"),
+            Self::MovieInfoError(error) => write!(f, "MovieInfoError: {error}"),
+            Self::ImdbNotFound(message) => write!(f, "ImdbNotFound: {message}"),
+            Self::TmdbNotFound(message) => write!(f, "TmdbNotFound: {message}"),
+        }
     }
 }
 
@@ -43,6 +72,18 @@ impl From<qbittorrent_api::Error> for HttpErrorKind {
     }
 }
 
+impl From<transmission_api::Error> for HttpErrorKind {
+    fn from(err: transmission_api::Error) -> Self {
+        Self::TransmissionError(err)
+    }
+}
+
+impl From<aria2_api::Error> for HttpErrorKind {
+    fn from(err: aria2_api::Error) -> Self {
+        Self::Aria2Error(err)
+    }
+}
+
 impl From<IoError> for HttpErrorKind {
     fn from(err: IoError) -> Self {
         Self::IoError(err)
@@ -1,9 +1,12 @@
 pub mod config;
 pub mod context;
+pub mod download_torrent;
 pub mod filter;
 pub mod http_error;
 pub mod movie_files;
 pub mod provider_error;
+pub mod routing_rules;
 pub mod search_torrents_parameters;
 pub mod serde_regex;
+pub mod speed_schedule;
 pub mod torrent_movie_info;
@@ -0,0 +1,66 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use torrent_search_client::Quality;
+
+/// Which torrents a download client takes when none is chosen explicitly, every set condition has to match
+#[derive(Debug, Serialize, Deserialize, Getters, Clone, Default)]
+#[get = "pub"]
+pub struct RoutingRules {
+    /// Empty for any quality
+    #[serde(default)]
+    qualities: Vec<Quality>,
+    /// Sizes are in bytes, torrents of unknown size do not match a size condition
+    #[serde(default)]
+    min_size: Option<u64>,
+    #[serde(default)]
+    max_size: Option<u64>,
+}
+
+impl RoutingRules {
+    /// A client without rules takes the torrents no other client matches
+    pub fn is_empty(&self) -> bool {
+        self.qualities.is_empty() && self.min_size.is_none() && self.max_size.is_none()
+    }
+
+    pub fn matches(&self, quality: Quality, size: Option<u64>) -> bool {
+        let quality_matches = self.qualities.is_empty() || self.qualities.contains(&quality);
+
+        let size_matches = match size {
+            Some(size) => {
+                self.min_size.is_none_or(|min| size >= min)
+                    && self.max_size.is_none_or(|max| size <= max)
+            }
+            None => self.min_size.is_none() && self.max_size.is_none(),
+        };
+
+        quality_matches && size_matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1 << 30;
+
+    #[test]
+    fn test_matches() {
+        let rules: RoutingRules =
+            serde_json::from_str(r#"{ "qualities": ["2160p"], "min_size": 10737418240 }"#).unwrap();
+
+        assert!(!rules.is_empty());
+        assert!(rules.matches(Quality::P2160, Some(20 * GIB)));
+        assert!(!rules.matches(Quality::P2160, Some(2 * GIB)));
+        assert!(!rules.matches(Quality::P1080, Some(20 * GIB)));
+        assert!(!rules.matches(Quality::P2160, None));
+    }
+
+    #[test]
+    fn test_empty_matches_everything() {
+        let rules = RoutingRules::default();
+
+        assert!(rules.is_empty());
+        assert!(rules.matches(Quality::Unknown, None));
+        assert!(rules.matches(Quality::P720, Some(GIB)));
+    }
+}
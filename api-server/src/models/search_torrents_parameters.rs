@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use async_graphql::InputObject;
 use getset::Getters;
+use movie_info::TmdbId;
 use torrent_search_client::{Category, Codec, Order, Provider, Quality, SortColumn, Source};
 
 #[derive(InputObject, Getters, Debug)]
@@ -9,6 +10,10 @@ use torrent_search_client::{Category, Codec, Order, Provider, Quality, SortColum
 pub struct SearchTorrentsParameters {
     query: Option<String>,
     imdb: Option<String>,
+    tmdb: Option<TmdbId>,
+    /// Searches for a movie by title, optionally narrowed down by `year`
+    title: Option<String>,
+    year: Option<u16>,
 
     #[graphql(default)]
     category: Category,
@@ -0,0 +1,125 @@
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
+use getset::Getters;
+use qbittorrent_api::SpeedLimits;
+use serde::{Deserialize, Serialize};
+
+/// A time window in which the speed is throttled, windows may cross midnight
+#[derive(Debug, Serialize, Deserialize, Getters, Clone)]
+#[get = "pub"]
+pub struct SpeedWindow {
+    /// The days the window starts on, empty for every day
+    #[serde(default)]
+    days: Vec<Weekday>,
+    start: NaiveTime,
+    end: NaiveTime,
+    /// Enables qBittorrent's alternative speed limits during the window
+    #[serde(default)]
+    alternative_speed: bool,
+    /// Global limits in bytes per second during the window
+    #[serde(default)]
+    limits: Option<SpeedLimits>,
+}
+
+impl SpeedWindow {
+    fn runs_on(&self, day: Weekday) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+
+    pub fn contains(&self, now: &NaiveDateTime) -> bool {
+        let time = now.time();
+        let today = now.weekday();
+
+        if self.start <= self.end {
+            self.runs_on(today) && time >= self.start && time < self.end
+        } else {
+            (self.runs_on(today) && time >= self.start)
+                || (self.runs_on(today.pred()) && time < self.end)
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Getters, Default)]
+#[get = "pub"]
+pub struct SpeedScheduleConf {
+    #[serde(default)]
+    windows: Vec<SpeedWindow>,
+    /// Global limits outside of the windows, only applied when a window sets limits
+    #[serde(default)]
+    limits: SpeedLimits,
+}
+
+/// What the schedule wants qBittorrent's speed settings to be
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedState {
+    pub alternative_speed: Option<bool>,
+    pub limits: Option<SpeedLimits>,
+}
+
+impl SpeedScheduleConf {
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Settings not used by any window are left untouched
+    pub fn state(&self, now: &NaiveDateTime) -> SpeedState {
+        let active = self.windows.iter().find(|window| window.contains(now));
+
+        let uses_alternative_speed = self.windows.iter().any(|w| w.alternative_speed);
+        let uses_limits = self.windows.iter().any(|w| w.limits.is_some());
+
+        SpeedState {
+            alternative_speed: uses_alternative_speed
+                .then(|| active.is_some_and(|w| w.alternative_speed)),
+            limits: uses_limits.then(|| active.and_then(|w| w.limits).unwrap_or(self.limits)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        // 2024-01-01 is a Monday
+        NaiveDate::from_ymd_opt(2024, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn schedule(json: &str) -> SpeedScheduleConf {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_weekday_window() {
+        let schedule = schedule(
+            r#"{ "windows": [{ "days": ["Mon", "Tue", "Wed", "Thu", "Fri"], "start": "08:00", "end": "18:00", "alternative_speed": true }] }"#,
+        );
+
+        assert_eq!(schedule.state(&at(1, 9, 0)).alternative_speed, Some(true));
+        assert_eq!(schedule.state(&at(1, 18, 0)).alternative_speed, Some(false));
+        assert_eq!(schedule.state(&at(6, 9, 0)).alternative_speed, Some(false));
+        assert_eq!(schedule.state(&at(1, 9, 0)).limits, None);
+    }
+
+    #[test]
+    fn test_midnight_window() {
+        let schedule = schedule(
+            r#"{
+                "windows": [{ "days": ["Fri"], "start": "22:00", "end": "06:00", "limits": { "download": 1000, "upload": 500 } }],
+                "limits": { "download": 0, "upload": 100 }
+            }"#,
+        );
+
+        let throttled = Some(SpeedLimits::new(1000, 500));
+        let default = Some(SpeedLimits::new(0, 100));
+
+        assert_eq!(schedule.state(&at(5, 23, 0)).limits, throttled);
+        assert_eq!(schedule.state(&at(6, 5, 59)).limits, throttled);
+        assert_eq!(schedule.state(&at(6, 6, 0)).limits, default);
+        assert_eq!(schedule.state(&at(4, 23, 0)).limits, default);
+        assert_eq!(schedule.state(&at(5, 23, 0)).alternative_speed, None);
+    }
+}
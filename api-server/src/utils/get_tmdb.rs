@@ -1,12 +1,41 @@
+use crate::models::download_torrent::DownloadTorrent;
 use lazy_static::lazy_static;
 use movie_info::TmdbId;
 use regex::Regex;
 lazy_static! {
     static ref IMDB_REGEX: Regex = Regex::new(r"\((\d{1,8})\)$").unwrap();
 }
-pub fn get_tmdb(name: &str) -> Option<TmdbId> {
+
+const TMDB_TAG_PREFIX: &str = "tmdb:";
+
+/// The tag used to mark a tracked movie
+pub fn tmdb_tag(tmdb: TmdbId) -> String {
+    format!("{TMDB_TAG_PREFIX}{tmdb}")
+}
+
+fn tmdb_from_name(name: &str) -> Option<TmdbId> {
     IMDB_REGEX
         .captures(name)
         .and_then(|c| c.get(1).map(|m| m.as_str().parse().ok()))
         .flatten()
 }
+
+/// Reads the TMDB id from the `tmdb:<id>` tag, falling back to the `"{name} ({tmdb})"` rename used before tags
+pub fn get_tmdb(torrent: &DownloadTorrent) -> Option<TmdbId> {
+    torrent
+        .get_tags()
+        .iter()
+        .find_map(|tag| tag.strip_prefix(TMDB_TAG_PREFIX)?.parse().ok())
+        .or_else(|| tmdb_from_name(torrent.get_name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tmdb_from_name() {
+        assert_eq!(tmdb_from_name("The Matrix (603)"), Some(603));
+        assert_eq!(tmdb_from_name("The Matrix (1999) 1080p"), None);
+    }
+}
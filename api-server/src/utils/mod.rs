@@ -1,4 +1,7 @@
 pub mod get_tmdb;
 pub mod import_movie;
+pub mod movie_rss_rule;
 pub mod parse_subtitle_language;
+pub mod read_upload;
+pub mod torrent_log;
 pub mod track_movie;
@@ -0,0 +1,76 @@
+use crate::utils::get_tmdb::tmdb_tag;
+use movie_info::TmdbId;
+use qbittorrent_api::RssRule;
+use serde_json::json;
+
+/// qBittorrent has no "first match only" option, ignoring matches for a century has the same effect
+const IGNORE_DAYS_AFTER_MATCH: i64 = 36500;
+
+/// The words of the title and the year, punctuation is left out since release names replace it with dots
+pub fn movie_must_contain(title: &str, year: u16) -> String {
+    let title: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let mut words: Vec<String> = title.split_whitespace().map(str::to_owned).collect();
+    words.push(year.to_string());
+
+    words.join(" ")
+}
+
+/// A rule downloading the movie into `category`, tagged for movie tracking like `trackMovie`.
+///
+/// Only the first matching release is downloaded. The tag is set through `torrentParams`, which needs qBittorrent 5.
+pub fn movie_rss_rule(
+    tmdb: TmdbId,
+    must_contain: String,
+    must_not_contain: String,
+    feeds: Vec<String>,
+    category: &str,
+) -> RssRule {
+    let mut rule = RssRule::default();
+
+    rule.set_enabled(true)
+        .set_must_contain(must_contain)
+        .set_must_not_contain(must_not_contain)
+        .set_affected_feeds(feeds)
+        .set_ignore_days(IGNORE_DAYS_AFTER_MATCH)
+        .set_assigned_category(category.to_owned())
+        .set_value(
+            "torrentParams",
+            json!({ "category": category, "tags": [tmdb_tag(tmdb)] }),
+        );
+
+    rule
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_movie_must_contain() {
+        assert_eq!(
+            movie_must_contain("Spider-Man: No Way Home", 2021),
+            "Spider Man No Way Home 2021"
+        );
+    }
+
+    #[test]
+    fn test_movie_rss_rule() {
+        let rule = movie_rss_rule(
+            603,
+            "Matrix 1999".into(),
+            "CAM".into(),
+            vec!["https://yts.example/rss".into()],
+            "torrent-api",
+        );
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert_eq!(json["enabled"], true);
+        assert_eq!(json["ignoreDays"], IGNORE_DAYS_AFTER_MATCH);
+        assert_eq!(json["assignedCategory"], "torrent-api");
+        assert_eq!(json["torrentParams"]["tags"][0], "tmdb:603");
+    }
+}
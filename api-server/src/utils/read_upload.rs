@@ -0,0 +1,15 @@
+use crate::models::http_error::HttpErrorKind;
+use async_graphql::{Context, Upload};
+use qbittorrent_api::TorrentUpload;
+use std::io::Read;
+
+/// Reads an uploaded `.torrent` file from a GraphQL multipart request
+pub fn read_upload(context: &Context<'_>, upload: &Upload) -> Result<TorrentUpload, HttpErrorKind> {
+    let value = upload.value(context)?;
+    let filename = value.filename.clone();
+
+    let mut content = Vec::new();
+    value.into_read().read_to_end(&mut content)?;
+
+    Ok(TorrentUpload::new(filename, content))
+}
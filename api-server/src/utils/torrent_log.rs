@@ -0,0 +1,130 @@
+use crate::models::{
+    context::Context,
+    download_torrent::{DownloadState, DownloadTorrent},
+};
+use log::warn;
+use qbittorrent_api::LogEntry;
+
+/// How many log messages are shown for a torrent
+const MAX_MESSAGES: usize = 5;
+
+/// The recent warnings and critical messages about the torrent, newest first.
+///
+/// qBittorrent names torrents in quotes, e.g. `File error alert. Torrent: "Name". Reason: "..."`.
+pub fn torrent_log_messages(entries: &[LogEntry], name: &str, hash: &str) -> Vec<String> {
+    let quoted_name = format!("\"{name}\"");
+    let hash = hash.to_lowercase();
+
+    entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.get_log_type().is_problem())
+        .filter(|entry| {
+            let message = entry.get_message();
+            message.contains(&quoted_name) || message.to_lowercase().contains(&hash)
+        })
+        .take(MAX_MESSAGES)
+        .map(|entry| entry.get_message().to_owned())
+        .collect()
+}
+
+/// Fills the error messages of the errored qBittorrent torrents from the log of their client
+pub async fn add_log_messages(context: &Context, torrents: &mut [DownloadTorrent]) {
+    for instance in context.download_clients() {
+        let Ok(qbittorrent) = instance.qbittorrent() else {
+            continue;
+        };
+
+        let mut errored = torrents.iter_mut().filter(|torrent| {
+            torrent.get_client() == instance.name() && *torrent.get_state() == DownloadState::Error
+        });
+
+        let Some(first) = errored.next() else {
+            continue;
+        };
+
+        let entries = match qbittorrent.recent_log().await {
+            Ok(entries) => entries,
+            Err(error) => {
+                warn!("Could not read the log of {}: {error}", instance.name());
+                continue;
+            }
+        };
+
+        for torrent in std::iter::once(first).chain(errored) {
+            let messages = torrent_log_messages(&entries, torrent.get_name(), torrent.get_hash());
+            torrent.set_error_messages(messages);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{download_client, models::config::Config};
+    use fake_qbittorrent::{FakeLogType, FakeQbittorrent, PASSWORD, USERNAME};
+    use movie_info::MovieInfoClient;
+    use serde_json::json;
+    use torrent_search_client::TorrentClient;
+
+    fn entry(id: i64, message: &str, log_type: u8) -> LogEntry {
+        serde_json::from_value(json!({
+            "id": id,
+            "message": message,
+            "timestamp": 0,
+            "type": log_type,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_torrent_log_messages() {
+        let entries = vec![
+            entry(0, "Added new torrent. Torrent: \"The Matrix (1999)\"", 2),
+            entry(1, "File error alert. Torrent: \"The Matrix (1999)\". Reason: \"No space left on device\"", 8),
+            entry(2, "File error alert. Torrent: \"Inception (2010)\"", 8),
+            entry(3, "Torrent errored. Torrent: \"abcdef\". Error: \"missing files\"", 4),
+        ];
+
+        let messages = torrent_log_messages(&entries, "The Matrix (1999)", "ABCDEF");
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("missing files"));
+        assert!(messages[1].contains("No space left"));
+    }
+
+    #[tokio::test]
+    async fn test_add_log_messages() {
+        let server = FakeQbittorrent::start().await;
+        server.add_torrent("aaaa", "Stuck Movie", "torrent-api");
+        server.add_torrent("bbbb", "Fine Movie", "torrent-api");
+        server.set_state("aaaa", Some("missingFiles"));
+        server.add_log(
+            "File error alert. Torrent: \"Stuck Movie\". Reason: \"No space left on device\"",
+            FakeLogType::Critical,
+        );
+        server.add_log(
+            "File error alert. Torrent: \"Fine Movie\"",
+            FakeLogType::Critical,
+        );
+
+        let config: Config = serde_json::from_value(json!({
+            "qbittorrent": { "url": server.url(), "username": USERNAME, "password": PASSWORD },
+            "movies_path": "/movies",
+        }))
+        .unwrap();
+        let context = Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        );
+
+        let mut torrents = context.torrents().await;
+        add_log_messages(&context, &mut torrents).await;
+        torrents.sort_by(|a, b| a.get_hash().cmp(b.get_hash()));
+
+        assert_eq!(torrents[0].get_error_messages().len(), 1);
+        assert!(torrents[0].get_error_messages()[0].contains("No space left"));
+        assert!(torrents[1].get_error_messages().is_empty());
+    }
+}
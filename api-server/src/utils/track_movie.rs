@@ -1,26 +1,57 @@
-use crate::models::{context::ContextPointer, http_error::HttpErrorKind};
+use crate::{
+    download_client::{DownloadClientInstance, DownloadOptions},
+    models::{context::ContextPointer, http_error::HttpErrorKind},
+    utils::get_tmdb::tmdb_tag,
+};
 use movie_info::TmdbId;
-use qbittorrent_api::AddTorrentOptions;
-use utils::magnet::Magnet;
+use qbittorrent_api::TorrentUpload;
+use utils::magnet::torrent_name;
+
+/// Where a tracked movie is downloaded, see `Context::route`
+#[derive(Debug, Default)]
+pub struct TrackRoute {
+    /// The name of the download client, picked by the routing rules when unset
+    pub client: Option<String>,
+    /// In bytes, for the size routing rules
+    pub size: Option<u64>,
+}
+
+fn tracking_options(client: &DownloadClientInstance, tmdb: TmdbId) -> DownloadOptions {
+    DownloadOptions {
+        category: Some(client.category().to_string()),
+        tags: vec![tmdb_tag(tmdb)],
+    }
+}
 
 pub async fn track_movie(
     ctx: &ContextPointer,
     url: String,
     tmdb: TmdbId,
+    route: TrackRoute,
 ) -> Result<(), HttpErrorKind> {
-    let magnet = Magnet::from_url(&url).map_err(HttpErrorKind::InvalidMagnet)?;
-    let display_name = magnet.display_name();
+    let name = torrent_name(&url).map_err(HttpErrorKind::InvalidMagnet)?;
 
-    let category = ctx.config().qbittorrent().category().to_string();
-    let qb = ctx.qbittorrent_client();
+    let client = ctx.route(route.client.as_deref(), &name, route.size)?;
+    client
+        .client()
+        .add_torrent(url, &tracking_options(client, tmdb))
+        .await?;
 
-    let mut options = AddTorrentOptions::default();
+    ctx.enable_movie_tracking().await;
 
-    options
-        .set_category(Some(category))
-        .set_rename(Some(format!("{display_name} ({tmdb})")));
+    Ok(())
+}
+
+pub async fn track_movie_file(
+    ctx: &ContextPointer,
+    file: TorrentUpload,
+    tmdb: TmdbId,
+    route: TrackRoute,
+) -> Result<(), HttpErrorKind> {
+    let client = ctx.route(route.client.as_deref(), file.filename(), route.size)?;
+    let options = tracking_options(client, tmdb);
 
-    qb.add_torrent(url, options).await?;
+    client.client().upload_torrent(file, &options).await?;
 
     ctx.enable_movie_tracking().await;
 
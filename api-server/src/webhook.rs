@@ -0,0 +1,230 @@
+use crate::{
+    background::movie_tracking::import_torrent,
+    download_client::DownloadClientInstance,
+    models::{context::ContextPointer, download_torrent::DownloadTorrent},
+};
+use log::{info, warn};
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request, State,
+};
+
+const SECRET_HEADER: &str = "X-Webhook-Secret";
+
+/// Compares in constant time, so the secret cannot be guessed from the response times
+fn secrets_match(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// The shared secret of `webhook` sent in the `X-Webhook-Secret` header, `404` when the webhook is not configured
+pub struct WebhookAuth;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for WebhookAuth {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(context) = request.rocket().state::<ContextPointer>() else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+
+        let Some(webhook) = context.config().webhook() else {
+            return Outcome::Forward(Status::NotFound);
+        };
+
+        match request.headers().get_one(SECRET_HEADER) {
+            Some(secret) if secrets_match(secret, webhook.secret()) => {
+                Outcome::Success(WebhookAuth)
+            }
+            _ => {
+                warn!("Rejected a webhook call with a missing or wrong secret");
+                Outcome::Error((Status::Unauthorized, ()))
+            }
+        }
+    }
+}
+
+/// The torrent with `hash` and the client which has it, unreachable clients are skipped
+async fn find_torrent<'a>(
+    context: &'a ContextPointer,
+    hash: &str,
+) -> Option<(&'a DownloadClientInstance, DownloadTorrent)> {
+    for client in context.download_clients() {
+        match client.torrents().await {
+            Ok(torrents) => {
+                if let Some(torrent) = torrents
+                    .into_iter()
+                    .find(|torrent| torrent.get_hash().eq_ignore_ascii_case(hash))
+                {
+                    return Some((client, torrent));
+                }
+            }
+            Err(error) => warn!("Could not get the torrents of {}: {error:?}", client.name()),
+        }
+    }
+
+    None
+}
+
+/// For qBittorrent's "Run external program on torrent finished", e.g.
+/// `curl -X POST -H "X-Webhook-Secret: secret" "http://api-server:8000/webhook/torrent-finished?hash=%I"`
+///
+/// Imports the torrent right away, movie tracking checks every torrent when it cannot be imported yet.
+/// `422` when no download client has the torrent.
+#[rocket::post("/webhook/torrent-finished?<hash>")]
+pub async fn torrent_finished(
+    _auth: WebhookAuth,
+    context: &State<ContextPointer>,
+    hash: String,
+) -> Status {
+    let Some((client, torrent)) = find_torrent(context, &hash).await else {
+        warn!("Download {hash} finished but no download client has it");
+        return Status::UnprocessableEntity;
+    };
+
+    if torrent.get_category() == client.category() && *torrent.get_progress() == 1.0 {
+        info!("Download {hash} finished, importing it");
+        match import_torrent(context, client, &torrent).await {
+            Ok(()) => return Status::Accepted,
+            Err(error) => warn!("Could not import {:?}: {error:?}", torrent.get_name()),
+        }
+    }
+
+    info!("Download {hash} finished, checking for torrents to import");
+    context.wake_movie_tracking().await;
+
+    Status::Accepted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        download_client,
+        models::{config::Config, context::Context},
+    };
+    use fake_qbittorrent::{FakeQbittorrent, PASSWORD, USERNAME};
+    use movie_info::MovieInfoClient;
+    use rocket::{http::Header, local::asynchronous::Client};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use torrent_search_client::TorrentClient;
+
+    const HASH: &str = "c9e15763f722f23e98a29decdfae341b98d53056";
+    const URL: &str = "/webhook/torrent-finished?hash=c9e15763f722f23e98a29decdfae341b98d53056";
+
+    async fn client(webhook: Value, server: &FakeQbittorrent) -> (Client, ContextPointer) {
+        let config: Config = serde_json::from_value(json!({
+            "qbittorrent": { "url": server.url(), "username": USERNAME, "password": PASSWORD },
+            "movies_path": "/movies",
+            "webhook": webhook,
+        }))
+        .unwrap();
+
+        let context = Arc::new(Context::new(
+            TorrentClient::new(),
+            download_client::from_config(&config),
+            MovieInfoClient::new(),
+            config,
+        ));
+
+        let rocket = rocket::build()
+            .manage(Arc::clone(&context))
+            .mount("/", rocket::routes![torrent_finished]);
+
+        (Client::untracked(rocket).await.unwrap(), context)
+    }
+
+    #[tokio::test]
+    async fn test_torrent_finished() {
+        let server = FakeQbittorrent::start().await;
+        // Importing fails since the content path does not exist, movie tracking retries it
+        server.add_torrent(HASH, "Movie (603)", "torrent-api");
+        server.complete(HASH);
+        let (client, context) = client(json!({ "secret": "secret" }), &server).await;
+        context.disable_movie_tracking().await;
+
+        let response = client
+            .post(URL)
+            .header(Header::new(SECRET_HEADER, "secret"))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Accepted);
+        assert!(*context.movie_tracking_enabled().lock().await);
+        assert_eq!(server.torrent(HASH).unwrap().category(), "torrent-api");
+    }
+
+    #[tokio::test]
+    async fn test_torrent_finished_without_tmdb_id() {
+        let server = FakeQbittorrent::start().await;
+        // Without a TMDB id the torrent is handled right away, there is nothing left to track
+        server.add_torrent(HASH, "Movie", "torrent-api");
+        server.complete(HASH);
+        let (client, context) = client(json!({ "secret": "secret" }), &server).await;
+        context.disable_movie_tracking().await;
+
+        let response = client
+            .post(URL)
+            .header(Header::new(SECRET_HEADER, "secret"))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Accepted);
+        assert!(!*context.movie_tracking_enabled().lock().await);
+    }
+
+    #[tokio::test]
+    async fn test_torrent_finished_unknown_hash() {
+        let server = FakeQbittorrent::start().await;
+        let (client, context) = client(json!({ "secret": "secret" }), &server).await;
+        context.disable_movie_tracking().await;
+
+        let response = client
+            .post(URL)
+            .header(Header::new(SECRET_HEADER, "secret"))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert!(!*context.movie_tracking_enabled().lock().await);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_secret() {
+        let server = FakeQbittorrent::start().await;
+        let (client, context) = client(json!({ "secret": "secret" }), &server).await;
+        context.disable_movie_tracking().await;
+
+        let response = client
+            .post(URL)
+            .header(Header::new(SECRET_HEADER, "wrong"))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        let response = client.post(URL).dispatch().await;
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        assert!(!*context.movie_tracking_enabled().lock().await);
+    }
+
+    #[tokio::test]
+    async fn test_not_configured() {
+        let server = FakeQbittorrent::start().await;
+        let (client, _) = client(Value::Null, &server).await;
+
+        let response = client
+            .post(URL)
+            .header(Header::new(SECRET_HEADER, "secret"))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
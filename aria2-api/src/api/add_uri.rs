@@ -0,0 +1,24 @@
+use crate::{models::add_options::AddOptions, Aria2Client, Error};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+
+impl Aria2Client {
+    /// Adds a download from urls to the same file, or a magnet, returns its gid
+    pub async fn add_uri(&self, uris: Vec<String>, options: &AddOptions) -> Result<String, Error> {
+        self.call("aria2.addUri", vec![json!(uris), json!(options)])
+            .await
+    }
+
+    /// Adds the content of a `.torrent` file, returns its gid
+    pub async fn add_torrent(&self, content: &[u8], options: &AddOptions) -> Result<String, Error> {
+        self.call(
+            "aria2.addTorrent",
+            vec![
+                Value::String(STANDARD.encode(content)),
+                json!([]),
+                json!(options),
+            ],
+        )
+        .await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod add_uri;
+pub mod notifications;
+pub mod remove;
+mod rpc;
+pub mod tell_status;
@@ -0,0 +1,10 @@
+use crate::{models::notification::Notification, Aria2Client, Error};
+use tokio::sync::broadcast;
+
+impl Aria2Client {
+    /// Subscribes to the events pushed by aria2, always over a WebSocket.
+    /// The receiver closes with the connection, subscribe again to reconnect.
+    pub async fn notifications(&self) -> Result<broadcast::Receiver<Notification>, Error> {
+        Ok(self.websocket().await?.subscribe())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{Aria2Client, Error};
+use serde_json::json;
+
+impl Aria2Client {
+    /// Stops the download, the files are kept
+    pub async fn remove(&self, gid: &str) -> Result<(), Error> {
+        self.call::<String>("aria2.remove", vec![json!(gid)])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stops the download without contacting the trackers first
+    pub async fn force_remove(&self, gid: &str) -> Result<(), Error> {
+        self.call::<String>("aria2.forceRemove", vec![json!(gid)])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Forgets a stopped download, the files are kept
+    pub async fn remove_download_result(&self, gid: &str) -> Result<(), Error> {
+        self.call::<String>("aria2.removeDownloadResult", vec![json!(gid)])
+            .await?;
+
+        Ok(())
+    }
+}
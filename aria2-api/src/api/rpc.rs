@@ -0,0 +1,59 @@
+use crate::{
+    models::rpc::{RpcRequest, RpcResponse},
+    websocket::WebSocket,
+    Aria2Client, Error,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::{atomic::Ordering, Arc};
+
+impl Aria2Client {
+    /// The shared WebSocket connection, opened again when it closed
+    pub(crate) async fn websocket(&self) -> Result<Arc<WebSocket>, Error> {
+        let mut websocket = self.websocket.lock().await;
+
+        if let Some(connection) = websocket.as_ref().filter(|c| !c.is_closed()) {
+            return Ok(Arc::clone(connection));
+        }
+
+        let connection = Arc::new(WebSocket::connect(&self.websocket_url()).await?);
+        *websocket = Some(Arc::clone(&connection));
+
+        Ok(connection)
+    }
+
+    /// Calls `method` over the WebSocket for `ws://` urls and over HTTP otherwise
+    pub(crate) async fn call<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<R, Error> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+
+        let params = self
+            .secret
+            .as_ref()
+            .map(|secret| Value::String(format!("token:{secret}")))
+            .into_iter()
+            .chain(params)
+            .collect();
+
+        let request = RpcRequest::new(id, method, params);
+
+        let response: RpcResponse = match self.url.scheme() {
+            "ws" | "wss" => self.websocket().await?.call(&request).await?,
+            // aria2 answers errors with a 400 and the error in the body
+            _ => {
+                self.http
+                    .post(&self.url)
+                    .body_json(&request)?
+                    .send()
+                    .await?
+                    .body_json()
+                    .await?
+            }
+        };
+
+        Ok(serde_json::from_value(response.into_result()?)?)
+    }
+}
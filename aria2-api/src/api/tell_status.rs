@@ -0,0 +1,47 @@
+use crate::{
+    models::download::{Download, DOWNLOAD_KEYS},
+    Aria2Client, Error,
+};
+use serde_json::json;
+
+/// The most downloads requested from the waiting and stopped queues
+const PAGE_SIZE: u64 = 1000;
+
+impl Aria2Client {
+    pub async fn tell_status(&self, gid: &str) -> Result<Download, Error> {
+        self.call("aria2.tellStatus", vec![json!(gid), json!(DOWNLOAD_KEYS)])
+            .await
+    }
+
+    pub async fn tell_active(&self) -> Result<Vec<Download>, Error> {
+        self.call("aria2.tellActive", vec![json!(DOWNLOAD_KEYS)])
+            .await
+    }
+
+    pub async fn tell_waiting(&self, offset: u64, num: u64) -> Result<Vec<Download>, Error> {
+        self.call(
+            "aria2.tellWaiting",
+            vec![json!(offset), json!(num), json!(DOWNLOAD_KEYS)],
+        )
+        .await
+    }
+
+    /// Completed, failed and removed downloads, until their result is removed
+    pub async fn tell_stopped(&self, offset: u64, num: u64) -> Result<Vec<Download>, Error> {
+        self.call(
+            "aria2.tellStopped",
+            vec![json!(offset), json!(num), json!(DOWNLOAD_KEYS)],
+        )
+        .await
+    }
+
+    /// The active, waiting and stopped downloads
+    pub async fn downloads(&self) -> Result<Vec<Download>, Error> {
+        let mut downloads = self.tell_active().await?;
+
+        downloads.extend(self.tell_waiting(0, PAGE_SIZE).await?);
+        downloads.extend(self.tell_stopped(0, PAGE_SIZE).await?);
+
+        Ok(downloads)
+    }
+}
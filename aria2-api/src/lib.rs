@@ -0,0 +1,73 @@
+mod api;
+mod error;
+mod models;
+mod websocket;
+pub use error::Error;
+pub use error::ErrorKind;
+pub use models::add_options::AddOptions;
+pub use models::download::{Download, DownloadFile};
+pub use models::download_status::DownloadStatus;
+pub use models::notification::{Notification, NotificationKind};
+use std::fmt::Debug;
+use std::sync::{atomic::AtomicU64, Arc};
+use surf::{Client, Config, Url};
+use tokio::sync::Mutex;
+use utils::proxy::{with_proxy, Proxy};
+use utils::surf_logging::SurfLogging;
+use websocket::WebSocket;
+
+/// Cheap to clone, clones share the same WebSocket connection
+#[derive(Clone)]
+pub struct Aria2Client {
+    http: Client,
+    url: Url,
+    secret: Option<String>,
+    next_id: Arc<AtomicU64>,
+    websocket: Arc<Mutex<Option<Arc<WebSocket>>>>,
+}
+
+impl Aria2Client {
+    /// `url` is the RPC url, `http://localhost:6800/jsonrpc` or `ws://localhost:6800/jsonrpc`
+    pub fn new<U: TryInto<Url>>(url: U, secret: Option<String>) -> Self
+    where
+        U::Error: Debug,
+    {
+        Self::with_proxy(url, secret, None)
+    }
+
+    /// Creates a client which sends its HTTP requests through `proxy`, WebSockets connect directly
+    pub fn with_proxy<U: TryInto<Url>>(
+        url: U,
+        secret: Option<String>,
+        proxy: Option<&Proxy>,
+    ) -> Self
+    where
+        U::Error: Debug,
+    {
+        let url: Url = url.try_into().expect("Invalid url");
+
+        let client: Client = with_proxy(Config::new(), proxy).try_into().unwrap();
+
+        Self {
+            http: client.with(SurfLogging),
+            url,
+            secret,
+            next_id: Arc::new(AtomicU64::new(0)),
+            websocket: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The url with a `ws` scheme, aria2 serves both on the same path
+    fn websocket_url(&self) -> Url {
+        let mut url = self.url.clone();
+
+        let scheme = match url.scheme() {
+            "https" | "wss" => "wss",
+            _ => "ws",
+        };
+        // Only fails when changing between special and non special schemes
+        let _ = url.set_scheme(scheme);
+
+        url
+    }
+}
@@ -0,0 +1,11 @@
+use getset::Setters;
+use serde::Serialize;
+
+/// Options of a new download, aria2 has many more which can be added when needed
+#[derive(Debug, Serialize, Default, Setters, Clone)]
+#[set = "pub"]
+pub struct AddOptions {
+    /// The directory the download is saved in, as seen by aria2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dir: Option<String>,
+}
@@ -0,0 +1,155 @@
+use super::download_status::DownloadStatus;
+use getset::Getters;
+use serde::{Deserialize, Deserializer};
+
+/// The keys requested by the `tell*` calls, they must match the fields of `Download`
+pub(crate) const DOWNLOAD_KEYS: &[&str] = &[
+    "gid",
+    "status",
+    "totalLength",
+    "completedLength",
+    "downloadSpeed",
+    "uploadSpeed",
+    "dir",
+    "files",
+    "bittorrent",
+    "infoHash",
+    "followedBy",
+    "errorMessage",
+];
+
+/// aria2 sends every number as a string
+fn string_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+#[derive(Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[get = "pub with_prefix"]
+pub struct DownloadFile {
+    path: String,
+    #[serde(deserialize_with = "string_number")]
+    length: u64,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct BittorrentInfo {
+    name: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct Bittorrent {
+    /// Missing while the metadata of a magnet is being downloaded
+    info: Option<BittorrentInfo>,
+}
+
+#[derive(Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[get = "pub with_prefix"]
+pub struct Download {
+    gid: String,
+    status: DownloadStatus,
+    #[serde(deserialize_with = "string_number")]
+    total_length: u64,
+    #[serde(deserialize_with = "string_number")]
+    completed_length: u64,
+    /// Speeds are in bytes per second
+    #[serde(deserialize_with = "string_number")]
+    download_speed: u64,
+    #[serde(deserialize_with = "string_number")]
+    upload_speed: u64,
+    dir: String,
+    #[serde(default)]
+    files: Vec<DownloadFile>,
+    #[serde(default)]
+    bittorrent: Option<Bittorrent>,
+    #[serde(default)]
+    info_hash: Option<String>,
+    /// The downloads started by this one, e.g. the torrent of a magnet once its metadata is downloaded
+    #[serde(default)]
+    followed_by: Vec<String>,
+    #[serde(default)]
+    error_message: Option<String>,
+}
+
+impl Download {
+    /// The name of the torrent, or the name of the first file
+    pub fn name(&self) -> String {
+        self.torrent_name()
+            .or_else(|| {
+                self.files
+                    .first()
+                    .and_then(|file| file.path.rsplit('/').next())
+                    .map(String::from)
+            })
+            .unwrap_or_else(|| self.gid.to_owned())
+    }
+
+    fn torrent_name(&self) -> Option<String> {
+        self.bittorrent
+            .as_ref()
+            .and_then(|bittorrent| bittorrent.info.as_ref())
+            .map(|info| info.name.to_owned())
+    }
+
+    /// The path of the file, or of the folder for torrents with several files
+    pub fn content_path(&self) -> String {
+        let dir = self.dir.trim_end_matches('/');
+
+        match self.torrent_name() {
+            Some(name) => format!("{dir}/{name}"),
+            None => self
+                .files
+                .first()
+                .map(|file| file.path.to_owned())
+                .unwrap_or_else(|| dir.to_string()),
+        }
+    }
+
+    pub fn progress(&self) -> f64 {
+        if self.total_length == 0 {
+            0.0
+        } else {
+            self.completed_length as f64 / self.total_length as f64
+        }
+    }
+
+    /// In seconds, `None` when nothing is being downloaded
+    pub fn eta(&self) -> Option<u64> {
+        (self.download_speed > 0)
+            .then(|| self.total_length.saturating_sub(self.completed_length) / self.download_speed)
+    }
+
+    /// Metadata downloads of magnets are followed by the actual download
+    pub fn is_metadata(&self) -> bool {
+        !self.followed_by.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let download: Download = serde_json::from_str(
+            r#"{
+                "gid": "2089b05ecca3d829", "status": "active", "totalLength": "1000", "completedLength": "250",
+                "downloadSpeed": "50", "uploadSpeed": "0", "dir": "/downloads/movies/",
+                "files": [{ "index": "1", "path": "/downloads/movies/Movie/movie.mkv", "length": "1000", "completedLength": "250", "selected": "true", "uris": [] }],
+                "bittorrent": { "info": { "name": "Movie" }, "mode": "single" },
+                "infoHash": "abc"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(download.status, DownloadStatus::Active);
+        assert_eq!(download.name(), "Movie");
+        assert_eq!(download.content_path(), "/downloads/movies/Movie");
+        assert_eq!(download.progress(), 0.25);
+        assert_eq!(download.eta(), Some(15));
+        assert!(!download.is_metadata());
+    }
+}
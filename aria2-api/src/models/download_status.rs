@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DownloadStatus {
+    Active,
+    /// Queued
+    Waiting,
+    Paused,
+    Error,
+    Complete,
+    Removed,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,5 @@
+pub mod add_options;
+pub mod download;
+pub mod download_status;
+pub mod notification;
+pub mod rpc;
@@ -0,0 +1,78 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    DownloadStart,
+    DownloadPause,
+    DownloadStop,
+    DownloadComplete,
+    DownloadError,
+    /// The torrent finished downloading, it may still be seeding
+    BtDownloadComplete,
+}
+
+impl NotificationKind {
+    fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "aria2.onDownloadStart" => Some(Self::DownloadStart),
+            "aria2.onDownloadPause" => Some(Self::DownloadPause),
+            "aria2.onDownloadStop" => Some(Self::DownloadStop),
+            "aria2.onDownloadComplete" => Some(Self::DownloadComplete),
+            "aria2.onDownloadError" => Some(Self::DownloadError),
+            "aria2.onBtDownloadComplete" => Some(Self::BtDownloadComplete),
+            _ => None,
+        }
+    }
+}
+
+/// An event pushed by aria2 over the WebSocket
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub gid: String,
+}
+
+#[derive(Deserialize)]
+struct Event {
+    gid: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawNotification {
+    method: String,
+    params: Vec<Event>,
+}
+
+impl RawNotification {
+    pub(crate) fn into_notifications(self) -> Vec<Notification> {
+        let Some(kind) = NotificationKind::from_method(&self.method) else {
+            return Vec::new();
+        };
+
+        self.params
+            .into_iter()
+            .map(|event| Notification {
+                kind,
+                gid: event.gid,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification() {
+        let raw: RawNotification = serde_json::from_str(
+            r#"{ "jsonrpc": "2.0", "method": "aria2.onDownloadComplete", "params": [{ "gid": "2089b05ecca3d829" }] }"#,
+        )
+        .unwrap();
+
+        let notifications = raw.into_notifications();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].kind, NotificationKind::DownloadComplete);
+        assert_eq!(notifications[0].gid, "2089b05ecca3d829");
+    }
+}
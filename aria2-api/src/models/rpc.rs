@@ -0,0 +1,51 @@
+use crate::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Serialize)]
+pub struct RpcRequest<'a> {
+    jsonrpc: &'static str,
+    id: String,
+    method: &'a str,
+    params: Vec<Value>,
+}
+
+impl<'a> RpcRequest<'a> {
+    pub fn new(id: String, method: &'a str, params: Vec<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RpcResponse {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    pub fn into_result(self) -> Result<Value, Error> {
+        match self.error {
+            // aria2 answers a wrong token with code 1, like any other failure
+            Some(error) if error.message == "Unauthorized" => {
+                Err(Error::new(ErrorKind::IncorrectSecret, error.message))
+            }
+            Some(error) => Err(Error::new(ErrorKind::RpcError(error.code), error.message)),
+            None => Ok(self.result.unwrap_or(Value::Null)),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    models::{
+        notification::{Notification, RawNotification},
+        rpc::{RpcRequest, RpcResponse},
+    },
+    Error, ErrorKind,
+};
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use log::{debug, warn};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use surf::Url;
+use tokio::{
+    net::TcpStream,
+    sync::{broadcast, oneshot, Mutex},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Pending = Arc<Mutex<HashMap<String, oneshot::Sender<RpcResponse>>>>;
+
+/// A WebSocket connection, answers are matched to their request by id and notifications are broadcast
+pub(crate) struct WebSocket {
+    sink: Mutex<SplitSink<Socket, Message>>,
+    pending: Pending,
+    notifications: broadcast::Receiver<Notification>,
+    closed: Arc<AtomicBool>,
+}
+
+impl WebSocket {
+    pub(crate) async fn connect(url: &Url) -> Result<Self, Error> {
+        let (socket, _) = connect_async(url.as_str()).await?;
+        let (sink, stream) = socket.split();
+
+        let pending = Pending::default();
+        let closed = Arc::new(AtomicBool::new(false));
+        let (sender, notifications) = broadcast::channel(64);
+
+        tokio::spawn(Self::read(
+            stream,
+            Arc::clone(&pending),
+            sender,
+            Arc::clone(&closed),
+        ));
+
+        Ok(Self {
+            sink: Mutex::new(sink),
+            pending,
+            notifications,
+            closed,
+        })
+    }
+
+    async fn read(
+        mut stream: SplitStream<Socket>,
+        pending: Pending,
+        notifications: broadcast::Sender<Notification>,
+        closed: Arc<AtomicBool>,
+    ) {
+        while let Some(message) = stream.next().await {
+            let text = match message {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(_)) => break,
+                Ok(_) => continue,
+                Err(error) => {
+                    warn!("aria2 WebSocket error: {error}");
+                    break;
+                }
+            };
+
+            let Ok(value) = serde_json::from_str::<Value>(&text) else {
+                warn!("Invalid message from aria2: {text}");
+                continue;
+            };
+
+            match value.get("id").and_then(Value::as_str) {
+                Some(id) => {
+                    let sender = pending.lock().await.remove(id);
+
+                    if let (Some(sender), Ok(response)) =
+                        (sender, serde_json::from_value::<RpcResponse>(value))
+                    {
+                        let _ = sender.send(response);
+                    }
+                }
+                None => {
+                    if let Ok(raw) = serde_json::from_value::<RawNotification>(value) {
+                        for notification in raw.into_notifications() {
+                            // Fails when nobody is subscribed
+                            let _ = notifications.send(notification);
+                        }
+                    }
+                }
+            }
+        }
+
+        debug!("aria2 WebSocket closed");
+        closed.store(true, Ordering::Relaxed);
+        // Dropping the senders fails the calls still waiting for an answer
+        pending.lock().await.clear();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+
+    pub(crate) async fn call(&self, request: &RpcRequest<'_>) -> Result<RpcResponse, Error> {
+        let (sender, receiver) = oneshot::channel();
+        self.pending
+            .lock()
+            .await
+            .insert(request.id().to_string(), sender);
+
+        let message = Message::text(serde_json::to_string(request)?);
+        if let Err(error) = self.sink.lock().await.send(message).await {
+            self.pending.lock().await.remove(request.id());
+            return Err(error.into());
+        }
+
+        receiver.await.map_err(|_| {
+            Error::new(
+                ErrorKind::Disconnected,
+                "The connection to aria2 closed before it answered",
+            )
+        })
+    }
+
+    /// Receives the notifications sent after subscribing, closes with the connection
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.notifications.resubscribe()
+    }
+}
@@ -0,0 +1,240 @@
+//! An in-process fake of the qBittorrent WebUI API v2, for testing clients offline.
+//!
+//! Torrents are added through `torrents/add` like with qBittorrent, but never download on their own:
+//! the test scripts their progress with [`FakeQbittorrent::set_progress`] and friends.
+#![recursion_limit = "256"]
+mod routes;
+mod session;
+mod state;
+
+use rocket::{config::LogLevel, fairing::AdHoc, Shutdown};
+use serde_json::json;
+pub use state::FakeTorrent;
+use state::State;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    net::Ipv4Addr,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot;
+
+pub const USERNAME: &str = "admin";
+pub const PASSWORD: &str = "adminadmin";
+
+/// The level of a message of `log/main`, qBittorrent sends them as bit flags
+#[derive(Debug, Clone, Copy)]
+pub enum FakeLogType {
+    Normal = 1,
+    Info = 2,
+    Warning = 4,
+    Critical = 8,
+}
+
+pub(crate) type SharedState = Arc<Mutex<State>>;
+
+/// Stops the server when dropped
+pub struct FakeQbittorrent {
+    url: String,
+    state: SharedState,
+    shutdown: Shutdown,
+}
+
+impl FakeQbittorrent {
+    /// Starts the server on a free port of localhost, log in with [`USERNAME`] and [`PASSWORD`]
+    pub async fn start() -> Self {
+        let state = SharedState::default();
+        let (port_sender, port_receiver) = oneshot::channel();
+
+        let config = rocket::Config {
+            address: Ipv4Addr::LOCALHOST.into(),
+            port: 0,
+            log_level: LogLevel::Off,
+            cli_colors: false,
+            shutdown: rocket::config::Shutdown {
+                ctrlc: false,
+                #[cfg(unix)]
+                signals: Default::default(),
+                ..Default::default()
+            },
+            ..rocket::Config::debug_default()
+        };
+
+        let rocket = rocket::custom(config)
+            .manage(Arc::clone(&state))
+            .mount("/api/v2", routes::routes())
+            .attach(AdHoc::on_liftoff("Port", |rocket| {
+                Box::pin(async move {
+                    let _ = port_sender.send(rocket.config().port);
+                })
+            }))
+            .ignite()
+            .await
+            .expect("Could not start the fake qBittorrent server");
+
+        let shutdown = rocket.shutdown();
+        tokio::spawn(rocket.launch());
+
+        let port = port_receiver
+            .await
+            .expect("The fake qBittorrent server stopped before it started listening");
+
+        Self {
+            url: format!("http://127.0.0.1:{port}"),
+            state,
+            shutdown,
+        }
+    }
+
+    /// The base url to give to the client, e.g. `http://127.0.0.1:1234`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_torrent<T>(&self, hash: &str, f: impl FnOnce(&mut FakeTorrent) -> T) -> T {
+        f(self.state.lock().unwrap().torrent_mut(hash))
+    }
+
+    /// Adds a torrent without going through the API, as if it was added in the WebUI
+    pub fn add_torrent(&self, hash: &str, name: &str, category: &str) {
+        let mut state = self.state.lock().unwrap();
+
+        let mut torrent = FakeTorrent::new(hash.to_string(), name.to_string());
+        torrent.set_category(category.to_string());
+
+        if !category.is_empty() {
+            state.categories.entry(category.to_string()).or_default();
+        }
+        state.torrents.insert(hash.to_string(), torrent);
+    }
+
+    pub fn add_category(&self, name: &str, save_path: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .categories
+            .insert(name.to_string(), save_path.to_string());
+    }
+
+    /// Panics if there is no torrent with `hash`, like the other scripting methods
+    pub fn set_progress(&self, hash: &str, progress: f64) {
+        self.with_torrent(hash, |torrent| torrent.set_progress(progress));
+    }
+
+    pub fn complete(&self, hash: &str) {
+        self.set_progress(hash, 1.0);
+    }
+
+    /// In bytes per second, the ETA is derived from it
+    pub fn set_download_speed(&self, hash: &str, dlspeed: u64) {
+        self.with_torrent(hash, |torrent| torrent.set_dlspeed(dlspeed));
+    }
+
+    /// Overrides the state derived from the progress, e.g. `pausedDL` or `error`, `None` clears it
+    pub fn set_state(&self, hash: &str, state: Option<&str>) {
+        self.with_torrent(hash, |torrent| torrent.set_state(state.map(str::to_string)));
+    }
+
+    pub fn remove_torrent(&self, hash: &str) {
+        self.state.lock().unwrap().torrents.remove(hash);
+    }
+
+    pub fn torrent(&self, hash: &str) -> Option<FakeTorrent> {
+        self.state.lock().unwrap().torrents.get(hash).cloned()
+    }
+
+    pub fn torrents(&self) -> Vec<FakeTorrent> {
+        self.state
+            .lock()
+            .unwrap()
+            .torrents
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Category name to save path
+    pub fn categories(&self) -> BTreeMap<String, String> {
+        self.state.lock().unwrap().categories.clone()
+    }
+
+    pub fn tags(&self) -> BTreeSet<String> {
+        self.state.lock().unwrap().tags.clone()
+    }
+
+    /// Adds a result found by the searches whose words are all in `name`, `file_url` is usually a magnet link
+    pub fn add_search_result(
+        &self,
+        name: &str,
+        file_url: &str,
+        size: i64,
+        seeders: i64,
+        leechers: i64,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        let id = state.search_results.len();
+
+        state.search_results.push(json!({
+            "descrLink": format!("https://fake.invalid/torrent/{id}"),
+            "fileName": name,
+            "fileSize": size,
+            "fileUrl": file_url,
+            "nbLeechers": leechers,
+            "nbSeeders": seeders,
+            "siteUrl": "https://fake.invalid",
+            "pubDate": -1,
+        }));
+    }
+
+    /// The ids of the search jobs which were started and not deleted yet
+    pub fn search_jobs(&self) -> Vec<u64> {
+        self.state
+            .lock()
+            .unwrap()
+            .search_jobs
+            .keys()
+            .copied()
+            .collect()
+    }
+
+    /// The next `sync/maindata` delta cannot be parsed, full updates are not affected
+    pub fn corrupt_next_sync_delta(&self) {
+        self.state.lock().unwrap().corrupt_next_delta = true;
+    }
+
+    /// The rid of every `sync/maindata` request, in order
+    pub fn sync_rids(&self) -> Vec<usize> {
+        self.state.lock().unwrap().sync_rids.clone()
+    }
+
+    pub fn add_log(&self, message: &str, log_type: FakeLogType) {
+        self.state.lock().unwrap().add_log(message, log_type as u8);
+    }
+
+    /// `blocked` is false for banned peers
+    pub fn add_peer_log(&self, ip: &str, blocked: bool, reason: &str) {
+        self.state.lock().unwrap().add_peer_log(ip, blocked, reason);
+    }
+
+    /// Clears the log like a restart of qBittorrent, the ids of the next messages start over
+    pub fn restart_log(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.log.clear();
+        state.peer_log.clear();
+    }
+
+    /// Invalidates every session, like a restart of qBittorrent, the next requests get `403 Forbidden`
+    pub fn expire_sessions(&self) {
+        self.state.lock().unwrap().sessions.clear();
+    }
+
+    /// How many times a client logged in successfully
+    pub fn logins(&self) -> usize {
+        self.state.lock().unwrap().logins
+    }
+}
+
+impl Drop for FakeQbittorrent {
+    fn drop(&mut self) {
+        self.shutdown.clone().notify();
+    }
+}
@@ -0,0 +1,11 @@
+use crate::session::Session;
+
+#[rocket::get("/app/version")]
+pub(crate) fn version(_session: Session) -> &'static str {
+    "v4.6.7"
+}
+
+#[rocket::get("/app/webapiVersion")]
+pub(crate) fn webapi_version(_session: Session) -> &'static str {
+    "2.9.3"
+}
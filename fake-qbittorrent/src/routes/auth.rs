@@ -0,0 +1,39 @@
+use crate::{
+    session::{Session, SESSION_COOKIE},
+    SharedState, PASSWORD, USERNAME,
+};
+use rocket::{
+    form::Form,
+    http::{Cookie, CookieJar},
+    FromForm, State,
+};
+
+#[derive(FromForm)]
+pub(crate) struct Credentials {
+    username: String,
+    password: String,
+}
+
+/// Answers `200 Fails.` for wrong credentials, like qBittorrent
+#[rocket::post("/auth/login", data = "<credentials>")]
+pub(crate) fn login(
+    credentials: Form<Credentials>,
+    cookies: &CookieJar<'_>,
+    state: &State<SharedState>,
+) -> &'static str {
+    if credentials.username != USERNAME || credentials.password != PASSWORD {
+        return "Fails.";
+    }
+
+    let session_id = state.lock().unwrap().new_session();
+    cookies.add(Cookie::build((SESSION_COOKIE, session_id)).http_only(true));
+
+    "Ok."
+}
+
+#[rocket::post("/auth/logout")]
+pub(crate) fn logout(_session: Session, cookies: &CookieJar<'_>, state: &State<SharedState>) {
+    if let Some(cookie) = cookies.get(SESSION_COOKIE) {
+        state.lock().unwrap().sessions.remove(cookie.value());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{session::Session, SharedState};
+use rocket::{form::Form, http::Status, serde::json::Json, FromForm, State};
+use serde_json::{json, Map, Value};
+
+#[derive(FromForm)]
+pub(crate) struct CategoryForm {
+    category: String,
+    #[field(name = "savePath", default = String::new())]
+    save_path: String,
+}
+
+#[derive(FromForm)]
+pub(crate) struct RemoveCategoriesForm {
+    categories: String,
+}
+
+#[rocket::get("/torrents/categories")]
+pub(crate) fn categories(
+    _session: Session,
+    state: &State<SharedState>,
+) -> Json<Map<String, Value>> {
+    let state = state.lock().unwrap();
+
+    Json(
+        state
+            .categories
+            .iter()
+            .map(|(name, save_path)| (name.clone(), json!({ "name": name, "savePath": save_path })))
+            .collect(),
+    )
+}
+
+#[rocket::post("/torrents/createCategory", data = "<form>")]
+pub(crate) fn create_category(
+    _session: Session,
+    form: Form<CategoryForm>,
+    state: &State<SharedState>,
+) -> Result<(), (Status, &'static str)> {
+    let mut state = state.lock().unwrap();
+
+    if form.category.is_empty() {
+        return Err((Status::BadRequest, "Category cannot be empty"));
+    }
+    if state.categories.contains_key(&form.category) {
+        return Err((Status::Conflict, "Unable to create category"));
+    }
+
+    state
+        .categories
+        .insert(form.category.clone(), form.save_path.clone());
+
+    Ok(())
+}
+
+#[rocket::post("/torrents/editCategory", data = "<form>")]
+pub(crate) fn edit_category(
+    _session: Session,
+    form: Form<CategoryForm>,
+    state: &State<SharedState>,
+) -> Result<(), (Status, &'static str)> {
+    let mut state = state.lock().unwrap();
+
+    match state.categories.get_mut(&form.category) {
+        Some(save_path) => {
+            save_path.clone_from(&form.save_path);
+            Ok(())
+        }
+        None => Err((Status::Conflict, "Unable to edit category")),
+    }
+}
+
+/// Torrents in a removed category are left without a category
+#[rocket::post("/torrents/removeCategories", data = "<form>")]
+pub(crate) fn remove_categories(
+    _session: Session,
+    form: Form<RemoveCategoriesForm>,
+    state: &State<SharedState>,
+) {
+    let mut state = state.lock().unwrap();
+
+    for category in form.categories.lines() {
+        state.categories.remove(category);
+
+        for torrent in state.torrents.values_mut() {
+            if torrent.category() == category {
+                torrent.set_category(String::new());
+            }
+        }
+    }
+}
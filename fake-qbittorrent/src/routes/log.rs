@@ -0,0 +1,53 @@
+use crate::{session::Session, SharedState};
+use rocket::{serde::json::Json, State};
+use serde_json::Value;
+
+#[allow(clippy::too_many_arguments)]
+#[rocket::get("/log/main?<normal>&<info>&<warning>&<critical>&<last_known_id>")]
+pub(crate) fn main(
+    _session: Session,
+    normal: Option<bool>,
+    info: Option<bool>,
+    warning: Option<bool>,
+    critical: Option<bool>,
+    last_known_id: Option<i64>,
+    state: &State<SharedState>,
+) -> Json<Vec<Value>> {
+    let state = state.lock().unwrap();
+    let last_known_id = last_known_id.unwrap_or(-1);
+
+    let types: Vec<u64> = [(normal, 1), (info, 2), (warning, 4), (critical, 8)]
+        .into_iter()
+        .filter(|(enabled, _)| enabled.unwrap_or(true))
+        .map(|(_, log_type)| log_type)
+        .collect();
+
+    Json(
+        state
+            .log
+            .iter()
+            .filter(|entry| entry["id"].as_i64().unwrap_or_default() > last_known_id)
+            .filter(|entry| types.contains(&entry["type"].as_u64().unwrap_or_default()))
+            .cloned()
+            .collect(),
+    )
+}
+
+#[rocket::get("/log/peers?<last_known_id>")]
+pub(crate) fn peers(
+    _session: Session,
+    last_known_id: Option<i64>,
+    state: &State<SharedState>,
+) -> Json<Vec<Value>> {
+    let state = state.lock().unwrap();
+    let last_known_id = last_known_id.unwrap_or(-1);
+
+    Json(
+        state
+            .peer_log
+            .iter()
+            .filter(|entry| entry["id"].as_i64().unwrap_or_default() > last_known_id)
+            .cloned()
+            .collect(),
+    )
+}
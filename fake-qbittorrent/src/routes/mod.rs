@@ -0,0 +1,36 @@
+mod app;
+mod auth;
+mod categories;
+mod log;
+mod search;
+mod sync;
+mod torrents;
+
+use rocket::Route;
+
+/// Mounted at `/api/v2`
+pub(crate) fn routes() -> Vec<Route> {
+    rocket::routes![
+        app::version,
+        app::webapi_version,
+        auth::login,
+        auth::logout,
+        categories::categories,
+        categories::create_category,
+        categories::edit_category,
+        categories::remove_categories,
+        log::main,
+        log::peers,
+        search::delete,
+        search::plugins,
+        search::results,
+        search::start,
+        search::status,
+        search::stop,
+        sync::main_data,
+        torrents::add,
+        torrents::delete,
+        torrents::info,
+        torrents::set_category,
+    ]
+}
@@ -0,0 +1,92 @@
+use crate::{session::Session, SharedState};
+use rocket::{form::Form, http::Status, serde::json::Json, FromForm, State};
+use serde_json::{json, Value};
+
+#[derive(FromForm)]
+pub(crate) struct StartForm {
+    pattern: String,
+}
+
+#[derive(FromForm)]
+pub(crate) struct IdForm {
+    id: u64,
+}
+
+/// Jobs never run in the background, they are stopped with their results as soon as they start
+#[rocket::post("/search/start", data = "<form>")]
+pub(crate) fn start(
+    _session: Session,
+    form: Form<StartForm>,
+    state: &State<SharedState>,
+) -> Json<Value> {
+    let id = state.lock().unwrap().start_search(&form.pattern);
+
+    Json(json!({ "id": id }))
+}
+
+#[rocket::post("/search/stop", data = "<form>")]
+pub(crate) fn stop(_session: Session, form: Form<IdForm>, state: &State<SharedState>) -> Status {
+    match state.lock().unwrap().search_jobs.contains_key(&form.id) {
+        true => Status::Ok,
+        false => Status::NotFound,
+    }
+}
+
+#[rocket::get("/search/status?<id>")]
+pub(crate) fn status(
+    _session: Session,
+    id: u64,
+    state: &State<SharedState>,
+) -> Result<Json<Value>, Status> {
+    let state = state.lock().unwrap();
+    let job = state.search_jobs.get(&id).ok_or(Status::NotFound)?;
+
+    Ok(Json(
+        json!([{ "id": id, "status": "Stopped", "total": job.len() }]),
+    ))
+}
+
+#[rocket::get("/search/results?<id>&<limit>&<offset>")]
+pub(crate) fn results(
+    _session: Session,
+    id: u64,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    state: &State<SharedState>,
+) -> Result<Json<Value>, Status> {
+    let state = state.lock().unwrap();
+    let job = state.search_jobs.get(&id).ok_or(Status::NotFound)?;
+
+    let results: Vec<&Value> = job
+        .iter()
+        .skip(offset.unwrap_or_default())
+        .take(limit.filter(|limit| *limit > 0).unwrap_or(usize::MAX))
+        .collect();
+
+    Ok(Json(
+        json!({ "results": results, "status": "Stopped", "total": job.len() }),
+    ))
+}
+
+#[rocket::post("/search/delete", data = "<form>")]
+pub(crate) fn delete(_session: Session, form: Form<IdForm>, state: &State<SharedState>) -> Status {
+    match state.lock().unwrap().search_jobs.remove(&form.id) {
+        Some(_) => Status::Ok,
+        None => Status::NotFound,
+    }
+}
+
+#[rocket::get("/search/plugins")]
+pub(crate) fn plugins(_session: Session) -> Json<Value> {
+    Json(json!([{
+        "enabled": true,
+        "fullName": "Fake search engine",
+        "name": "fake",
+        "supportedCategories": [
+            { "id": "all", "name": "All categories" },
+            { "id": "movies", "name": "Movies" },
+        ],
+        "url": "https://fake.invalid",
+        "version": "1.0",
+    }]))
+}
@@ -0,0 +1,12 @@
+use crate::{session::Session, SharedState};
+use rocket::{serde::json::Json, State};
+use serde_json::Value;
+
+#[rocket::get("/sync/maindata?<rid>")]
+pub(crate) fn main_data(
+    _session: Session,
+    rid: Option<usize>,
+    state: &State<SharedState>,
+) -> Json<Value> {
+    Json(state.lock().unwrap().main_data(rid.unwrap_or_default()))
+}
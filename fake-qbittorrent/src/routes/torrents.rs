@@ -0,0 +1,247 @@
+use crate::{session::Session, state::FakeTorrent, SharedState};
+use rocket::{
+    form::Form, fs::TempFile, http::Status, serde::json::Json, tokio::io::AsyncReadExt, FromForm,
+    State,
+};
+use serde_json::{Map, Value};
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+use utils::magnet::Magnet;
+
+type BadRequest = (Status, &'static str);
+
+#[derive(FromForm)]
+pub(crate) struct InfoQuery<'r> {
+    filter: Option<&'r str>,
+    category: Option<&'r str>,
+    tag: Option<&'r str>,
+    sort: Option<&'r str>,
+    reverse: Option<bool>,
+    limit: Option<usize>,
+    offset: Option<i64>,
+    hashes: Option<&'r str>,
+}
+
+#[derive(FromForm)]
+pub(crate) struct AddForm<'r> {
+    urls: Option<String>,
+    torrents: Vec<TempFile<'r>>,
+    savepath: Option<String>,
+    category: Option<String>,
+    tags: Option<String>,
+    paused: Option<bool>,
+    rename: Option<String>,
+}
+
+#[derive(FromForm)]
+pub(crate) struct DeleteForm {
+    hashes: String,
+    #[field(name = "deleteFiles", default = false)]
+    _delete_files: bool,
+}
+
+#[derive(FromForm)]
+pub(crate) struct SetCategoryForm {
+    hashes: String,
+    category: String,
+}
+
+/// `all` or hashes separated by `|`
+fn matches_hashes(hashes: &str, hash: &str) -> bool {
+    hashes == "all" || hashes.split('|').any(|h| h.eq_ignore_ascii_case(hash))
+}
+
+/// Stands in for the info hash of urls and files, which are never actually downloaded
+fn fake_hash(bytes: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+
+    format!("{:0>40x}", hasher.finish())
+}
+
+fn matches_filter(filter: &str, torrent: &FakeTorrent) -> Result<bool, BadRequest> {
+    Ok(match filter {
+        "all" => true,
+        "downloading" => !torrent.is_complete(),
+        "seeding" | "completed" => torrent.is_complete(),
+        "paused" | "stopped" => {
+            torrent.state_name().starts_with("paused")
+                || torrent.state_name().starts_with("stopped")
+        }
+        "errored" => torrent.state_name() == "error",
+        _ => return Err((Status::BadRequest, "filter parameter is invalid")),
+    })
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+#[rocket::get("/torrents/info?<query..>")]
+pub(crate) fn info(
+    _session: Session,
+    query: InfoQuery<'_>,
+    state: &State<SharedState>,
+) -> Result<Json<Vec<Map<String, Value>>>, BadRequest> {
+    let state = state.lock().unwrap();
+
+    let mut torrents = Vec::new();
+    for torrent in state.torrents.values() {
+        let matches = query
+            .filter
+            .map_or(Ok(true), |filter| matches_filter(filter, torrent))?
+            && query.category.is_none_or(|c| torrent.category() == c)
+            && query.tag.is_none_or(|tag| torrent.tags().contains(tag))
+            && query
+                .hashes
+                .is_none_or(|h| matches_hashes(h, torrent.hash()));
+
+        if matches {
+            torrents.push(torrent.to_json());
+        }
+    }
+
+    if let Some(sort) = query.sort {
+        torrents.sort_by(|a, b| compare(&a[sort], &b[sort]));
+    }
+    if query.reverse == Some(true) {
+        torrents.reverse();
+    }
+
+    // A negative offset counts from the end
+    let offset = match query.offset {
+        Some(offset) if offset < 0 => torrents
+            .len()
+            .saturating_sub(offset.unsigned_abs() as usize),
+        Some(offset) => offset as usize,
+        None => 0,
+    };
+    let limit = query.limit.filter(|limit| *limit > 0).unwrap_or(usize::MAX);
+
+    Ok(Json(
+        torrents.into_iter().skip(offset).take(limit).collect(),
+    ))
+}
+
+/// Adds the urls and `.torrent` files right away, the torrents then only progress when scripted
+#[rocket::post("/torrents/add", data = "<form>")]
+pub(crate) async fn add(
+    _session: Session,
+    form: Form<AddForm<'_>>,
+    state: &State<SharedState>,
+) -> &'static str {
+    let mut added = Vec::new();
+
+    for url in form.urls.iter().flat_map(|urls| urls.lines()) {
+        let url = url.trim();
+        if url.is_empty() {
+            continue;
+        }
+
+        match Magnet::from_url(url) {
+            Ok(magnet) if magnet.info_hash() != "Unknown" => added.push((
+                magnet.info_hash().to_lowercase(),
+                magnet.display_name().to_owned(),
+            )),
+            Ok(_) => {}
+            Err(_) => {
+                let name = url.rsplit('/').next().unwrap_or(url);
+                added.push((fake_hash(url.as_bytes()), name.to_owned()));
+            }
+        }
+    }
+
+    for file in &form.torrents {
+        let mut content = Vec::new();
+        let Ok(mut reader) = file.open().await else {
+            continue;
+        };
+        if reader.read_to_end(&mut content).await.is_err() {
+            continue;
+        }
+
+        let name = file.name().unwrap_or("torrent");
+        added.push((fake_hash(&content), name.to_owned()));
+    }
+
+    if added.is_empty() {
+        return "Fails.";
+    }
+
+    let tags: Vec<String> = form
+        .tags
+        .iter()
+        .flat_map(|tags| tags.split(','))
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_owned)
+        .collect();
+
+    let mut state = state.lock().unwrap();
+
+    if let Some(category) = form.category.as_ref().filter(|c| !c.is_empty()) {
+        state.categories.entry(category.clone()).or_default();
+    }
+    state.tags.extend(tags.iter().cloned());
+
+    for (hash, name) in added {
+        if state.torrents.contains_key(&hash) {
+            continue;
+        }
+
+        let mut torrent = FakeTorrent::new(hash.clone(), form.rename.clone().unwrap_or(name));
+        torrent.set_category(form.category.clone().unwrap_or_default());
+        torrent.add_tags(tags.iter().cloned());
+        if let Some(savepath) = &form.savepath {
+            torrent.set_save_path(savepath.clone());
+        }
+        if form.paused == Some(true) {
+            torrent.set_state(Some("pausedDL".to_string()));
+        }
+
+        state.torrents.insert(hash, torrent);
+    }
+
+    "Ok."
+}
+
+#[rocket::post("/torrents/delete", data = "<form>")]
+pub(crate) fn delete(_session: Session, form: Form<DeleteForm>, state: &State<SharedState>) {
+    state
+        .lock()
+        .unwrap()
+        .torrents
+        .retain(|hash, _| !matches_hashes(&form.hashes, hash));
+}
+
+#[rocket::post("/torrents/setCategory", data = "<form>")]
+pub(crate) fn set_category(
+    _session: Session,
+    form: Form<SetCategoryForm>,
+    state: &State<SharedState>,
+) -> Result<(), BadRequest> {
+    let mut state = state.lock().unwrap();
+
+    if !form.category.is_empty() && !state.categories.contains_key(&form.category) {
+        return Err((Status::Conflict, "Incorrect category name"));
+    }
+
+    for torrent in state.torrents.values_mut() {
+        if matches_hashes(&form.hashes, torrent.hash()) {
+            torrent.set_category(form.category.clone());
+        }
+    }
+
+    Ok(())
+}
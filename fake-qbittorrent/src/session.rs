@@ -0,0 +1,34 @@
+use crate::SharedState;
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request,
+};
+
+/// The name of qBittorrent's session cookie
+pub(crate) const SESSION_COOKIE: &str = "SID";
+
+/// Rejects requests without a valid session cookie with `403 Forbidden`, like qBittorrent
+pub(crate) struct Session;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Session {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(state) = request.rocket().state::<SharedState>() else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+
+        let valid = request
+            .cookies()
+            .get(SESSION_COOKIE)
+            .is_some_and(|cookie| state.lock().unwrap().sessions.contains(cookie.value()));
+
+        if valid {
+            Outcome::Success(Session)
+        } else {
+            Outcome::Error((Status::Forbidden, ()))
+        }
+    }
+}
@@ -0,0 +1,476 @@
+use getset::Getters;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// qBittorrent's ETA for torrents which are not downloading
+const ETA_UNKNOWN: u64 = 8640000;
+
+/// A torrent in the fake server, rendered as the full `torrents/info` object
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct FakeTorrent {
+    hash: String,
+    name: String,
+    category: String,
+    tags: BTreeSet<String>,
+    save_path: String,
+    size: u64,
+    progress: f64,
+    dlspeed: u64,
+    /// Overrides the state derived from the progress, e.g. `pausedDL`
+    state: Option<String>,
+    added_on: u64,
+    completion_on: Option<u64>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+impl FakeTorrent {
+    pub(crate) fn new(hash: String, name: String) -> Self {
+        Self {
+            hash,
+            name,
+            category: String::new(),
+            tags: BTreeSet::new(),
+            save_path: "/downloads".to_string(),
+            size: 1 << 30,
+            progress: 0.0,
+            dlspeed: 0,
+            state: None,
+            added_on: now(),
+            completion_on: None,
+        }
+    }
+
+    pub(crate) fn set_category(&mut self, category: String) {
+        self.category = category;
+    }
+
+    pub(crate) fn set_save_path(&mut self, save_path: String) {
+        self.save_path = save_path;
+    }
+
+    pub(crate) fn add_tags<I: IntoIterator<Item = String>>(&mut self, tags: I) {
+        self.tags.extend(tags);
+    }
+
+    pub(crate) fn set_state(&mut self, state: Option<String>) {
+        self.state = state;
+    }
+
+    pub(crate) fn set_dlspeed(&mut self, dlspeed: u64) {
+        self.dlspeed = dlspeed;
+    }
+
+    pub(crate) fn set_progress(&mut self, progress: f64) {
+        self.progress = progress.clamp(0.0, 1.0);
+
+        if self.is_complete() {
+            self.dlspeed = 0;
+            self.completion_on.get_or_insert_with(now);
+        } else {
+            self.completion_on = None;
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.progress >= 1.0
+    }
+
+    /// The state qBittorrent would report, `stalledUP` once the download is complete
+    pub fn state_name(&self) -> &str {
+        match &self.state {
+            Some(state) => state,
+            None if self.is_complete() => "stalledUP",
+            None if self.dlspeed > 0 => "downloading",
+            None => "stalledDL",
+        }
+    }
+
+    fn amount_left(&self) -> u64 {
+        ((1.0 - self.progress) * self.size as f64) as u64
+    }
+
+    fn eta(&self) -> u64 {
+        if self.is_complete() || self.dlspeed == 0 {
+            ETA_UNKNOWN
+        } else {
+            self.amount_left() / self.dlspeed
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Map<String, Value> {
+        let downloaded = self.size - self.amount_left();
+        let content_path = format!("{}/{}", self.save_path.trim_end_matches('/'), self.name);
+        let tags: Vec<&str> = self.tags.iter().map(String::as_str).collect();
+
+        let torrent = json!({
+            "added_on": self.added_on,
+            "amount_left": self.amount_left(),
+            "auto_tmm": false,
+            "availability": if self.is_complete() { -1.0 } else { 1.0 },
+            "category": self.category,
+            "completed": downloaded,
+            "completion_on": self.completion_on.unwrap_or_default(),
+            "content_path": content_path,
+            "dl_limit": -1,
+            "dlspeed": self.dlspeed,
+            "downloaded": downloaded,
+            "downloaded_session": downloaded,
+            "eta": self.eta(),
+            "f_l_piece_prio": false,
+            "force_start": false,
+            "infohash_v1": self.hash,
+            "last_activity": self.added_on,
+            "magnet_uri": format!("magnet:?xt=urn:btih:{}&dn={}", self.hash, self.name),
+            "max_ratio": -1.0,
+            "max_seeding_time": -1,
+            "name": self.name,
+            "num_complete": 0,
+            "num_incomplete": 0,
+            "num_leechs": 0,
+            "num_seeds": 0,
+            "priority": 0,
+            "progress": self.progress,
+            "ratio": 0.0,
+            "ratio_limit": -2.0,
+            "save_path": self.save_path,
+            "seeding_time_limit": -2,
+            "seen_complete": self.completion_on.unwrap_or_default(),
+            "seq_dl": false,
+            "size": self.size,
+            "state": self.state_name(),
+            "super_seeding": false,
+            "tags": tags.join(", "),
+            "time_active": 0,
+            "total_size": self.size,
+            "tracker": "",
+            "up_limit": -1,
+            "uploaded": 0,
+            "uploaded_session": 0,
+            "upspeed": 0,
+        });
+
+        match torrent {
+            Value::Object(torrent) => torrent,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// What `sync/maindata` reported for a rid, deltas are computed against it
+#[derive(Debug, Clone, Default)]
+struct Snapshot {
+    torrents: BTreeMap<String, Map<String, Value>>,
+    categories: BTreeMap<String, Value>,
+    tags: BTreeSet<String>,
+    server_state: Map<String, Value>,
+}
+
+/// Only the fields which differ from `previous`
+fn changed_fields(
+    current: &Map<String, Value>,
+    previous: &Map<String, Value>,
+) -> Map<String, Value> {
+    current
+        .iter()
+        .filter(|(key, value)| previous.get(*key) != Some(*value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+impl Snapshot {
+    fn full_update(&self, rid: usize) -> Value {
+        json!({
+            "rid": rid,
+            "full_update": true,
+            "torrents": self.torrents,
+            "categories": self.categories,
+            "tags": self.tags,
+            "server_state": self.server_state,
+        })
+    }
+
+    /// Like qBittorrent, unchanged torrents and fields are left out of the delta
+    fn delta(&self, previous: &Snapshot, rid: usize) -> Value {
+        let mut data = Map::new();
+        data.insert("rid".to_string(), rid.into());
+
+        let torrents: Map<String, Value> = self
+            .torrents
+            .iter()
+            .filter_map(|(hash, torrent)| {
+                let changed = match previous.torrents.get(hash) {
+                    Some(old) => changed_fields(torrent, old),
+                    None => torrent.clone(),
+                };
+                (!changed.is_empty()).then(|| (hash.clone(), Value::Object(changed)))
+            })
+            .collect();
+        let torrents_removed: Vec<&String> = previous
+            .torrents
+            .keys()
+            .filter(|hash| !self.torrents.contains_key(*hash))
+            .collect();
+
+        let categories: Map<String, Value> = self
+            .categories
+            .iter()
+            .filter(|(name, category)| previous.categories.get(*name) != Some(*category))
+            .map(|(name, category)| (name.clone(), category.clone()))
+            .collect();
+        let categories_removed: Vec<&String> = previous
+            .categories
+            .keys()
+            .filter(|name| !self.categories.contains_key(*name))
+            .collect();
+
+        let tags: Vec<&String> = self.tags.difference(&previous.tags).collect();
+        let tags_removed: Vec<&String> = previous.tags.difference(&self.tags).collect();
+
+        let server_state = changed_fields(&self.server_state, &previous.server_state);
+
+        if !torrents.is_empty() {
+            data.insert("torrents".to_string(), torrents.into());
+        }
+        if !torrents_removed.is_empty() {
+            data.insert("torrents_removed".to_string(), json!(torrents_removed));
+        }
+        if !categories.is_empty() {
+            data.insert("categories".to_string(), categories.into());
+        }
+        if !categories_removed.is_empty() {
+            data.insert("categories_removed".to_string(), json!(categories_removed));
+        }
+        if !tags.is_empty() {
+            data.insert("tags".to_string(), json!(tags));
+        }
+        if !tags_removed.is_empty() {
+            data.insert("tags_removed".to_string(), json!(tags_removed));
+        }
+        if !server_state.is_empty() {
+            data.insert("server_state".to_string(), server_state.into());
+        }
+
+        Value::Object(data)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    pub(crate) torrents: BTreeMap<String, FakeTorrent>,
+    /// Category name to save path
+    pub(crate) categories: BTreeMap<String, String>,
+    pub(crate) tags: BTreeSet<String>,
+    pub(crate) sessions: HashSet<String>,
+    pub(crate) logins: usize,
+    /// Every result a search can find, rendered as `search/results` items
+    pub(crate) search_results: Vec<Value>,
+    /// The results of the jobs which were not deleted, by id
+    pub(crate) search_jobs: BTreeMap<u64, Vec<Value>>,
+    searches: u64,
+    /// Rendered as `log/main` items, ids start over once the log is cleared
+    pub(crate) log: Vec<Value>,
+    pub(crate) peer_log: Vec<Value>,
+    rid: usize,
+    snapshots: HashMap<usize, Snapshot>,
+    /// The rid of every `sync/maindata` request
+    pub(crate) sync_rids: Vec<usize>,
+    /// Sends the next delta with a `torrents` field which is not an object
+    pub(crate) corrupt_next_delta: bool,
+}
+
+impl State {
+    pub(crate) fn torrent_mut(&mut self, hash: &str) -> &mut FakeTorrent {
+        self.torrents
+            .get_mut(hash)
+            .unwrap_or_else(|| panic!("No fake torrent with hash {hash}"))
+    }
+
+    pub(crate) fn new_session(&mut self) -> String {
+        self.logins += 1;
+        let session_id = format!("fake-session-{}", self.logins);
+        self.sessions.insert(session_id.clone());
+
+        session_id
+    }
+
+    fn next_log_id(entries: &[Value]) -> i64 {
+        entries
+            .last()
+            .and_then(|entry| entry["id"].as_i64())
+            .map_or(0, |id| id + 1)
+    }
+
+    pub(crate) fn add_log(&mut self, message: &str, log_type: u8) {
+        self.log.push(json!({
+            "id": Self::next_log_id(&self.log),
+            "message": message,
+            "timestamp": now() * 1000,
+            "type": log_type,
+        }));
+    }
+
+    pub(crate) fn add_peer_log(&mut self, ip: &str, blocked: bool, reason: &str) {
+        self.peer_log.push(json!({
+            "id": Self::next_log_id(&self.peer_log),
+            "ip": ip,
+            "timestamp": now() * 1000,
+            "blocked": blocked,
+            "reason": reason,
+        }));
+    }
+
+    /// Finds the results whose name contains every word of `pattern`, ignoring case
+    pub(crate) fn start_search(&mut self, pattern: &str) -> u64 {
+        let words: Vec<String> = pattern.split_whitespace().map(str::to_lowercase).collect();
+
+        let results = self
+            .search_results
+            .iter()
+            .filter(|result| {
+                let name = result["fileName"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                words.iter().all(|word| name.contains(word))
+            })
+            .cloned()
+            .collect();
+
+        self.searches += 1;
+        self.search_jobs.insert(self.searches, results);
+
+        self.searches
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        let dl_info_speed: u64 = self.torrents.values().map(|t| *t.dlspeed()).sum();
+
+        Snapshot {
+            torrents: self
+                .torrents
+                .iter()
+                .map(|(hash, torrent)| (hash.clone(), torrent.to_json()))
+                .collect(),
+            categories: self
+                .categories
+                .iter()
+                .map(|(name, save_path)| {
+                    (name.clone(), json!({ "name": name, "savePath": save_path }))
+                })
+                .collect(),
+            tags: self.tags.clone(),
+            server_state: match json!({
+                "connection_status": "connected",
+                "dl_info_speed": dl_info_speed,
+                "up_info_speed": 0,
+            }) {
+                Value::Object(server_state) => server_state,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    /// A full update for rid 0 or an unknown rid, otherwise the changes since that rid
+    pub(crate) fn main_data(&mut self, rid: usize) -> Value {
+        self.sync_rids.push(rid);
+        let current = self.snapshot();
+        self.rid += 1;
+
+        let data = match self.snapshots.get(&rid) {
+            Some(_) if rid != 0 && self.corrupt_next_delta => {
+                self.corrupt_next_delta = false;
+                json!({ "rid": self.rid, "torrents": 42 })
+            }
+            Some(previous) if rid != 0 => current.delta(previous, self.rid),
+            _ => current.full_update(self.rid),
+        };
+        self.snapshots.insert(self.rid, current);
+
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_torrent() -> State {
+        let mut state = State::default();
+        state.categories.insert("movies".to_string(), String::new());
+        let mut torrent = FakeTorrent::new("abc".to_string(), "Movie".to_string());
+        torrent.set_category("movies".to_string());
+        state.torrents.insert("abc".to_string(), torrent);
+
+        state
+    }
+
+    #[test]
+    fn test_main_data_delta() {
+        let mut state = state_with_torrent();
+
+        let full = state.main_data(0);
+        assert_eq!(full["full_update"], true);
+        assert_eq!(full["torrents"]["abc"]["category"], "movies");
+        assert_eq!(full["categories"]["movies"]["savePath"], "");
+
+        let rid = full["rid"].as_u64().unwrap() as usize;
+        let unchanged = state.main_data(rid);
+        assert_eq!(unchanged.as_object().unwrap().len(), 1);
+
+        state.torrent_mut("abc").set_progress(0.5);
+        let rid = unchanged["rid"].as_u64().unwrap() as usize;
+        let delta = state.main_data(rid);
+        let torrent = delta["torrents"]["abc"].as_object().unwrap();
+        assert_eq!(torrent["progress"], 0.5);
+        assert!(!torrent.contains_key("name"));
+        assert!(delta.get("full_update").is_none());
+    }
+
+    #[test]
+    fn test_main_data_removed() {
+        let mut state = state_with_torrent();
+        let rid = state.main_data(0)["rid"].as_u64().unwrap() as usize;
+
+        state.torrents.clear();
+        state.categories.clear();
+
+        let delta = state.main_data(rid);
+        assert_eq!(delta["torrents_removed"], json!(["abc"]));
+        assert_eq!(delta["categories_removed"], json!(["movies"]));
+    }
+
+    #[test]
+    fn test_start_search() {
+        let mut state = State::default();
+        state
+            .search_results
+            .push(json!({ "fileName": "The.Matrix.1999.1080p" }));
+        state
+            .search_results
+            .push(json!({ "fileName": "The Matrix Reloaded 2003" }));
+
+        let id = state.start_search("matrix 1999");
+        assert_eq!(state.search_jobs[&id].len(), 1);
+
+        let id = state.start_search("MATRIX");
+        assert_eq!(state.search_jobs[&id].len(), 2);
+    }
+
+    #[test]
+    fn test_main_data_unknown_rid() {
+        let mut state = state_with_torrent();
+
+        let data = state.main_data(42);
+        assert_eq!(data["full_update"], true);
+    }
+}
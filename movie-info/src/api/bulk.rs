@@ -1,32 +1,80 @@
 use crate::{
     models::{movie_info::MovieInfo, tmdb_id::TmdbId},
+    movie_cache::{parse_cached, tmdb_key},
     Error, MovieInfoClient,
 };
+use futures::future::join_all;
 use log::debug;
+use serde_json::Value;
 use std::collections::HashSet;
 
 impl MovieInfoClient {
-    pub async fn bulk(&self, tmdb_ids: &HashSet<TmdbId>) -> Result<Vec<MovieInfo>, Error> {
-        let mut movies: Vec<serde_json::Value> = self
-            .http
-            .post("movie/bulk")
-            .body_json(tmdb_ids)
-            .unwrap()
-            .recv_json()
+    /// Fetches the movies which are not cached, concurrent calls for the same ids share the request
+    async fn fetch_bulk(&self, tmdb_ids: &[TmdbId]) -> Result<Vec<(TmdbId, Value)>, Error> {
+        let key = format!(
+            "bulk:{}",
+            tmdb_ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        let movies = self
+            .cache
+            .single_flight(&key, || async {
+                let mut movies = self.provider.bulk(tmdb_ids).await?;
+                join_all(movies.iter_mut().map(|movie| self.add_ratings(movie))).await;
+
+                let mut found = Vec::new();
+                for tmdb_id in tmdb_ids {
+                    let movie = movies.iter().find(|movie| movie.tmdb_id == *tmdb_id);
+                    if movie.is_none() {
+                        debug!("No movie found for tmdb_id: {tmdb_id}");
+                    }
+
+                    let movie = serde_json::to_value(movie)?;
+                    self.cache.insert(tmdb_key(*tmdb_id), movie.clone());
+                    found.push((*tmdb_id, movie));
+                }
+                self.cache.schedule_save();
+
+                Ok(Value::Array(
+                    found
+                        .into_iter()
+                        .map(|(tmdb_id, movie)| Value::Array(vec![tmdb_id.into(), movie]))
+                        .collect(),
+                ))
+            })
             .await?;
 
-        movies.retain(|m| {
-            let retain = m["Year"] != 0;
-            if !retain {
-                debug!("No movie found for tmdb_id: {}", m["TmdbId"])
-            };
-            retain
-        });
-
-        Ok(movies
-            .into_iter()
-            .map(|m| serde_json::from_value(m).unwrap())
-            .collect())
+        Ok(serde_json::from_value(movies)?)
+    }
+
+    pub async fn bulk(&self, tmdb_ids: &HashSet<TmdbId>) -> Result<Vec<MovieInfo>, Error> {
+        let mut missing = Vec::new();
+        let mut movies = Vec::new();
+
+        for tmdb_id in tmdb_ids {
+            match self.cache.get(&tmdb_key(*tmdb_id)) {
+                Some(movie) => movies.push((*tmdb_id, movie)),
+                None => missing.push(*tmdb_id),
+            }
+        }
+
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            movies.extend(self.fetch_bulk(&missing).await?);
+        }
+
+        movies.sort_unstable_by_key(|(tmdb_id, _)| *tmdb_id);
+
+        let mut found = Vec::new();
+        for (_, movie) in movies {
+            found.extend(parse_cached(movie)?);
+        }
+
+        Ok(found)
     }
 }
 
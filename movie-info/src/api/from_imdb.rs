@@ -1,14 +1,29 @@
-use crate::{models::movie_info::MovieInfo, Error, MovieInfoClient};
+use crate::{
+    models::movie_info::MovieInfo,
+    movie_cache::{imdb_key, parse_cached},
+    Error, MovieInfoClient,
+};
+use serde_json::Value;
 
 impl MovieInfoClient {
+    async fn fetch_imdb(&self, imdb: &str) -> Result<Value, Error> {
+        let mut movie = self.provider.movie_by_imdb(imdb).await?;
+
+        if let Some(movie) = &mut movie {
+            self.add_ratings(movie).await;
+        }
+
+        Ok(serde_json::to_value(movie)?)
+    }
+
+    /// Cached, concurrent calls for the same movie share a single request
     pub async fn from_imdb(&self, imdb: &str) -> Result<Option<MovieInfo>, Error> {
-        let movie: Vec<MovieInfo> = self
-            .http
-            .get(format!("movie/imdb/{imdb}"))
-            .recv_json()
+        let movie = self
+            .cache
+            .get_or_fetch(imdb_key(imdb), || self.fetch_imdb(imdb))
             .await?;
 
-        Ok(movie.into_iter().next())
+        parse_cached(movie)
     }
 }
 
@@ -1,19 +1,29 @@
 use crate::{
     models::{movie_info::MovieInfo, tmdb_id::TmdbId},
+    movie_cache::{parse_cached, tmdb_key},
     Error, MovieInfoClient,
 };
+use serde_json::Value;
 
 impl MovieInfoClient {
-    pub async fn from_tmdb(&self, tmdb: TmdbId) -> Result<Option<MovieInfo>, Error> {
-        let mut resp = self.http.get(format!("movie/{tmdb}")).send().await?;
+    async fn fetch_tmdb(&self, tmdb: TmdbId) -> Result<Value, Error> {
+        let mut movie = self.provider.movie_by_tmdb(tmdb).await?;
 
-        if resp.status().is_client_error() {
-            return Ok(None);
+        if let Some(movie) = &mut movie {
+            self.add_ratings(movie).await;
         }
 
-        let movie: MovieInfo = resp.body_json().await?;
+        Ok(serde_json::to_value(movie)?)
+    }
+
+    /// Cached, concurrent calls for the same movie share a single request
+    pub async fn from_tmdb(&self, tmdb: TmdbId) -> Result<Option<MovieInfo>, Error> {
+        let movie = self
+            .cache
+            .get_or_fetch(tmdb_key(tmdb), || self.fetch_tmdb(tmdb))
+            .await?;
 
-        Ok(Some(movie))
+        parse_cached(movie)
     }
 }
 
@@ -2,4 +2,5 @@ pub mod bulk;
 pub mod from_imdb;
 pub mod from_tmdb;
 pub mod search;
+pub mod series;
 pub mod trending_popular;
@@ -1,33 +1,10 @@
-use serde::Serialize;
-
 use crate::{
     models::{filters::Filters, movie_info::MovieInfo},
     utils::parse_imdb_id::parse_imdb_id,
     Error, MovieInfoClient,
 };
 
-#[derive(Serialize)]
-struct Query {
-    q: String,
-}
-
-impl Query {
-    pub fn new(q: String) -> Self {
-        Self { q }
-    }
-}
-
 impl MovieInfoClient {
-    async fn force_search(&self, query: String) -> Result<Vec<MovieInfo>, Error> {
-        Ok(self
-            .http
-            .get("search")
-            .query(&Query::new(query))
-            .unwrap()
-            .recv_json()
-            .await?)
-    }
-
     pub async fn search(&self, query: String, filters: &Filters) -> Result<Vec<MovieInfo>, Error> {
         let query = query.trim().to_lowercase();
 
@@ -45,7 +22,7 @@ impl MovieInfoClient {
                     Vec::new()
                 }
             }
-            None => self.force_search(query).await?,
+            None => self.provider.search(&query).await?,
         };
 
         filters.filter(&mut movies);
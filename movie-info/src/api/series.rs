@@ -0,0 +1,79 @@
+use crate::{
+    models::{series_info::SeriesInfo, tmdb_id::TmdbId, tvdb_id::TvdbId},
+    movie_cache::{parse_cached, series_imdb_key, series_tmdb_key, tvdb_key},
+    Error, MovieInfoClient,
+};
+
+impl MovieInfoClient {
+    /// Cached, concurrent calls for the same series share a single request
+    pub async fn series_info(&self, tvdb: TvdbId) -> Result<Option<SeriesInfo>, Error> {
+        let series = self
+            .cache
+            .get_or_fetch(tvdb_key(tvdb), || async {
+                Ok(serde_json::to_value(
+                    self.series.series_by_tvdb(tvdb).await?,
+                )?)
+            })
+            .await?;
+
+        parse_cached(series)
+    }
+
+    /// Looks the TVDB id up once, the series is then cached like with `series_info`
+    pub async fn series_by_imdb(&self, imdb: &str) -> Result<Option<SeriesInfo>, Error> {
+        let tvdb = self
+            .cache
+            .get_or_fetch(series_imdb_key(imdb), || async {
+                Ok(serde_json::to_value(self.series.tvdb_by_imdb(imdb).await?)?)
+            })
+            .await?;
+
+        match parse_cached(tvdb)? {
+            Some(tvdb) => self.series_info(tvdb).await,
+            None => Ok(None),
+        }
+    }
+
+    /// `tmdb` is a TMDB TV id, which are numbered apart from the movie ids
+    pub async fn series_by_tmdb(&self, tmdb: TmdbId) -> Result<Option<SeriesInfo>, Error> {
+        let tvdb = self
+            .cache
+            .get_or_fetch(series_tmdb_key(tmdb), || async {
+                Ok(serde_json::to_value(self.series.tvdb_by_tmdb(tmdb).await?)?)
+            })
+            .await?;
+
+        match parse_cached(tvdb)? {
+            Some(tvdb) => self.series_info(tvdb).await,
+            None => Ok(None),
+        }
+    }
+
+    /// The series matching `query`, without their episodes
+    pub async fn search_series(&self, query: String) -> Result<Vec<SeriesInfo>, Error> {
+        let query = query.trim().to_lowercase();
+
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.series.search(&query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref CLIENT: MovieInfoClient = MovieInfoClient::new();
+    }
+
+    #[tokio::test]
+    async fn test_empty_search() {
+        let series = CLIENT.search_series("  ".to_string()).await.unwrap();
+
+        assert!(series.is_empty());
+    }
+}
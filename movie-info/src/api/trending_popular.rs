@@ -1,24 +1,20 @@
 use crate::{models::movie_info::MovieInfo, Error, Filters, MovieInfoClient};
 
 impl MovieInfoClient {
-    async fn get(&self, path: &str, filters: Filters) -> Result<Vec<MovieInfo>, Error> {
-        let mut movies: Vec<MovieInfo> = self
-            .http
-            .get(format!("list/tmdb/{path}"))
-            .recv_json()
-            .await?;
+    pub async fn trending(&self, filters: Filters) -> Result<Vec<MovieInfo>, Error> {
+        let mut movies = self.provider.trending().await?;
 
         filters.filter(&mut movies);
 
         Ok(movies)
     }
 
-    pub async fn trending(&self, filters: Filters) -> Result<Vec<MovieInfo>, Error> {
-        self.get("trending", filters).await
-    }
-
     pub async fn popular(&self, filters: Filters) -> Result<Vec<MovieInfo>, Error> {
-        self.get("popular", filters).await
+        let mut movies = self.provider.popular().await?;
+
+        filters.filter(&mut movies);
+
+        Ok(movies)
     }
 }
 
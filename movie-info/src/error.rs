@@ -4,12 +4,14 @@ use surf::Error as SurfError;
 #[derive(Debug)]
 pub enum Error {
     RequestError(SurfError),
+    ParsingError(serde_json::Error),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Error::RequestError(error) => write!(f, "RequestError: {error}"),
+            Error::ParsingError(error) => write!(f, "ParsingError: {error}"),
         }
     }
 }
@@ -19,3 +21,9 @@ impl From<SurfError> for Error {
         Self::RequestError(request_error)
     }
 }
+
+impl From<serde_json::Error> for Error {
+    fn from(parsing_error: serde_json::Error) -> Self {
+        Self::ParsingError(parsing_error)
+    }
+}
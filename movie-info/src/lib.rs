@@ -1,26 +1,92 @@
 mod api;
 mod error;
 mod models;
+mod movie_cache;
+mod movie_info_client_options;
+mod providers;
 mod utils;
+use ::utils::proxy::with_proxy;
+use ::utils::rate_limiter::RateLimiter;
 use ::utils::surf_logging::SurfLogging;
 pub use error::Error;
+use log::warn;
 pub use models::filters::Filters;
 pub use models::movie_info::MovieInfo;
+pub use models::series_info::{Episode, Season, SeriesActor, SeriesInfo};
 pub use models::tmdb_id::TmdbId;
+pub use models::tvdb_id::TvdbId;
+use movie_cache::MovieCache;
+pub use movie_info_client_options::{MetadataBackend, MovieInfoClientOptions, DEFAULT_CACHE_TTL};
+pub use providers::tmdb::TmdbAuth;
+use providers::{
+    omdb::OmdbClient, radarr::RadarrProvider, skyhook::SkyhookProvider, tmdb::TmdbProvider,
+    MetadataProvider,
+};
 use surf::{Client, Config};
 
-#[derive(Default)]
+/// The HTTP client of a backend, with the rate limit and proxy of the options
+fn http_client(config: Config, options: &MovieInfoClientOptions) -> Client {
+    let rate_limiter = match options.rate_limit() {
+        Some(rate_limit) => RateLimiter::new().with_default_limit(*rate_limit),
+        None => RateLimiter::new(),
+    };
+
+    let client: Client = with_proxy(config, options.proxy().as_ref())
+        .try_into()
+        .unwrap();
+
+    client.with(rate_limiter).with(SurfLogging)
+}
+
 pub struct MovieInfoClient {
-    http: Client,
+    provider: Box<dyn MetadataProvider>,
+    omdb: Option<OmdbClient>,
+    /// Series always come from Skyhook, whatever the movie backend
+    series: SkyhookProvider,
+    cache: MovieCache,
+}
+
+impl Default for MovieInfoClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MovieInfoClient {
     pub fn new() -> Self {
-        let config = Config::new().set_base_url("https://api.radarr.video/v1/".parse().unwrap());
-        let client: Client = config.try_into().unwrap();
+        Self::with_options(MovieInfoClientOptions::default())
+    }
+
+    pub fn with_options(options: MovieInfoClientOptions) -> Self {
+        let provider: Box<dyn MetadataProvider> = match options.backend() {
+            MetadataBackend::Radarr => Box::new(RadarrProvider::new(&options)),
+            MetadataBackend::Tmdb(auth) => Box::new(TmdbProvider::new(auth.clone(), &options)),
+        };
 
         Self {
-            http: client.with(SurfLogging),
+            provider,
+            omdb: options
+                .omdb_api_key()
+                .clone()
+                .map(|api_key| OmdbClient::new(api_key, &options)),
+            series: SkyhookProvider::new(&options),
+            cache: MovieCache::new(*options.cache_ttl(), options.cache_path().clone()),
+        }
+    }
+
+    /// Writes the cached movies and series to the disk now instead of after the next save delay, e.g. at shutdown
+    pub async fn save_cache(&self) {
+        self.cache.save().await;
+    }
+
+    /// Fills the ratings the backend did not return from OMDb, a failing OMDb request keeps the movie as is
+    async fn add_ratings(&self, movie: &mut MovieInfo) {
+        let (Some(omdb), Some(imdb)) = (&self.omdb, &movie.imdb_id) else {
+            return;
+        };
+
+        if let Err(error) = omdb.fill_ratings(imdb, &mut movie.movie_ratings).await {
+            warn!("Could not get the OMDb ratings of {imdb}: {error}");
         }
     }
 }
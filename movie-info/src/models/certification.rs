@@ -1,11 +1,11 @@
 use getset::Getters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
 pub struct Certification {
-    country: String,
-    certification: String,
+    pub(crate) country: String,
+    pub(crate) certification: String,
 }
@@ -1,13 +1,13 @@
 use getset::Getters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::tmdb_id::TmdbId;
 
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
 pub struct Collection {
-    name: String,
-    tmdb_id: TmdbId,
+    pub(crate) name: String,
+    pub(crate) tmdb_id: TmdbId,
 }
@@ -1,5 +1,5 @@
 use getset::Getters;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{image::Image, tmdb_id::TmdbId};
 
@@ -17,39 +17,55 @@ where
     Ok(headshot_url)
 }
 
-#[derive(Deserialize, Debug, Getters)]
+fn serialize_headshot_url<S>(
+    headshot_url: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Image::list("Headshot", headshot_url).serialize(serializer)
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 pub struct CastItem {
-    name: String,
-    order: i32,
-    character: String,
-    tmdb_id: TmdbId,
-    credit_id: String,
-    #[serde(deserialize_with = "deserialize_headshot_url")]
+    pub(crate) name: String,
+    pub(crate) order: i32,
+    pub(crate) character: String,
+    pub(crate) tmdb_id: TmdbId,
+    pub(crate) credit_id: String,
+    #[serde(
+        deserialize_with = "deserialize_headshot_url",
+        serialize_with = "serialize_headshot_url"
+    )]
     #[serde(rename = "Images")]
-    headshot_url: Option<String>,
+    pub(crate) headshot_url: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 pub struct CrewItem {
-    name: String,
-    job: String,
-    department: String,
-    tmdb_id: TmdbId,
-    credit_id: String,
-    #[serde(deserialize_with = "deserialize_headshot_url")]
+    pub(crate) name: String,
+    pub(crate) job: String,
+    pub(crate) department: String,
+    pub(crate) tmdb_id: TmdbId,
+    pub(crate) credit_id: String,
+    #[serde(
+        deserialize_with = "deserialize_headshot_url",
+        serialize_with = "serialize_headshot_url"
+    )]
     #[serde(rename = "Images")]
-    headshot_url: Option<String>,
+    pub(crate) headshot_url: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
 pub struct Credits {
-    cast: Vec<CastItem>,
-    crew: Vec<CrewItem>,
+    pub(crate) cast: Vec<CastItem>,
+    pub(crate) crew: Vec<CrewItem>,
 }
@@ -1,6 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Image {
     #[serde(rename = "CoverType")]
     cover_type: String,
@@ -9,6 +9,16 @@ pub struct Image {
 }
 
 impl Image {
+    /// The images of the Radarr API for `url`, empty when there is none
+    pub fn list(cover_type: &str, url: &Option<String>) -> Vec<Self> {
+        url.iter()
+            .map(|url| Self {
+                cover_type: cover_type.to_owned(),
+                url: url.to_owned(),
+            })
+            .collect()
+    }
+
     pub fn url(self) -> String {
         self.url
     }
@@ -6,4 +6,6 @@ pub mod image;
 pub mod movie_info;
 pub mod ratings;
 pub mod recommendation;
+pub mod series_info;
 pub mod tmdb_id;
+pub mod tvdb_id;
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use getset::Getters;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{
     certification::Certification, collection::Collection, credits::Credits, image::Image,
@@ -21,34 +21,44 @@ where
     Ok(poster_url)
 }
 
-#[derive(Deserialize, Debug, Getters)]
+fn serialize_poster_url<S>(poster_url: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Image::list("Poster", poster_url).serialize(serializer)
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
 pub struct MovieInfo {
-    imdb_id: Option<String>,
-    overview: String,
-    title: String,
-    original_title: String,
-    runtime: u16,
-    year: u16,
-    movie_ratings: MovieRatings,
-    genres: Vec<String>,
-    #[serde(deserialize_with = "deserialize_poster_url")]
+    pub(crate) imdb_id: Option<String>,
+    pub(crate) overview: String,
+    pub(crate) title: String,
+    pub(crate) original_title: String,
+    pub(crate) runtime: u16,
+    pub(crate) year: u16,
+    pub(crate) movie_ratings: MovieRatings,
+    pub(crate) genres: Vec<String>,
+    #[serde(
+        deserialize_with = "deserialize_poster_url",
+        serialize_with = "serialize_poster_url"
+    )]
     #[serde(rename = "Images")]
-    poster_url: Option<String>,
-    physical_release: Option<DateTime<Utc>>,
-    digital_release: Option<DateTime<Utc>>,
-    in_cinema: Option<DateTime<Utc>>,
-    recommendations: Vec<Recommendation>,
-    credits: Credits,
-    studio: String,
-    youtube_trailer_id: Option<String>,
-    certifications: Vec<Certification>,
-    collection: Option<Collection>,
-    original_language: String,
-    homepage: String,
-    tmdb_id: TmdbId,
+    pub(crate) poster_url: Option<String>,
+    pub(crate) physical_release: Option<DateTime<Utc>>,
+    pub(crate) digital_release: Option<DateTime<Utc>>,
+    pub(crate) in_cinema: Option<DateTime<Utc>>,
+    pub(crate) recommendations: Vec<Recommendation>,
+    pub(crate) credits: Credits,
+    pub(crate) studio: String,
+    pub(crate) youtube_trailer_id: Option<String>,
+    pub(crate) certifications: Vec<Certification>,
+    pub(crate) collection: Option<Collection>,
+    pub(crate) original_language: String,
+    pub(crate) homepage: String,
+    pub(crate) tmdb_id: TmdbId,
 }
 
 impl MovieInfo {
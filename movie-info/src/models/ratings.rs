@@ -1,7 +1,7 @@
 use getset::Getters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
@@ -9,13 +9,13 @@ pub struct MovieRating {
     pub value: f64,
     pub count: i32,
 }
-#[derive(Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 #[getset(get = "pub with_prefix")]
 pub struct MovieRatings {
-    tmdb: Option<MovieRating>,
-    imdb: Option<MovieRating>,
-    metacritic: Option<MovieRating>,
-    rotten_tomatoes: Option<MovieRating>,
+    pub(crate) tmdb: Option<MovieRating>,
+    pub(crate) imdb: Option<MovieRating>,
+    pub(crate) metacritic: Option<MovieRating>,
+    pub(crate) rotten_tomatoes: Option<MovieRating>,
 }
@@ -0,0 +1,78 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use super::{tmdb_id::TmdbId, tvdb_id::TvdbId};
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct Episode {
+    pub(crate) tvdb_id: TvdbId,
+    pub(crate) season_number: u32,
+    pub(crate) episode_number: u32,
+    pub(crate) absolute_episode_number: Option<u32>,
+    pub(crate) title: String,
+    pub(crate) overview: String,
+    /// The date in the timezone of the network
+    pub(crate) air_date: Option<NaiveDate>,
+    pub(crate) air_date_utc: Option<DateTime<Utc>>,
+    pub(crate) runtime: u16,
+    pub(crate) image_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct Season {
+    /// `0` holds the specials
+    pub(crate) season_number: u32,
+    pub(crate) poster_url: Option<String>,
+    pub(crate) episodes: Vec<Episode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SeriesActor {
+    pub(crate) name: String,
+    pub(crate) character: String,
+    pub(crate) headshot_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SeriesInfo {
+    pub(crate) tvdb_id: TvdbId,
+    pub(crate) tmdb_id: Option<TmdbId>,
+    pub(crate) imdb_id: Option<String>,
+    pub(crate) tv_maze_id: Option<u32>,
+    pub(crate) title: String,
+    pub(crate) alternate_titles: Vec<String>,
+    pub(crate) overview: String,
+    pub(crate) year: u16,
+    pub(crate) first_aired: Option<NaiveDate>,
+    pub(crate) last_aired: Option<NaiveDate>,
+    /// `continuing`, `ended` or `upcoming`
+    pub(crate) status: String,
+    /// Minutes per episode
+    pub(crate) runtime: u16,
+    pub(crate) network: Option<String>,
+    pub(crate) genres: Vec<String>,
+    pub(crate) content_rating: Option<String>,
+    pub(crate) original_language: Option<String>,
+    pub(crate) poster_url: Option<String>,
+    pub(crate) actors: Vec<SeriesActor>,
+    pub(crate) seasons: Vec<Season>,
+}
+
+impl SeriesInfo {
+    pub fn format(&self) -> String {
+        format!("{} ({})", self.title, self.year)
+    }
+}
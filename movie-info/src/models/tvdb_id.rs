@@ -0,0 +1 @@
+pub type TvdbId = u32;
@@ -0,0 +1,314 @@
+use crate::{
+    models::{tmdb_id::TmdbId, tvdb_id::TvdbId},
+    Error,
+};
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs,
+    future::Future,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{runtime::Handle, sync::OnceCell, task, time::sleep};
+
+/// Misses in this window are written to the disk together
+const SAVE_DELAY: Duration = Duration::from_secs(10);
+
+type Entries = Arc<Mutex<HashMap<String, CacheEntry>>>;
+
+pub(crate) fn tmdb_key(tmdb: TmdbId) -> String {
+    format!("tmdb:{tmdb}")
+}
+
+pub(crate) fn imdb_key(imdb: &str) -> String {
+    format!("imdb:{imdb}")
+}
+
+pub(crate) fn tvdb_key(tvdb: TvdbId) -> String {
+    format!("tvdb:{tvdb}")
+}
+
+/// The TVDB id of the series with this IMDb id, kept apart from the movies
+pub(crate) fn series_imdb_key(imdb: &str) -> String {
+    format!("series-imdb:{imdb}")
+}
+
+/// The TVDB id of the series with this TMDB id, TMDB numbers movies and series separately
+pub(crate) fn series_tmdb_key(tmdb: TmdbId) -> String {
+    format!("series-tmdb:{tmdb}")
+}
+
+/// `null` is cached for movies and series which were not found
+pub(crate) fn parse_cached<T: DeserializeOwned>(value: Value) -> Result<Option<T>, Error> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_value(value)?))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CacheEntry {
+    value: Value,
+    fetched_at: DateTime<Utc>,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        (Utc::now() - self.fetched_at)
+            .to_std()
+            .is_ok_and(|age| age < ttl)
+    }
+}
+
+/// Drops the expired entries and writes the others, the file is written off the async workers
+async fn write_entries(path: PathBuf, entries: Entries, ttl: Duration) {
+    let result = task::spawn_blocking(move || {
+        let data = {
+            let mut entries = entries.lock().unwrap();
+            entries.retain(|_, entry| entry.is_fresh(ttl));
+            serde_json::to_vec(&*entries)
+        };
+
+        data.map_err(|error| error.to_string())
+            .and_then(|data| fs::write(&path, data).map_err(|error| error.to_string()))
+            .map_err(|error| (path, error))
+    })
+    .await;
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err((path, error))) => {
+            warn!("Could not save the movie cache {}: {error}", path.display())
+        }
+        Err(error) => warn!("Could not save the movie cache: {error}"),
+    }
+}
+
+/// Movies by TMDB and IMDb id and series by TVDB id, parsed again on every hit
+pub(crate) struct MovieCache {
+    ttl: Duration,
+    path: Option<PathBuf>,
+    entries: Entries,
+    in_flight: Mutex<HashMap<String, Arc<OnceCell<Value>>>>,
+    save_pending: Arc<AtomicBool>,
+}
+
+impl MovieCache {
+    /// Loads the entries persisted at `path`, a missing or unreadable file starts an empty cache
+    pub(crate) fn new(ttl: Duration, path: Option<PathBuf>) -> Self {
+        let entries = path
+            .as_ref()
+            .filter(|path| path.exists())
+            .and_then(|path| {
+                fs::read(path)
+                    .map_err(|error| error.to_string())
+                    .and_then(|data| serde_json::from_slice(&data).map_err(|e| e.to_string()))
+                    .inspect_err(|error| {
+                        warn!("Could not load the movie cache {}: {error}", path.display())
+                    })
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Self {
+            ttl,
+            path,
+            entries: Arc::new(Mutex::new(entries)),
+            in_flight: Mutex::new(HashMap::new()),
+            save_pending: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<Value> {
+        let entries = self.entries.lock().unwrap();
+
+        entries
+            .get(key)
+            .filter(|entry| entry.is_fresh(self.ttl))
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Caches `value` under `key`, and a movie also under both of its ids
+    pub(crate) fn insert(&self, key: String, value: Value) {
+        let fetched_at = Utc::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(tmdb) = value["TmdbId"].as_i64().filter(|tmdb| *tmdb > 0) {
+            let entry = CacheEntry {
+                value: value.clone(),
+                fetched_at,
+            };
+            entries.insert(tmdb_key(tmdb as TmdbId), entry);
+        }
+        if let Some(imdb) = value["ImdbId"].as_str().filter(|imdb| !imdb.is_empty()) {
+            let entry = CacheEntry {
+                value: value.clone(),
+                fetched_at,
+            };
+            entries.insert(imdb_key(imdb), entry);
+        }
+
+        entries.insert(key, CacheEntry { value, fetched_at });
+    }
+
+    /// Writes the fresh entries to the disk when the cache is persisted
+    pub(crate) async fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        write_entries(path.clone(), Arc::clone(&self.entries), self.ttl).await;
+    }
+
+    /// Saves the cache [`SAVE_DELAY`] after a change, once for all the changes in between.
+    ///
+    /// Outside of a Tokio runtime nothing is scheduled and [`Self::save`] has to be called.
+    pub(crate) fn schedule_save(&self) {
+        let (Some(path), Ok(runtime)) = (&self.path, Handle::try_current()) else {
+            return;
+        };
+        if self.save_pending.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let path = path.clone();
+        let entries = Arc::clone(&self.entries);
+        let save_pending = Arc::clone(&self.save_pending);
+        let ttl = self.ttl;
+        runtime.spawn(async move {
+            sleep(SAVE_DELAY).await;
+            save_pending.store(false, Ordering::SeqCst);
+            write_entries(path, entries, ttl).await;
+        });
+    }
+
+    /// Runs `fetch` once for concurrent calls with the same key, the others wait for its value.
+    ///
+    /// When `fetch` fails, the next waiting call fetches again.
+    pub(crate) async fn single_flight<F, Fut>(&self, key: &str, fetch: F) -> Result<Value, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value, Error>>,
+    {
+        let cell = Arc::clone(
+            self.in_flight
+                .lock()
+                .unwrap()
+                .entry(key.to_owned())
+                .or_default(),
+        );
+
+        let result = cell.get_or_try_init(fetch).await.cloned();
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(key);
+        }
+
+        result
+    }
+
+    /// The cached value of `key`, or the value `fetch` returns which is then cached
+    pub(crate) async fn get_or_fetch<F, Fut>(&self, key: String, fetch: F) -> Result<Value, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value, Error>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        self.single_flight(&key, || async {
+            // Another call may have finished fetching since the cache was checked
+            if let Some(value) = self.get(&key) {
+                return Ok(value);
+            }
+
+            debug!("Movie cache miss for {key}");
+            let value = fetch().await?;
+            self.insert(key.clone(), value.clone());
+            self.schedule_save();
+
+            Ok(value)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::AtomicUsize;
+
+    const DAY: Duration = Duration::from_secs(24 * 3600);
+
+    #[test]
+    fn test_insert_under_both_ids() {
+        let cache = MovieCache::new(DAY, None);
+
+        cache.insert(
+            tmdb_key(603),
+            json!({ "TmdbId": 603, "ImdbId": "tt0133093" }),
+        );
+
+        assert!(cache.get(&imdb_key("tt0133093")).is_some());
+        assert!(cache.get(&tmdb_key(604)).is_none());
+    }
+
+    #[test]
+    fn test_expired() {
+        let cache = MovieCache::new(Duration::ZERO, None);
+
+        cache.insert(tmdb_key(603), json!({ "TmdbId": 603 }));
+
+        assert!(cache.get(&tmdb_key(603)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_single_flight() {
+        let cache = MovieCache::new(DAY, None);
+        let fetches = AtomicUsize::new(0);
+
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(json!({ "TmdbId": 603 }))
+        };
+
+        let (first, second) = tokio::join!(
+            cache.get_or_fetch(tmdb_key(603), fetch),
+            cache.get_or_fetch(tmdb_key(603), fetch),
+        );
+
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_persistence() {
+        let path = std::env::temp_dir().join(format!("movie-cache-{}.json", std::process::id()));
+
+        let cache = MovieCache::new(DAY, Some(path.clone()));
+        cache.insert(tmdb_key(603), json!({ "TmdbId": 603 }));
+        cache.save().await;
+
+        let loaded = MovieCache::new(DAY, Some(path.clone()));
+        assert!(loaded.get(&tmdb_key(603)).is_some());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+use getset::{Getters, Setters};
+use std::{path::PathBuf, time::Duration};
+use utils::{proxy::Proxy, rate_limiter::RateLimit};
+
+use crate::TmdbAuth;
+
+/// How long movies are cached by default
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// Where the movie metadata comes from
+#[derive(Debug, Clone, Default)]
+pub enum MetadataBackend {
+    /// The metadata proxy of Radarr, it needs no API key
+    #[default]
+    Radarr,
+    /// The TMDB v3 API
+    Tmdb(TmdbAuth),
+}
+
+#[derive(Debug, Clone, Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct MovieInfoClientOptions {
+    backend: MetadataBackend,
+    /// Fills the IMDb, Metacritic and Rotten Tomatoes ratings the backend does not return
+    omdb_api_key: Option<String>,
+    rate_limit: Option<RateLimit>,
+    proxy: Option<Proxy>,
+    /// Movies by TMDB and IMDb id are cached for this long, `0` disables the cache
+    cache_ttl: Duration,
+    /// Persists the cache to this JSON file, the cache is only kept in memory without it
+    cache_path: Option<PathBuf>,
+}
+
+impl Default for MovieInfoClientOptions {
+    fn default() -> Self {
+        Self {
+            backend: MetadataBackend::default(),
+            omdb_api_key: None,
+            rate_limit: None,
+            proxy: None,
+            cache_ttl: DEFAULT_CACHE_TTL,
+            cache_path: None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{models::tmdb_id::TmdbId, Error, MovieInfo};
+use async_trait::async_trait;
+use futures::future::try_join_all;
+
+pub mod omdb;
+pub mod radarr;
+pub mod skyhook;
+pub mod tmdb;
+
+/// A source of movie metadata, every response is mapped into [`MovieInfo`]
+#[async_trait]
+pub(crate) trait MetadataProvider: Send + Sync {
+    async fn movie_by_tmdb(&self, tmdb: TmdbId) -> Result<Option<MovieInfo>, Error>;
+
+    async fn movie_by_imdb(&self, imdb: &str) -> Result<Option<MovieInfo>, Error>;
+
+    /// The movies which were found, one request per movie unless the provider has a bulk endpoint
+    async fn bulk(&self, tmdb_ids: &[TmdbId]) -> Result<Vec<MovieInfo>, Error> {
+        let movies = try_join_all(tmdb_ids.iter().map(|tmdb| self.movie_by_tmdb(*tmdb))).await?;
+
+        Ok(movies.into_iter().flatten().collect())
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<MovieInfo>, Error>;
+
+    async fn trending(&self) -> Result<Vec<MovieInfo>, Error>;
+
+    async fn popular(&self) -> Result<Vec<MovieInfo>, Error>;
+}
@@ -0,0 +1,175 @@
+use crate::{
+    http_client,
+    models::ratings::{MovieRating, MovieRatings},
+    Error, MovieInfoClientOptions,
+};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use surf::{Client, Config};
+
+const OMDB_URL: &str = "https://www.omdbapi.com/";
+
+#[derive(Serialize)]
+struct Query<'a> {
+    i: &'a str,
+    apikey: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OmdbRating {
+    source: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OmdbMovie {
+    response: String,
+    error: Option<String>,
+    #[serde(rename = "imdbRating")]
+    imdb_rating: Option<String>,
+    #[serde(rename = "imdbVotes")]
+    imdb_votes: Option<String>,
+    metascore: Option<String>,
+    #[serde(default)]
+    ratings: Vec<OmdbRating>,
+}
+
+/// OMDb uses `N/A` for missing values, and formats them like `1,234` or `87%`
+fn parse_number<T: std::str::FromStr>(value: &str) -> Option<T> {
+    value
+        .trim_end_matches('%')
+        .trim_end_matches("/100")
+        .replace(',', "")
+        .parse()
+        .ok()
+}
+
+impl OmdbMovie {
+    fn rating(value: Option<&str>, count: Option<&str>) -> Option<MovieRating> {
+        Some(MovieRating {
+            value: parse_number(value?)?,
+            count: count.and_then(parse_number).unwrap_or_default(),
+        })
+    }
+
+    /// Fills the ratings the metadata provider did not return
+    fn fill(&self, ratings: &mut MovieRatings) {
+        let rotten_tomatoes = self
+            .ratings
+            .iter()
+            .find(|rating| rating.source == "Rotten Tomatoes")
+            .map(|rating| rating.value.as_str());
+
+        if ratings.imdb.is_none() {
+            ratings.imdb = Self::rating(self.imdb_rating.as_deref(), self.imdb_votes.as_deref());
+        }
+        if ratings.metacritic.is_none() {
+            ratings.metacritic = Self::rating(self.metascore.as_deref(), None);
+        }
+        if ratings.rotten_tomatoes.is_none() {
+            ratings.rotten_tomatoes = Self::rating(rotten_tomatoes, None);
+        }
+    }
+}
+
+/// The OMDb API, only used for the IMDb, Metacritic and Rotten Tomatoes ratings
+pub(crate) struct OmdbClient {
+    http: Client,
+    api_key: String,
+}
+
+impl OmdbClient {
+    pub(crate) fn new(api_key: String, options: &MovieInfoClientOptions) -> Self {
+        let config = Config::new().set_base_url(OMDB_URL.parse().unwrap());
+
+        Self {
+            http: http_client(config, options),
+            api_key,
+        }
+    }
+
+    /// Requests the ratings only when one of them is missing
+    pub(crate) async fn fill_ratings(
+        &self,
+        imdb: &str,
+        ratings: &mut MovieRatings,
+    ) -> Result<(), Error> {
+        if ratings.imdb.is_some()
+            && ratings.metacritic.is_some()
+            && ratings.rotten_tomatoes.is_some()
+        {
+            return Ok(());
+        }
+
+        let movie: OmdbMovie = self
+            .http
+            .get("")
+            .query(&Query {
+                i: imdb,
+                apikey: &self.api_key,
+            })?
+            .recv_json()
+            .await?;
+
+        if movie.response != "True" {
+            debug!(
+                "No OMDb ratings for {imdb}: {}",
+                movie.error.unwrap_or_default()
+            );
+            return Ok(());
+        }
+
+        movie.fill(ratings);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_fill() {
+        let movie: OmdbMovie = serde_json::from_value(json!({
+            "Title": "The Matrix",
+            "Ratings": [
+                { "Source": "Internet Movie Database", "Value": "8.7/10" },
+                { "Source": "Rotten Tomatoes", "Value": "83%" },
+                { "Source": "Metacritic", "Value": "73/100" },
+            ],
+            "Metascore": "73",
+            "imdbRating": "8.7",
+            "imdbVotes": "2,153,043",
+            "Response": "True",
+        }))
+        .unwrap();
+
+        let mut ratings = MovieRatings {
+            tmdb: None,
+            imdb: None,
+            metacritic: None,
+            rotten_tomatoes: Some(MovieRating {
+                value: 88.0,
+                count: 0,
+            }),
+        };
+        movie.fill(&mut ratings);
+
+        let imdb = ratings.imdb.unwrap();
+        assert_eq!(imdb.value, 8.7);
+        assert_eq!(imdb.count, 2_153_043);
+        assert_eq!(ratings.metacritic.unwrap().value, 73.0);
+        assert_eq!(ratings.rotten_tomatoes.unwrap().value, 88.0);
+        assert!(ratings.tmdb.is_none());
+    }
+
+    #[test]
+    fn test_not_available() {
+        assert_eq!(parse_number::<f64>("N/A"), None);
+        assert_eq!(parse_number::<f64>("83%"), Some(83.0));
+    }
+}
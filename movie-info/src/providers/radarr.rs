@@ -0,0 +1,103 @@
+use crate::{
+    http_client, models::tmdb_id::TmdbId, providers::MetadataProvider, Error, MovieInfo,
+    MovieInfoClientOptions,
+};
+use async_trait::async_trait;
+use log::debug;
+use serde::Serialize;
+use serde_json::Value;
+use surf::{Client, Config};
+
+const RADARR_URL: &str = "https://api.radarr.video/v1/";
+
+#[derive(Serialize)]
+struct Query<'a> {
+    q: &'a str,
+}
+
+/// The metadata proxy Radarr uses, it needs no API key
+pub(crate) struct RadarrProvider {
+    http: Client,
+}
+
+impl RadarrProvider {
+    pub(crate) fn new(options: &MovieInfoClientOptions) -> Self {
+        let config = Config::new().set_base_url(RADARR_URL.parse().unwrap());
+
+        Self {
+            http: http_client(config, options),
+        }
+    }
+
+    async fn list(&self, path: &str) -> Result<Vec<MovieInfo>, Error> {
+        Ok(self
+            .http
+            .get(format!("list/tmdb/{path}"))
+            .recv_json()
+            .await?)
+    }
+}
+
+#[async_trait]
+impl MetadataProvider for RadarrProvider {
+    async fn movie_by_tmdb(&self, tmdb: TmdbId) -> Result<Option<MovieInfo>, Error> {
+        let mut resp = self.http.get(format!("movie/{tmdb}")).send().await?;
+
+        if resp.status().is_client_error() {
+            return Ok(None);
+        }
+
+        Ok(Some(resp.body_json().await?))
+    }
+
+    async fn movie_by_imdb(&self, imdb: &str) -> Result<Option<MovieInfo>, Error> {
+        let movies: Vec<MovieInfo> = self
+            .http
+            .get(format!("movie/imdb/{imdb}"))
+            .recv_json()
+            .await?;
+
+        Ok(movies.into_iter().next())
+    }
+
+    async fn bulk(&self, tmdb_ids: &[TmdbId]) -> Result<Vec<MovieInfo>, Error> {
+        let mut movies: Vec<Value> = self
+            .http
+            .post("movie/bulk")
+            .body_json(&tmdb_ids)
+            .unwrap()
+            .recv_json()
+            .await?;
+
+        movies.retain(|m| {
+            let retain = m["Year"] != 0;
+            if !retain {
+                debug!("No movie found for tmdb_id: {}", m["TmdbId"])
+            };
+            retain
+        });
+
+        Ok(movies
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<MovieInfo>, Error> {
+        Ok(self
+            .http
+            .get("search")
+            .query(&Query { q: query })
+            .unwrap()
+            .recv_json()
+            .await?)
+    }
+
+    async fn trending(&self) -> Result<Vec<MovieInfo>, Error> {
+        self.list("trending").await
+    }
+
+    async fn popular(&self) -> Result<Vec<MovieInfo>, Error> {
+        self.list("popular").await
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{
+    http_client,
+    models::{
+        series_info::{Episode, Season, SeriesActor, SeriesInfo},
+        tmdb_id::TmdbId,
+        tvdb_id::TvdbId,
+    },
+    Error, MovieInfoClientOptions,
+};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use surf::{Client, Config, StatusCode};
+
+/// The TVDB metadata proxy Sonarr uses, it needs no API key
+const SKYHOOK_URL: &str = "https://skyhook.sonarr.tv/v1/tvdb/";
+const LANGUAGE: &str = "en";
+
+#[derive(Serialize)]
+struct Query<'a> {
+    term: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkyhookImage {
+    cover_type: String,
+    url: String,
+}
+
+fn poster_url(images: Vec<SkyhookImage>) -> Option<String> {
+    images
+        .into_iter()
+        .find(|image| image.cover_type.eq_ignore_ascii_case("poster"))
+        .map(|image| image.url)
+}
+
+#[derive(Deserialize)]
+struct SkyhookTitle {
+    title: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkyhookActor {
+    name: String,
+    #[serde(default)]
+    character: Option<String>,
+    image: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkyhookSeason {
+    season_number: u32,
+    #[serde(default)]
+    images: Vec<SkyhookImage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkyhookEpisode {
+    tvdb_id: TvdbId,
+    season_number: u32,
+    episode_number: u32,
+    absolute_episode_number: Option<u32>,
+    title: Option<String>,
+    overview: Option<String>,
+    air_date: Option<NaiveDate>,
+    air_date_utc: Option<DateTime<Utc>>,
+    runtime: Option<u16>,
+    image: Option<String>,
+}
+
+/// `shows/{language}/{tvdb}`, searches return the same shows without their episodes
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkyhookShow {
+    tvdb_id: TvdbId,
+    tmdb_id: Option<TmdbId>,
+    imdb_id: Option<String>,
+    tv_maze_id: Option<u32>,
+    title: String,
+    #[serde(default)]
+    alternate_titles: Vec<SkyhookTitle>,
+    overview: Option<String>,
+    first_aired: Option<NaiveDate>,
+    last_aired: Option<NaiveDate>,
+    #[serde(default)]
+    status: String,
+    runtime: Option<u16>,
+    network: Option<String>,
+    #[serde(default)]
+    genres: Vec<String>,
+    content_rating: Option<String>,
+    original_language: Option<String>,
+    #[serde(default)]
+    images: Vec<SkyhookImage>,
+    #[serde(default)]
+    actors: Vec<SkyhookActor>,
+    #[serde(default)]
+    seasons: Vec<SkyhookSeason>,
+    #[serde(default)]
+    episodes: Vec<SkyhookEpisode>,
+}
+
+impl From<SkyhookEpisode> for Episode {
+    fn from(episode: SkyhookEpisode) -> Self {
+        Self {
+            tvdb_id: episode.tvdb_id,
+            season_number: episode.season_number,
+            episode_number: episode.episode_number,
+            absolute_episode_number: episode.absolute_episode_number,
+            title: episode.title.unwrap_or_default(),
+            overview: episode.overview.unwrap_or_default(),
+            air_date: episode.air_date,
+            air_date_utc: episode.air_date_utc,
+            runtime: episode.runtime.unwrap_or_default(),
+            image_url: episode.image,
+        }
+    }
+}
+
+impl From<SkyhookShow> for SeriesInfo {
+    fn from(show: SkyhookShow) -> Self {
+        let mut seasons: Vec<Season> = show
+            .seasons
+            .into_iter()
+            .map(|season| Season {
+                season_number: season.season_number,
+                poster_url: poster_url(season.images),
+                episodes: Vec::new(),
+            })
+            .collect();
+
+        for episode in show.episodes {
+            let index = match seasons
+                .iter()
+                .position(|season| season.season_number == episode.season_number)
+            {
+                Some(index) => index,
+                None => {
+                    seasons.push(Season {
+                        season_number: episode.season_number,
+                        poster_url: None,
+                        episodes: Vec::new(),
+                    });
+                    seasons.len() - 1
+                }
+            };
+            seasons[index].episodes.push(episode.into());
+        }
+
+        seasons.sort_by_key(|season| season.season_number);
+        for season in &mut seasons {
+            season
+                .episodes
+                .sort_by_key(|episode| episode.episode_number);
+        }
+
+        Self {
+            tvdb_id: show.tvdb_id,
+            // Skyhook uses 0 for the missing ids
+            tmdb_id: show.tmdb_id.filter(|tmdb| *tmdb > 0),
+            imdb_id: show.imdb_id.filter(|imdb| !imdb.is_empty()),
+            tv_maze_id: show.tv_maze_id.filter(|tv_maze| *tv_maze > 0),
+            title: show.title,
+            alternate_titles: show
+                .alternate_titles
+                .into_iter()
+                .map(|title| title.title)
+                .collect(),
+            overview: show.overview.unwrap_or_default(),
+            year: show
+                .first_aired
+                .map(|date| date.year() as u16)
+                .unwrap_or_default(),
+            first_aired: show.first_aired,
+            last_aired: show.last_aired,
+            status: show.status,
+            runtime: show.runtime.unwrap_or_default(),
+            network: show.network,
+            genres: show.genres,
+            content_rating: show.content_rating,
+            original_language: show.original_language,
+            poster_url: poster_url(show.images),
+            actors: show
+                .actors
+                .into_iter()
+                .map(|actor| SeriesActor {
+                    name: actor.name,
+                    character: actor.character.unwrap_or_default(),
+                    headshot_url: actor.image,
+                })
+                .collect(),
+            seasons,
+        }
+    }
+}
+
+/// Series metadata from Sonarr's Skyhook, by TVDB id
+pub(crate) struct SkyhookProvider {
+    http: Client,
+}
+
+impl SkyhookProvider {
+    pub(crate) fn new(options: &MovieInfoClientOptions) -> Self {
+        let config = Config::new().set_base_url(SKYHOOK_URL.parse().unwrap());
+
+        Self {
+            http: http_client(config, options),
+        }
+    }
+
+    pub(crate) async fn series_by_tvdb(&self, tvdb: TvdbId) -> Result<Option<SeriesInfo>, Error> {
+        let mut resp = self
+            .http
+            .get(format!("shows/{LANGUAGE}/{tvdb}"))
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NotFound {
+            return Ok(None);
+        }
+
+        let show: SkyhookShow = resp.body_json().await?;
+
+        Ok(Some(show.into()))
+    }
+
+    async fn search_shows(&self, term: &str) -> Result<Vec<SkyhookShow>, Error> {
+        Ok(self
+            .http
+            .get(format!("search/{LANGUAGE}/"))
+            .query(&Query { term })?
+            .recv_json()
+            .await?)
+    }
+
+    pub(crate) async fn search(&self, query: &str) -> Result<Vec<SeriesInfo>, Error> {
+        let shows = self.search_shows(query).await?;
+
+        Ok(shows.into_iter().map(SeriesInfo::from).collect())
+    }
+
+    /// Skyhook searches by id with the `imdb:` and `tmdb:` terms, like Sonarr
+    pub(crate) async fn tvdb_by_imdb(&self, imdb: &str) -> Result<Option<TvdbId>, Error> {
+        let shows = self.search_shows(&format!("imdb:{imdb}")).await?;
+
+        Ok(shows
+            .into_iter()
+            .find(|show| show.imdb_id.as_deref() == Some(imdb))
+            .map(|show| show.tvdb_id))
+    }
+
+    pub(crate) async fn tvdb_by_tmdb(&self, tmdb: TmdbId) -> Result<Option<TvdbId>, Error> {
+        let shows = self.search_shows(&format!("tmdb:{tmdb}")).await?;
+
+        Ok(shows
+            .into_iter()
+            .find(|show| show.tmdb_id == Some(tmdb))
+            .map(|show| show.tvdb_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_series_info() {
+        let show: SkyhookShow = serde_json::from_value(json!({
+            "tvdbId": 81189,
+            "title": "Breaking Bad",
+            "overview": "Walter White, a struggling high school chemistry teacher...",
+            "firstAired": "2008-01-20",
+            "lastAired": "2013-09-29",
+            "tvMazeId": 169,
+            "tmdbId": 1396,
+            "imdbId": "tt0903747",
+            "status": "ended",
+            "runtime": 47,
+            "network": "AMC",
+            "genres": ["Crime", "Drama"],
+            "contentRating": "TV-MA",
+            "alternateTitles": [{ "title": "Metástasis" }],
+            "actors": [{ "name": "Bryan Cranston", "character": "Walter White", "image": null }],
+            "images": [{ "coverType": "Poster", "url": "https://artworks.thetvdb.com/poster.jpg" }],
+            "seasons": [
+                { "seasonNumber": 1, "images": [] },
+                { "seasonNumber": 2, "images": [] },
+            ],
+            "episodes": [
+                {
+                    "tvdbShowId": 81189,
+                    "tvdbId": 349232,
+                    "seasonNumber": 1,
+                    "episodeNumber": 2,
+                    "absoluteEpisodeNumber": 2,
+                    "title": "Cat's in the Bag...",
+                    "airDate": "2008-01-27",
+                    "airDateUtc": "2008-01-28T03:00:00Z",
+                    "runtime": 48,
+                },
+                {
+                    "tvdbShowId": 81189,
+                    "tvdbId": 349232,
+                    "seasonNumber": 1,
+                    "episodeNumber": 1,
+                    "title": "Pilot",
+                    "airDate": "2008-01-20",
+                },
+                {
+                    "tvdbShowId": 81189,
+                    "tvdbId": 1,
+                    "seasonNumber": 0,
+                    "episodeNumber": 1,
+                    "title": "Good Cop Bad Cop",
+                },
+            ],
+        }))
+        .unwrap();
+
+        let series = SeriesInfo::from(show);
+
+        assert_eq!(series.format(), "Breaking Bad (2008)");
+        assert_eq!(series.get_tmdb_id(), &Some(1396));
+        assert_eq!(series.get_imdb_id().as_deref(), Some("tt0903747"));
+        assert_eq!(series.get_alternate_titles(), &["Metástasis"]);
+        assert_eq!(
+            series.get_poster_url().as_deref(),
+            Some("https://artworks.thetvdb.com/poster.jpg")
+        );
+
+        let seasons: Vec<u32> = series
+            .get_seasons()
+            .iter()
+            .map(|season| season.season_number)
+            .collect();
+        assert_eq!(seasons, [0, 1, 2]);
+
+        let first_season = &series.get_seasons()[1];
+        assert_eq!(first_season.get_episodes().len(), 2);
+        assert_eq!(first_season.get_episodes()[0].get_title(), "Pilot");
+        assert_eq!(
+            first_season.get_episodes()[1].get_air_date(),
+            &NaiveDate::from_ymd_opt(2008, 1, 27)
+        );
+        assert!(series.get_seasons()[2].get_episodes().is_empty());
+
+        // The cache stores the series as they are serialized
+        let cached: SeriesInfo =
+            serde_json::from_value(serde_json::to_value(&series).unwrap()).unwrap();
+        assert_eq!(cached.get_seasons()[1].get_episodes()[1].get_runtime(), &48);
+    }
+}
@@ -0,0 +1,459 @@
+use crate::{
+    http_client,
+    models::{
+        certification::Certification,
+        collection::Collection,
+        credits::{CastItem, Credits, CrewItem},
+        ratings::{MovieRating, MovieRatings},
+        recommendation::Recommendation,
+        tmdb_id::TmdbId,
+    },
+    providers::MetadataProvider,
+    Error, MovieInfo, MovieInfoClientOptions,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{de::DeserializeOwned, Deserialize};
+use surf::{Client, Config, StatusCode};
+
+const TMDB_URL: &str = "https://api.themoviedb.org/3/";
+const IMAGE_URL: &str = "https://image.tmdb.org/t/p/original";
+/// Everything [`MovieInfo`] needs, in the same request as the movie
+const APPEND_TO_RESPONSE: &str = "credits,recommendations,release_dates,videos";
+
+/// The credentials of the TMDB API, either from the account's API settings
+#[derive(Debug, Clone)]
+pub enum TmdbAuth {
+    /// The v3 API key, sent as the `api_key` query parameter
+    ApiKey(String),
+    /// The API read access token, sent in the `Authorization` header
+    BearerToken(String),
+}
+
+#[derive(Deserialize)]
+struct TmdbResults<T> {
+    results: Vec<T>,
+}
+
+impl<T> Default for TmdbResults<T> {
+    fn default() -> Self {
+        Self {
+            results: Vec::new(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TmdbListItem {
+    id: TmdbId,
+    #[serde(default)]
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct TmdbFind {
+    movie_results: Vec<TmdbListItem>,
+}
+
+#[derive(Deserialize)]
+struct TmdbName {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct TmdbCollection {
+    id: TmdbId,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct TmdbCast {
+    id: TmdbId,
+    name: String,
+    order: i32,
+    character: String,
+    credit_id: String,
+    profile_path: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TmdbCrew {
+    id: TmdbId,
+    name: String,
+    job: String,
+    department: String,
+    credit_id: String,
+    profile_path: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct TmdbCredits {
+    cast: Vec<TmdbCast>,
+    crew: Vec<TmdbCrew>,
+}
+
+#[derive(Deserialize)]
+struct TmdbReleaseDate {
+    certification: String,
+    release_date: DateTime<Utc>,
+    #[serde(rename = "type")]
+    release_type: u8,
+}
+
+#[derive(Deserialize)]
+struct TmdbCountryReleases {
+    iso_3166_1: String,
+    release_dates: Vec<TmdbReleaseDate>,
+}
+
+#[derive(Deserialize)]
+struct TmdbVideo {
+    key: String,
+    site: String,
+    #[serde(rename = "type")]
+    video_type: String,
+}
+
+/// `movie/{id}` with [`APPEND_TO_RESPONSE`]
+#[derive(Deserialize)]
+struct TmdbMovie {
+    id: TmdbId,
+    imdb_id: Option<String>,
+    overview: Option<String>,
+    title: String,
+    original_title: String,
+    runtime: Option<u16>,
+    release_date: Option<String>,
+    vote_average: f64,
+    vote_count: i32,
+    genres: Vec<TmdbName>,
+    poster_path: Option<String>,
+    homepage: Option<String>,
+    original_language: String,
+    production_companies: Vec<TmdbName>,
+    belongs_to_collection: Option<TmdbCollection>,
+    #[serde(default)]
+    credits: TmdbCredits,
+    #[serde(default)]
+    recommendations: TmdbResults<TmdbListItem>,
+    #[serde(default)]
+    release_dates: TmdbResults<TmdbCountryReleases>,
+    #[serde(default)]
+    videos: TmdbResults<TmdbVideo>,
+}
+
+fn image_url(path: Option<String>) -> Option<String> {
+    path.map(|path| format!("{IMAGE_URL}{path}"))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+impl TmdbMovie {
+    /// The earliest release of one of the TMDB release types in any country
+    fn release(&self, release_types: &[u8]) -> Option<DateTime<Utc>> {
+        self.release_dates
+            .results
+            .iter()
+            .flat_map(|country| &country.release_dates)
+            .filter(|release| release_types.contains(&release.release_type))
+            .map(|release| release.release_date)
+            .min()
+    }
+
+    fn certifications(&self) -> Vec<Certification> {
+        self.release_dates
+            .results
+            .iter()
+            .filter_map(|country| {
+                country
+                    .release_dates
+                    .iter()
+                    .find(|release| !release.certification.is_empty())
+                    .map(|release| Certification {
+                        country: country.iso_3166_1.clone(),
+                        certification: release.certification.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+impl From<TmdbMovie> for MovieInfo {
+    fn from(movie: TmdbMovie) -> Self {
+        let release_date = movie
+            .release_date
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        // Premiere, limited and theatrical releases
+        let in_cinema = movie
+            .release(&[1, 2, 3])
+            .or_else(|| release_date.map(|date| date.and_time(Default::default()).and_utc()));
+        let youtube_trailer_id = movie
+            .videos
+            .results
+            .iter()
+            .find(|video| video.site == "YouTube" && video.video_type == "Trailer")
+            .map(|video| video.key.clone());
+
+        Self {
+            physical_release: movie.release(&[5]),
+            digital_release: movie.release(&[4]),
+            in_cinema,
+            certifications: movie.certifications(),
+            youtube_trailer_id,
+            imdb_id: non_empty(movie.imdb_id),
+            overview: movie.overview.unwrap_or_default(),
+            title: movie.title,
+            original_title: movie.original_title,
+            runtime: movie.runtime.unwrap_or_default(),
+            year: release_date
+                .map(|date| date.year() as u16)
+                .unwrap_or_default(),
+            movie_ratings: MovieRatings {
+                tmdb: Some(MovieRating {
+                    value: movie.vote_average,
+                    count: movie.vote_count,
+                }),
+                imdb: None,
+                metacritic: None,
+                rotten_tomatoes: None,
+            },
+            genres: movie.genres.into_iter().map(|genre| genre.name).collect(),
+            poster_url: image_url(movie.poster_path),
+            recommendations: movie
+                .recommendations
+                .results
+                .into_iter()
+                .map(|movie| Recommendation {
+                    tmdb_id: movie.id,
+                    title: movie.title,
+                })
+                .collect(),
+            credits: Credits {
+                cast: movie
+                    .credits
+                    .cast
+                    .into_iter()
+                    .map(|cast| CastItem {
+                        name: cast.name,
+                        order: cast.order,
+                        character: cast.character,
+                        tmdb_id: cast.id,
+                        credit_id: cast.credit_id,
+                        headshot_url: image_url(cast.profile_path),
+                    })
+                    .collect(),
+                crew: movie
+                    .credits
+                    .crew
+                    .into_iter()
+                    .map(|crew| CrewItem {
+                        name: crew.name,
+                        job: crew.job,
+                        department: crew.department,
+                        tmdb_id: crew.id,
+                        credit_id: crew.credit_id,
+                        headshot_url: image_url(crew.profile_path),
+                    })
+                    .collect(),
+            },
+            studio: movie
+                .production_companies
+                .into_iter()
+                .next()
+                .map(|company| company.name)
+                .unwrap_or_default(),
+            collection: movie.belongs_to_collection.map(|collection| Collection {
+                name: collection.name,
+                tmdb_id: collection.id,
+            }),
+            original_language: movie.original_language,
+            homepage: movie.homepage.unwrap_or_default(),
+            tmdb_id: movie.id,
+        }
+    }
+}
+
+/// The TMDB v3 API, lists and searches fetch the details of each movie they return
+pub(crate) struct TmdbProvider {
+    http: Client,
+    auth: TmdbAuth,
+}
+
+impl TmdbProvider {
+    pub(crate) fn new(auth: TmdbAuth, options: &MovieInfoClientOptions) -> Self {
+        let mut config = Config::new().set_base_url(TMDB_URL.parse().unwrap());
+        if let TmdbAuth::BearerToken(token) = &auth {
+            config = config
+                .add_header("Authorization", format!("Bearer {token}"))
+                .unwrap();
+        }
+
+        Self {
+            http: http_client(config, options),
+            auth,
+        }
+    }
+
+    /// `None` when TMDB answers 404
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Option<T>, Error> {
+        let mut query = params.to_vec();
+        if let TmdbAuth::ApiKey(api_key) = &self.auth {
+            query.push(("api_key", api_key));
+        }
+
+        let mut resp = self.http.get(path).query(&query)?.send().await?;
+
+        if resp.status() == StatusCode::NotFound {
+            return Ok(None);
+        }
+
+        Ok(Some(resp.body_json().await?))
+    }
+
+    /// The first page of a movie list, with the details of each movie
+    async fn list(&self, path: &str, params: &[(&str, &str)]) -> Result<Vec<MovieInfo>, Error> {
+        let Some(list) = self.get::<TmdbResults<TmdbListItem>>(path, params).await? else {
+            return Ok(Vec::new());
+        };
+
+        let ids: Vec<TmdbId> = list.results.iter().map(|movie| movie.id).collect();
+
+        self.bulk(&ids).await
+    }
+}
+
+#[async_trait]
+impl MetadataProvider for TmdbProvider {
+    async fn movie_by_tmdb(&self, tmdb: TmdbId) -> Result<Option<MovieInfo>, Error> {
+        let movie: Option<TmdbMovie> = self
+            .get(
+                &format!("movie/{tmdb}"),
+                &[("append_to_response", APPEND_TO_RESPONSE)],
+            )
+            .await?;
+
+        Ok(movie.map(MovieInfo::from))
+    }
+
+    async fn movie_by_imdb(&self, imdb: &str) -> Result<Option<MovieInfo>, Error> {
+        let found: Option<TmdbFind> = self
+            .get(&format!("find/{imdb}"), &[("external_source", "imdb_id")])
+            .await?;
+
+        match found.and_then(|found| found.movie_results.into_iter().next()) {
+            Some(movie) => self.movie_by_tmdb(movie.id).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<MovieInfo>, Error> {
+        self.list("search/movie", &[("query", query)]).await
+    }
+
+    async fn trending(&self) -> Result<Vec<MovieInfo>, Error> {
+        self.list("trending/movie/week", &[]).await
+    }
+
+    async fn popular(&self) -> Result<Vec<MovieInfo>, Error> {
+        self.list("movie/popular", &[]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_movie_info() {
+        let movie: TmdbMovie = serde_json::from_value(json!({
+            "id": 603,
+            "imdb_id": "tt0133093",
+            "overview": "Set in the 22nd century...",
+            "title": "The Matrix",
+            "original_title": "The Matrix",
+            "runtime": 136,
+            "release_date": "1999-03-31",
+            "vote_average": 8.2,
+            "vote_count": 26000,
+            "genres": [{ "id": 28, "name": "Action" }],
+            "poster_path": "/poster.jpg",
+            "homepage": null,
+            "original_language": "en",
+            "production_companies": [{ "id": 79, "name": "Village Roadshow Pictures" }],
+            "belongs_to_collection": { "id": 2344, "name": "The Matrix Collection" },
+            "credits": {
+                "cast": [{
+                    "id": 6384,
+                    "name": "Keanu Reeves",
+                    "order": 0,
+                    "character": "Neo",
+                    "credit_id": "52fe425bc3a36847f80181c1",
+                    "profile_path": "/keanu.jpg",
+                }],
+                "crew": [],
+            },
+            "recommendations": { "results": [{ "id": 604, "title": "The Matrix Reloaded" }] },
+            "release_dates": { "results": [
+                { "iso_3166_1": "US", "release_dates": [
+                    { "certification": "R", "release_date": "1999-03-31T00:00:00.000Z", "type": 3 },
+                    { "certification": "", "release_date": "1999-09-21T00:00:00.000Z", "type": 5 },
+                ] },
+                { "iso_3166_1": "AU", "release_dates": [
+                    { "certification": "", "release_date": "1999-04-08T00:00:00.000Z", "type": 3 },
+                ] },
+            ] },
+            "videos": { "results": [
+                { "key": "teaser", "site": "YouTube", "type": "Teaser" },
+                { "key": "vKQi3bBA1y8", "site": "YouTube", "type": "Trailer" },
+            ] },
+        }))
+        .unwrap();
+
+        let movie = MovieInfo::from(movie);
+
+        assert_eq!(movie.format(), "The Matrix (1999)");
+        assert_eq!(movie.get_imdb_id().as_deref(), Some("tt0133093"));
+        assert_eq!(movie.get_runtime(), &136);
+        assert_eq!(movie.get_genres(), &["Action"]);
+        assert_eq!(
+            movie.get_poster_url().as_deref(),
+            Some("https://image.tmdb.org/t/p/original/poster.jpg")
+        );
+        assert_eq!(movie.get_homepage(), "");
+        assert_eq!(movie.get_studio(), "Village Roadshow Pictures");
+        assert_eq!(
+            movie.get_youtube_trailer_id().as_deref(),
+            Some("vKQi3bBA1y8")
+        );
+        assert_eq!(
+            movie.get_in_cinema().unwrap().to_rfc3339(),
+            "1999-03-31T00:00:00+00:00"
+        );
+        assert_eq!(
+            movie.get_physical_release().unwrap().to_rfc3339(),
+            "1999-09-21T00:00:00+00:00"
+        );
+        assert!(movie.get_digital_release().is_none());
+        assert_eq!(movie.get_certifications().len(), 1);
+        assert_eq!(movie.get_certifications()[0].get_certification(), "R");
+        assert_eq!(movie.get_recommendations()[0].get_tmdb_id(), &604);
+        assert_eq!(movie.get_credits().get_cast()[0].character, "Neo");
+
+        // The cache stores movies in the format of the Radarr API
+        let cached: MovieInfo =
+            serde_json::from_value(serde_json::to_value(&movie).unwrap()).unwrap();
+        assert_eq!(cached.get_poster_url(), movie.get_poster_url());
+        assert_eq!(
+            cached.get_credits().get_cast()[0].headshot_url.as_deref(),
+            Some("https://image.tmdb.org/t/p/original/keanu.jpg")
+        );
+    }
+}
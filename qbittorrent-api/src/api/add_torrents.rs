@@ -1,17 +1,14 @@
 use surf::Body;
 
 use crate::{
-    error::ErrorKind, models::add_torrent_options::AddTorrentOptions, Error, QbittorrentClient,
+    error::ErrorKind,
+    models::{add_torrent_options::AddTorrentOptions, torrent_upload::TorrentUpload},
+    multipart::Multipart,
+    Error, QbittorrentClient,
 };
 
 impl QbittorrentClient {
-    pub async fn add_torrents(
-        &self,
-        urls: &[String],
-        mut options: AddTorrentOptions,
-    ) -> Result<(), Error> {
-        options.set_urls(urls.join("\n"));
-        let body = Body::from_form(&options).unwrap();
+    async fn post_add(&self, body: Body) -> Result<(), Error> {
         let resp = self
             .http
             .post("/api/v2/torrents/add")
@@ -29,8 +26,55 @@ impl QbittorrentClient {
         }
     }
 
+    pub async fn add_torrents(
+        &self,
+        urls: &[String],
+        mut options: AddTorrentOptions,
+    ) -> Result<(), Error> {
+        options.set_urls(urls.join("\n"));
+        let options = options.for_api_version(self.api_version().await?);
+        let body = Body::from_form(&options).unwrap();
+
+        self.post_add(body).await
+    }
+
     pub async fn add_torrent(&self, url: String, options: AddTorrentOptions) -> Result<(), Error> {
         let urls = vec![url];
         self.add_torrents(&urls, options).await
     }
+
+    /// Adds `.torrent` files, and optionally urls, in a single multipart request
+    pub async fn upload_torrents(
+        &self,
+        urls: &[String],
+        files: &[TorrentUpload],
+        mut options: AddTorrentOptions,
+    ) -> Result<(), Error> {
+        if urls.is_empty() && files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::BadParameters("torrents".to_string()),
+                "No urls or files to add",
+            ));
+        }
+
+        options.set_urls(urls.join("\n"));
+        let options = options.for_api_version(self.api_version().await?);
+
+        let mut multipart = Multipart::new();
+        multipart.form(&options)?;
+
+        for file in files {
+            multipart.file("torrents", file.filename(), file.content());
+        }
+
+        self.post_add(multipart.into_body()).await
+    }
+
+    pub async fn upload_torrent(
+        &self,
+        file: TorrentUpload,
+        options: AddTorrentOptions,
+    ) -> Result<(), Error> {
+        self.upload_torrents(&[], &[file], options).await
+    }
 }
@@ -0,0 +1,44 @@
+use crate::{
+    models::speed_limits::{AlternativeSpeedLimitsPreferences, SpeedLimits},
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    pub async fn alternative_speed_limits_enabled(&self) -> Result<bool, Error> {
+        let mode = self
+            .http
+            .get("/api/v2/transfer/speedLimitsMode")
+            .recv_string()
+            .await?;
+
+        Ok(mode.trim() == "1")
+    }
+
+    pub async fn toggle_alternative_speed_limits(&self) -> Result<(), Error> {
+        self.post_form("/api/v2/transfer/toggleSpeedLimitsMode", &())
+            .await
+    }
+
+    /// Only toggles when the current mode differs from `enabled`
+    pub async fn set_alternative_speed_limits_enabled(&self, enabled: bool) -> Result<(), Error> {
+        if self.alternative_speed_limits_enabled().await? != enabled {
+            self.toggle_alternative_speed_limits().await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn alternative_speed_limits(&self) -> Result<SpeedLimits, Error> {
+        let preferences: AlternativeSpeedLimitsPreferences =
+            self.http.get("/api/v2/app/preferences").recv_json().await?;
+
+        Ok(preferences.into())
+    }
+
+    /// The limits are stored in KiB per second, so they are rounded down to a multiple of 1024
+    pub async fn set_alternative_speed_limits(&self, limits: SpeedLimits) -> Result<(), Error> {
+        let preferences = AlternativeSpeedLimitsPreferences::from(limits);
+
+        self.set_preferences_json(&preferences).await
+    }
+}
@@ -4,6 +4,6 @@ impl QbittorrentClient {
     pub async fn categories(&self) -> Result<Vec<Category>, Error> {
         let sync = self.sync().await?;
 
-        Ok(sync.categories().clone())
+        Ok(sync.categories().values().cloned().collect())
     }
 }
@@ -0,0 +1,69 @@
+use crate::{
+    models::{
+        log_entry::{LogEntry, PeerLogEntry},
+        log_parameters::{LogParameters, PeerLogParameters},
+    },
+    Error, QbittorrentClient,
+};
+
+/// How many messages `recent_log` keeps
+pub const RECENT_LOG_SIZE: usize = 1000;
+
+impl QbittorrentClient {
+    pub async fn main_log(&self, params: &LogParameters) -> Result<Vec<LogEntry>, Error> {
+        let entries = self
+            .http
+            .get("/api/v2/log/main")
+            .query(params)?
+            .recv_json()
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// The peers which were blocked or banned, after `last_known_id` when set
+    pub async fn peer_log(&self, last_known_id: Option<i64>) -> Result<Vec<PeerLogEntry>, Error> {
+        let entries = self
+            .http
+            .get("/api/v2/log/peers")
+            .query(&PeerLogParameters::new(last_known_id))?
+            .recv_json()
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// The last [`RECENT_LOG_SIZE`] messages, only the messages since the previous call are requested.
+    ///
+    /// The last known message is requested again: when it is gone, qBittorrent restarted and its ids
+    /// started over, so every message is fetched again.
+    pub async fn recent_log(&self) -> Result<Vec<LogEntry>, Error> {
+        let mut recent_log = self.recent_log.lock().await;
+
+        let mut params = LogParameters::default();
+        let last_known_id = recent_log.back().map(|last| *last.get_id());
+        if let Some(id) = last_known_id {
+            params.set_last_known_id(id - 1);
+        }
+
+        let mut entries = self.main_log(&params).await?;
+
+        match last_known_id {
+            Some(id) if entries.first().is_some_and(|first| *first.get_id() == id) => {
+                entries.remove(0);
+            }
+            Some(_) => {
+                recent_log.clear();
+                entries = self.main_log(&LogParameters::default()).await?;
+            }
+            None => {}
+        }
+
+        recent_log.extend(entries);
+
+        let excess = recent_log.len().saturating_sub(RECENT_LOG_SIZE);
+        recent_log.drain(..excess);
+
+        Ok(recent_log.iter().cloned().collect())
+    }
+}
@@ -1,10 +1,36 @@
 pub mod add_category;
 pub mod add_torrents;
+pub mod alternative_speed_limits;
 pub mod categories;
 pub mod delete_torrents;
 pub mod edit_category;
 pub mod ensure_category;
+pub mod log;
+pub mod pause_torrents;
+pub mod preferences;
+pub mod reannounce_torrents;
+pub mod recheck_torrents;
+pub mod rename_file;
+pub mod rename_folder;
+mod request;
+pub mod resume_torrents;
+pub mod rss_items;
+pub mod rss_rules;
+pub mod search;
 pub mod set_category;
+pub mod set_file_priority;
+pub mod set_force_start;
+pub mod set_location;
+pub mod set_queue_position;
+pub mod set_torrent_limits;
+pub mod speed_limits;
 pub mod sync;
+pub mod tags;
+pub mod toggle_sequential_download;
+pub mod torrent_files;
+pub mod torrent_peers;
+pub mod torrent_tags;
+pub mod torrent_trackers;
 pub mod torrents;
+pub mod transfer_info;
 pub mod version;
@@ -0,0 +1,17 @@
+use crate::{
+    models::{api_version::ApiVersion, torrent_hashes::TorrentHashes},
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Uses `stop` on qBittorrent 5 and newer
+    pub async fn pause_torrents(&self, hashes: Vec<String>) -> Result<(), Error> {
+        let path = if self.api_version().await? >= ApiVersion::STOP_START {
+            "/api/v2/torrents/stop"
+        } else {
+            "/api/v2/torrents/pause"
+        };
+
+        self.post_form(path, &TorrentHashes::new(hashes)).await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    models::preferences::{Preferences, PreferencesUpdate},
+    Error, QbittorrentClient,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct SetPreferences {
+    json: String,
+}
+
+impl QbittorrentClient {
+    pub async fn preferences(&self) -> Result<Preferences, Error> {
+        let preferences = self.http.get("/api/v2/app/preferences").recv_json().await?;
+
+        Ok(preferences)
+    }
+
+    pub async fn set_preferences(&self, preferences: &PreferencesUpdate) -> Result<(), Error> {
+        self.set_preferences_json(preferences).await
+    }
+
+    /// Posts any serializable subset of the preferences
+    pub(crate) async fn set_preferences_json<T: Serialize>(
+        &self,
+        preferences: &T,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/app/setPreferences",
+            &SetPreferences {
+                json: serde_json::to_string(preferences)?,
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{models::torrent_hashes::TorrentHashes, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    pub async fn recheck_torrents(&self, hashes: Vec<String>) -> Result<(), Error> {
+        self.post_form("/api/v2/torrents/recheck", &TorrentHashes::new(hashes))
+            .await
+    }
+}
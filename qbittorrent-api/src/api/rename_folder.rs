@@ -0,0 +1,17 @@
+use crate::{models::rename_path_parameters::RenamePathParameters, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    /// Paths are relative to the save path of the torrent
+    pub async fn rename_folder(
+        &self,
+        hash: &str,
+        old_path: &str,
+        new_path: &str,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/renameFolder",
+            &RenamePathParameters::new(hash.into(), old_path.into(), new_path.into()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{de::DeserializeOwned, Serialize};
+use surf::{Body, StatusCode};
+
+use crate::{Error, ErrorKind, QbittorrentClient};
+
+#[derive(Serialize)]
+struct Hash<'a> {
+    hash: &'a str,
+}
+
+impl QbittorrentClient {
+    /// Posts `form` to `path`, for the calls that only answer with a status code
+    pub(crate) async fn post_form<T: Serialize>(&self, path: &str, form: &T) -> Result<(), Error> {
+        let body = Body::from_form(form)?;
+
+        let mut resp = self.http.post(path).body(body).send().await?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::NotFound => Err(Error::new(
+                ErrorKind::TorrentNotFound,
+                resp.body_string().await?,
+            )),
+            _ => Err(Error::new(
+                ErrorKind::RequestError,
+                resp.body_string().await?,
+            )),
+        }
+    }
+
+    /// Gets `path` for the torrent `hash`, for the calls that answer with JSON
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        hash: &str,
+    ) -> Result<T, Error> {
+        let mut resp = self.http.get(path).query(&Hash { hash })?.await?;
+
+        match resp.status() {
+            status if status.is_success() => Ok(resp.body_json().await?),
+            StatusCode::NotFound => Err(Error::new(
+                ErrorKind::TorrentNotFound,
+                format!("Torrent {hash} not found"),
+            )),
+            _ => Err(Error::new(
+                ErrorKind::RequestError,
+                resp.body_string().await?,
+            )),
+        }
+    }
+}
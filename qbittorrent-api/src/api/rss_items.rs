@@ -0,0 +1,87 @@
+use crate::{
+    models::{
+        rss_feed::RssItems,
+        rss_parameters::{
+            AddFeedParameters, ItemParameters, MoveItemParameters, RssPathParameters,
+        },
+    },
+    Error, QbittorrentClient,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WithData {
+    with_data: bool,
+}
+
+impl QbittorrentClient {
+    /// The feeds with their title, state and articles when `with_data` is set
+    pub async fn rss_items(&self, with_data: bool) -> Result<RssItems, Error> {
+        let tree: Map<String, Value> = self
+            .http
+            .get("/api/v2/rss/items")
+            .query(&WithData { with_data })?
+            .recv_json()
+            .await?;
+
+        Ok(RssItems::from_tree(tree)?)
+    }
+
+    /// `path` uses `\` between folders, the parent folders must exist
+    pub async fn add_rss_folder(&self, path: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/addFolder",
+            &RssPathParameters::new(path.into()),
+        )
+        .await
+    }
+
+    /// The feed is named after its title when `path` is unset
+    pub async fn add_rss_feed(&self, url: &str, path: Option<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/addFeed",
+            &AddFeedParameters::new(url.into(), path),
+        )
+        .await
+    }
+
+    /// Removes a feed, or a folder with its feeds
+    pub async fn remove_rss_item(&self, path: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/removeItem",
+            &RssPathParameters::new(path.into()),
+        )
+        .await
+    }
+
+    pub async fn move_rss_item(&self, item_path: &str, dest_path: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/moveItem",
+            &MoveItemParameters::new(item_path.into(), dest_path.into()),
+        )
+        .await
+    }
+
+    pub async fn refresh_rss_item(&self, item_path: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/refreshItem",
+            &ItemParameters::new(item_path.into(), None),
+        )
+        .await
+    }
+
+    /// Marks a single article as read, or every article of the item without `article_id`
+    pub async fn mark_rss_as_read(
+        &self,
+        item_path: &str,
+        article_id: Option<String>,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/markAsRead",
+            &ItemParameters::new(item_path.into(), article_id),
+        )
+        .await
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    models::{
+        rss_parameters::{RuleParameters, SetRuleParameters},
+        rss_rule::{RssMatchingArticles, RssRule},
+    },
+    Error, QbittorrentClient,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleName<'a> {
+    rule_name: &'a str,
+}
+
+impl QbittorrentClient {
+    pub async fn rss_rules(&self) -> Result<Vec<RssRule>, Error> {
+        let rules: HashMap<String, RssRule> =
+            self.http.get("/api/v2/rss/rules").recv_json().await?;
+
+        Ok(rules
+            .into_iter()
+            .map(|(name, mut rule)| {
+                rule.set_name(name);
+                rule
+            })
+            .collect())
+    }
+
+    pub async fn rss_rule(&self, name: &str) -> Result<Option<RssRule>, Error> {
+        let rules = self.rss_rules().await?;
+
+        Ok(rules.into_iter().find(|rule| rule.get_name() == name))
+    }
+
+    /// Creates the rule `name`, or replaces it
+    pub async fn set_rss_rule(&self, name: &str, rule: &RssRule) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/setRule",
+            &SetRuleParameters::new(name.into(), serde_json::to_string(rule)?),
+        )
+        .await
+    }
+
+    pub async fn rename_rss_rule(&self, name: &str, new_name: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/renameRule",
+            &RuleParameters::new(name.into(), Some(new_name.into())),
+        )
+        .await
+    }
+
+    pub async fn remove_rss_rule(&self, name: &str) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/rss/removeRule",
+            &RuleParameters::new(name.into(), None),
+        )
+        .await
+    }
+
+    /// The articles of the feeds the rule `name` matches, by feed name
+    pub async fn rss_matching_articles(
+        &self,
+        name: &str,
+    ) -> Result<Vec<RssMatchingArticles>, Error> {
+        let articles: HashMap<String, Vec<String>> = self
+            .http
+            .get("/api/v2/rss/matchingArticles")
+            .query(&RuleName { rule_name: name })?
+            .recv_json()
+            .await?;
+
+        Ok(articles
+            .into_iter()
+            .map(|(feed, articles)| RssMatchingArticles::new(feed, articles))
+            .collect())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    models::{
+        search::{SearchJob, SearchPlugin, SearchResults, SearchStatus},
+        search_parameters::{SearchIdParameters, SearchResultsParameters, StartSearchParameters},
+    },
+    Error, ErrorKind, QbittorrentClient,
+};
+use surf::Body;
+
+/// Searches with every enabled plugin
+pub const ENABLED_SEARCH_PLUGINS: &str = "enabled";
+
+impl QbittorrentClient {
+    /// Starts a search job, `plugins` are plugin names or `enabled`/`all`.
+    ///
+    /// qBittorrent runs at most 5 jobs at once, a job should be deleted after its results are read.
+    pub async fn start_search(
+        &self,
+        pattern: &str,
+        plugins: &[String],
+        category: &str,
+    ) -> Result<SearchJob, Error> {
+        let plugins = if plugins.is_empty() {
+            ENABLED_SEARCH_PLUGINS.to_string()
+        } else {
+            plugins.join("|")
+        };
+
+        let body = Body::from_form(&StartSearchParameters::new(
+            pattern.into(),
+            plugins,
+            category.into(),
+        ))?;
+
+        let mut resp = self
+            .http
+            .post("/api/v2/search/start")
+            .body(body)
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(Error::new(
+                ErrorKind::RequestError,
+                resp.body_string().await?,
+            ));
+        }
+
+        Ok(resp.body_json().await?)
+    }
+
+    pub async fn stop_search(&self, id: u64) -> Result<(), Error> {
+        self.post_form("/api/v2/search/stop", &SearchIdParameters::new(id))
+            .await
+    }
+
+    pub async fn search_status(&self, id: u64) -> Result<SearchStatus, Error> {
+        let statuses: Vec<SearchStatus> = self
+            .http
+            .get("/api/v2/search/status")
+            .query(&SearchIdParameters::new(id))?
+            .recv_json()
+            .await?;
+
+        statuses.into_iter().next().ok_or_else(|| {
+            Error::new(
+                ErrorKind::RequestError,
+                format!("No search job with id {id}"),
+            )
+        })
+    }
+
+    /// The results of the job, which can be read while it is still running
+    pub async fn search_results(
+        &self,
+        id: u64,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<SearchResults, Error> {
+        let results = self
+            .http
+            .get("/api/v2/search/results")
+            .query(&SearchResultsParameters::new(id, limit, offset))?
+            .recv_json()
+            .await?;
+
+        Ok(results)
+    }
+
+    pub async fn delete_search(&self, id: u64) -> Result<(), Error> {
+        self.post_form("/api/v2/search/delete", &SearchIdParameters::new(id))
+            .await
+    }
+
+    pub async fn search_plugins(&self) -> Result<Vec<SearchPlugin>, Error> {
+        let plugins = self.http.get("/api/v2/search/plugins").recv_json().await?;
+
+        Ok(plugins)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    models::{
+        file_priority::FilePriority, set_file_priority_parameters::SetFilePriorityParameters,
+    },
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// `ids` are the indexes of the files, as returned by `torrent_files`
+    pub async fn set_file_priority(
+        &self,
+        hash: &str,
+        ids: &[usize],
+        priority: FilePriority,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/filePrio",
+            &SetFilePriorityParameters::new(hash.to_string(), ids, priority),
+        )
+        .await
+    }
+}
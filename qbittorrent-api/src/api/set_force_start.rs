@@ -0,0 +1,14 @@
+use crate::{
+    models::set_force_start_parameters::SetForceStartParameters, Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Force started torrents ignore the queueing limits
+    pub async fn set_force_start(&self, hashes: Vec<String>, value: bool) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/setForceStart",
+            &SetForceStartParameters::new(hashes, value),
+        )
+        .await
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    error::ErrorKind, models::set_location_parameters::SetLocationParameters, Error,
+    QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Moves the torrents to `location`, which is created if it does not exist
+    pub async fn set_location(&self, hashes: Vec<String>, location: &str) -> Result<(), Error> {
+        if location.is_empty() {
+            return Err(Error::new(
+                ErrorKind::BadParameters("location".to_string()),
+                "Location is empty",
+            ));
+        }
+
+        self.post_form(
+            "/api/v2/torrents/setLocation",
+            &SetLocationParameters::new(hashes, location.to_string()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    models::{queue_position::QueuePosition, torrent_hashes::TorrentHashes},
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Fails when torrent queueing is disabled in qBittorrent
+    pub async fn set_queue_position(
+        &self,
+        hashes: Vec<String>,
+        position: QueuePosition,
+    ) -> Result<(), Error> {
+        self.post_form(position.endpoint(), &TorrentHashes::new(hashes))
+            .await
+    }
+}
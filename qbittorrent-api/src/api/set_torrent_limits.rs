@@ -0,0 +1,39 @@
+use crate::{
+    models::{
+        set_limit_parameters::SetLimitParameters,
+        share_limits::{SetShareLimitsParameters, ShareLimits},
+    },
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Limit in bytes per second, `0` removes the limit
+    pub async fn set_download_limit(&self, hashes: Vec<String>, limit: i64) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/setDownloadLimit",
+            &SetLimitParameters::new(hashes, limit),
+        )
+        .await
+    }
+
+    /// Limit in bytes per second, `0` removes the limit
+    pub async fn set_upload_limit(&self, hashes: Vec<String>, limit: i64) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/setUploadLimit",
+            &SetLimitParameters::new(hashes, limit),
+        )
+        .await
+    }
+
+    pub async fn set_share_limits(
+        &self,
+        hashes: Vec<String>,
+        limits: ShareLimits,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/setShareLimits",
+            &SetShareLimitsParameters::new(hashes, limits),
+        )
+        .await
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{models::speed_limits::SpeedLimits, Error, ErrorKind, QbittorrentClient};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Limit {
+    limit: i64,
+}
+
+impl QbittorrentClient {
+    async fn get_limit(&self, path: &str) -> Result<i64, Error> {
+        let limit = self.http.get(path).recv_string().await?;
+
+        limit.trim().parse().map_err(|_| {
+            Error::new(
+                ErrorKind::RequestError,
+                format!("Invalid speed limit: {limit}"),
+            )
+        })
+    }
+
+    /// The global limits, not taking the alternative limits into account
+    pub async fn global_speed_limits(&self) -> Result<SpeedLimits, Error> {
+        Ok(SpeedLimits::new(
+            self.get_limit("/api/v2/transfer/downloadLimit").await?,
+            self.get_limit("/api/v2/transfer/uploadLimit").await?,
+        ))
+    }
+
+    pub async fn set_global_speed_limits(&self, limits: SpeedLimits) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/transfer/setDownloadLimit",
+            &Limit {
+                limit: *limits.get_download(),
+            },
+        )
+        .await?;
+
+        self.post_form(
+            "/api/v2/transfer/setUploadLimit",
+            &Limit {
+                limit: *limits.get_upload(),
+            },
+        )
+        .await
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
-    models::{sync_main_data::SyncMainData, sync_result::SyncResult},
-    Error, QbittorrentClient,
+    models::sync_main_data::SyncMainData, Error, ErrorKind, QbittorrentClient, SyncResult,
 };
+use log::warn;
 
 #[derive(serde::Serialize)]
 struct Rid {
@@ -9,30 +9,49 @@ struct Rid {
 }
 
 impl QbittorrentClient {
-    pub async fn sync(&self) -> Result<SyncResult, Error> {
-        let mut sync_data = self.sync_data.lock().await;
-
-        let sync: SyncMainData = self
+    async fn sync_main_data(&self, rid: usize) -> Result<SyncMainData, Error> {
+        let text = self
             .http
             .get("/api/v2/sync/maindata")
-            .query(&Rid {
-                rid: sync_data.sync_rid,
-            })?
-            .recv_json()
+            .query(&Rid { rid })?
+            .recv_string()
             .await?;
 
-        sync_data.sync_rid = *sync.rid();
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches the changes since the previous sync and returns the updated snapshot.
+    ///
+    /// A delta which cannot be parsed or does not follow the previous rid causes a full resync from rid 0,
+    /// request errors are returned as is.
+    pub async fn sync(&self) -> Result<SyncResult, Error> {
+        let mut sync_data = self.sync_data.lock().await;
+        let previous_rid = sync_data.sync_rid;
+
+        let delta = match self.sync_main_data(previous_rid).await {
+            Ok(delta) if *delta.full_update() => delta,
+            Ok(delta) if sync_data.sync_result.is_some() && *delta.rid() > previous_rid => delta,
+            Ok(delta) => {
+                warn!(
+                    "Received a partial sync update for rid {} after rid {previous_rid}, resyncing",
+                    delta.rid()
+                );
+                self.sync_main_data(0).await?
+            }
+            Err(error) if previous_rid != 0 && matches!(error.kind(), ErrorKind::SerdeError(_)) => {
+                warn!("Could not parse sync update, resyncing: {error}");
+                self.sync_main_data(0).await?
+            }
+            Err(error) => return Err(error),
+        };
+
+        sync_data.sync_rid = *delta.rid();
 
-        if *sync.full_update() {
-            sync_data.sync_main_data = Some(sync);
-        } else {
-            sync_data
-                .sync_main_data
-                .as_mut()
-                .expect("Full update is false but sync_main_data is None.")
-                .update(sync);
-        }
+        let sync_result = sync_data
+            .sync_result
+            .get_or_insert_with(SyncResult::default);
+        sync_result.update(delta);
 
-        Ok(sync_data.sync_main_data.to_owned().unwrap().into())
+        Ok(sync_result.clone())
     }
 }
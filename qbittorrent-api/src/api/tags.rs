@@ -0,0 +1,21 @@
+use crate::{models::tags_parameters::TagsParameters, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    pub async fn tags(&self) -> Result<Vec<String>, Error> {
+        let tags = self.http.get("/api/v2/torrents/tags").recv_json().await?;
+
+        Ok(tags)
+    }
+
+    /// Tags which already exist are ignored
+    pub async fn create_tags(&self, tags: Vec<String>) -> Result<(), Error> {
+        self.post_form("/api/v2/torrents/createTags", &TagsParameters::new(tags))
+            .await
+    }
+
+    /// Removes the tags from qBittorrent and from every torrent using them
+    pub async fn delete_tags(&self, tags: Vec<String>) -> Result<(), Error> {
+        self.post_form("/api/v2/torrents/deleteTags", &TagsParameters::new(tags))
+            .await
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{models::torrent_hashes::TorrentHashes, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    pub async fn toggle_sequential_download(&self, hashes: Vec<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/toggleSequentialDownload",
+            &TorrentHashes::new(hashes),
+        )
+        .await
+    }
+}
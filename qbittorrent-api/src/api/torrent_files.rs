@@ -0,0 +1,7 @@
+use crate::{models::torrent_file::TorrentFile, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    pub async fn torrent_files(&self, hash: &str) -> Result<Vec<TorrentFile>, Error> {
+        self.get_json("/api/v2/torrents/files", hash).await
+    }
+}
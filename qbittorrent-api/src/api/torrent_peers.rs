@@ -0,0 +1,14 @@
+use crate::{
+    models::torrent_peer::{TorrentPeer, TorrentPeers},
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// The peers currently connected to the torrent
+    pub async fn torrent_peers(&self, hash: &str) -> Result<Vec<TorrentPeer>, Error> {
+        // Without a `rid`, qBittorrent sends all the peers instead of the changes
+        let peers: TorrentPeers = self.get_json("/api/v2/sync/torrentPeers", hash).await?;
+
+        Ok(peers.into_peers())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{models::tags_parameters::TorrentTagsParameters, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    /// Tags which do not exist yet are created
+    pub async fn add_tags(&self, hashes: Vec<String>, tags: Vec<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/addTags",
+            &TorrentTagsParameters::new(hashes, tags),
+        )
+        .await
+    }
+
+    /// An empty list of tags removes every tag from the torrents
+    pub async fn remove_tags(&self, hashes: Vec<String>, tags: Vec<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/removeTags",
+            &TorrentTagsParameters::new(hashes, tags),
+        )
+        .await
+    }
+}
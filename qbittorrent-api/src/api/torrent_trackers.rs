@@ -0,0 +1,45 @@
+use crate::{
+    models::{
+        torrent_tracker::TorrentTracker,
+        tracker_parameters::{
+            AddTrackersParameters, EditTrackerParameters, RemoveTrackersParameters,
+        },
+    },
+    Error, QbittorrentClient,
+};
+
+impl QbittorrentClient {
+    /// Includes the DHT, PeX and LSD entries
+    pub async fn torrent_trackers(&self, hash: &str) -> Result<Vec<TorrentTracker>, Error> {
+        self.get_json("/api/v2/torrents/trackers", hash).await
+    }
+
+    pub async fn add_trackers(&self, hash: &str, urls: Vec<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/addTrackers",
+            &AddTrackersParameters::new(hash.into(), urls),
+        )
+        .await
+    }
+
+    pub async fn edit_tracker(
+        &self,
+        hash: &str,
+        orig_url: &str,
+        new_url: &str,
+    ) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/editTracker",
+            &EditTrackerParameters::new(hash.into(), orig_url.into(), new_url.into()),
+        )
+        .await
+    }
+
+    pub async fn remove_trackers(&self, hash: &str, urls: Vec<String>) -> Result<(), Error> {
+        self.post_form(
+            "/api/v2/torrents/removeTrackers",
+            &RemoveTrackersParameters::new(hash.into(), urls),
+        )
+        .await
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{error::ErrorKind, Error, GetTorrentsParameters, QbittorrentClient, Torrent};
-use log::error;
+use log::{debug, error};
 
 impl QbittorrentClient {
     pub async fn torrents(&self, options: &GetTorrentsParameters) -> Result<Vec<Torrent>, Error> {
@@ -14,12 +14,14 @@ impl QbittorrentClient {
 
             let json = serde_json::from_str::<Vec<Torrent>>(&text);
             if let Err(error) = json {
-                error!("Serde error");
-                dbg!(text);
-                dbg!(&error);
+                error!("Could not parse torrents: {error}");
+                debug!("{text}");
                 Err(error)?
             } else {
-                Ok(json?)
+                Ok(json?
+                    .into_iter()
+                    .map(|torrent| torrent.with_client(self))
+                    .collect())
             }
         } else {
             let body = resp.body_string().await?;
@@ -37,6 +39,10 @@ impl QbittorrentClient {
     pub async fn torrents_sync(&self) -> Result<Vec<Torrent>, Error> {
         let sync = self.sync().await?;
 
-        Ok(sync.torrents().to_owned())
+        Ok(sync
+            .torrents()
+            .values()
+            .map(|torrent| torrent.clone().with_client(self))
+            .collect())
     }
 }
@@ -0,0 +1,16 @@
+use crate::{error::ErrorKind, models::transfer_info::TransferInfo, Error, QbittorrentClient};
+
+impl QbittorrentClient {
+    pub async fn transfer_info(&self) -> Result<TransferInfo, Error> {
+        let mut resp = self.http.get("/api/v2/transfer/info").await?;
+
+        if resp.status().is_success() {
+            Ok(resp.body_json().await?)
+        } else {
+            Err(Error::new(
+                ErrorKind::RequestError,
+                resp.body_string().await?,
+            ))
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Error, QbittorrentClient};
+use crate::{models::api_version::ApiVersion, Error, QbittorrentClient};
+use log::debug;
 
 impl QbittorrentClient {
     pub async fn version(&self) -> Result<String, Error> {
@@ -6,4 +7,26 @@ impl QbittorrentClient {
 
         Ok(version)
     }
+
+    pub async fn webapi_version(&self) -> Result<ApiVersion, Error> {
+        let version = self
+            .http
+            .get("/api/v2/app/webapiVersion")
+            .recv_string()
+            .await?;
+
+        version.parse()
+    }
+
+    /// The WebUI API version, requested once and cached afterwards
+    pub async fn api_version(&self) -> Result<ApiVersion, Error> {
+        self.api_version
+            .get_or_try_init(|| async {
+                let version = self.webapi_version().await?;
+                debug!("qBittorrent WebUI API version {version}");
+                Ok(version)
+            })
+            .await
+            .copied()
+    }
 }
@@ -1,36 +1,44 @@
+use log::{debug, warn};
 use serde::Serialize;
 use surf::{
     middleware::{Middleware, Next},
-    Body, Client, Error, Request, Response, Result, Url,
+    Body, Client, Error, Request, Response, Result, StatusCode, Url,
 };
 use tokio::sync::Mutex;
 
 #[derive(Serialize)]
-struct Credentials {
-    username: String,
-    password: String,
+struct Credentials<'a> {
+    username: &'a str,
+    password: &'a str,
 }
 
-impl Credentials {
-    fn new<S: Into<String>, P: Into<String>>(username: S, password: P) -> Self {
-        Self {
-            username: username.into(),
-            password: password.into(),
-        }
-    }
+/// How the client authenticates with qBittorrent
+#[derive(Debug, Clone)]
+pub enum QbittorrentAuth {
+    /// Logs in with a username and password, logging in again when the session expires
+    Credentials { username: String, password: String },
+    /// No authentication, for when the WebUI auth is bypassed (e.g. for localhost or a whitelisted subnet)
+    Bypass,
+    /// Sent as a bearer token with every request
+    ApiKey(String),
 }
 
 pub struct AuthMiddleware {
     url: Url,
     session_id: Mutex<Option<String>>,
-    username: String,
-    password: String,
+    auth: QbittorrentAuth,
 }
 
 impl AuthMiddleware {
     pub async fn login(&self, client: &Client) -> Result<String> {
-        let form = Credentials::new(&self.username, &self.password);
-        let body = Body::from_form(&form).unwrap();
+        let QbittorrentAuth::Credentials { username, password } = &self.auth else {
+            return Err(Error::from_str(
+                StatusCode::Unauthorized,
+                "Login requires a username and password",
+            ));
+        };
+
+        let body = Body::from_form(&Credentials { username, password })?;
 
         let resp = client
             .post("/api/v2/auth/login")
@@ -56,36 +64,91 @@ impl AuthMiddleware {
         Err(Error::from_str(401, "No cookie"))
     }
 
-    pub fn new(username: String, password: String, url: Url) -> Self {
+    pub fn new(auth: QbittorrentAuth, url: Url) -> Self {
         Self {
             url,
             session_id: Mutex::new(None),
-            username,
-            password,
+            auth,
         }
     }
 
     async fn session_id(&self) -> Option<String> {
         self.session_id.lock().await.to_owned()
     }
-}
 
-#[surf::utils::async_trait]
-impl Middleware for AuthMiddleware {
-    async fn handle(
+    /// Reads the body into memory so the request can be sent a second time
+    async fn buffer_body(req: &mut Request) -> Result<()> {
+        let body = req.take_body();
+        let mime = body.mime().clone();
+
+        let mut buffered = Body::from_bytes(body.into_bytes().await?);
+        buffered.set_mime(mime);
+        req.set_body(buffered);
+
+        Ok(())
+    }
+
+    async fn handle_session(
         &self,
         mut req: Request,
         client: Client,
         next: Next<'_>,
-    ) -> surf::Result<Response> {
+    ) -> Result<Response> {
         let session_id = match self.session_id().await {
             Some(session_id) => session_id,
             None => self.login(&client).await?,
         };
 
-        req.insert_header("Cookie", session_id);
+        Self::buffer_body(&mut req).await?;
+        let mut retry = req.clone();
+
+        req.insert_header("Cookie", session_id.as_str());
+        let res = next.run(req, client.clone()).await?;
+
+        if res.status() != StatusCode::Forbidden {
+            return Ok(res);
+        }
+
+        // The session expired or qBittorrent restarted, log in again and retry once
+        debug!("Session rejected by qBittorrent, logging in again");
+        {
+            let mut cached = self.session_id.lock().await;
+            if cached.as_deref() == Some(session_id.as_str()) {
+                *cached = None;
+            }
+        }
+
+        let session_id = match self.session_id().await {
+            Some(session_id) => session_id,
+            None => self.login(&client).await?,
+        };
+
+        retry.insert_header("Cookie", session_id);
+        let res = next.run(retry, client).await?;
+
+        if res.status() == StatusCode::Forbidden {
+            warn!("qBittorrent still rejects the request after logging in again");
+        }
 
-        let res = next.run(req, client).await?;
         Ok(res)
     }
 }
+
+#[surf::utils::async_trait]
+impl Middleware for AuthMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        client: Client,
+        next: Next<'_>,
+    ) -> surf::Result<Response> {
+        match &self.auth {
+            QbittorrentAuth::Credentials { .. } => self.handle_session(req, client, next).await,
+            QbittorrentAuth::Bypass => next.run(req, client).await,
+            QbittorrentAuth::ApiKey(key) => {
+                req.insert_header("Authorization", format!("Bearer {key}"));
+                next.run(req, client).await
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use log::debug;
 use std::fmt::Display;
 use surf::StatusCode;
 
@@ -62,7 +63,7 @@ impl Display for Error {
 
 impl From<surf::Error> for Error {
     fn from(request_error: surf::Error) -> Self {
-        println!("{request_error}");
+        debug!("{request_error}");
         if request_error.status() == StatusCode::Unauthorized {
             return Self::new(ErrorKind::IncorrectLogin, "Incorrect login");
         }
@@ -2,31 +2,71 @@ mod api;
 mod auth_middleware;
 mod error;
 mod models;
+mod multipart;
 pub mod serialize_hashes;
+pub use api::log::RECENT_LOG_SIZE;
+pub use api::search::ENABLED_SEARCH_PLUGINS;
 use auth_middleware::AuthMiddleware;
+pub use auth_middleware::QbittorrentAuth;
 pub use error::Error;
 pub use error::ErrorKind;
 pub use models::add_category_options::AddCategoryOptions;
 pub use models::add_torrent_options::AddTorrentOptions;
+pub use models::api_version::ApiVersion;
 pub use models::category::Category;
+pub use models::file_priority::FilePriority;
 pub use models::get_torrents_parameters::GetTorrentsParameters;
+pub use models::log_entry::{LogEntry, LogType, PeerLogEntry};
+pub use models::log_parameters::LogParameters;
+pub use models::preferences::{Preferences, PreferencesUpdate};
+pub use models::queue_position::QueuePosition;
+pub use models::rss_feed::{RssArticle, RssFeed, RssItems, RSS_PATH_SEPARATOR};
+pub use models::rss_rule::{RssMatchingArticles, RssRule};
+pub use models::search::{
+    SearchJob, SearchJobStatus, SearchPlugin, SearchPluginCategory, SearchResult, SearchResults,
+    SearchStatus,
+};
+pub use models::server_state::{ConnectionStatus, ServerState};
+pub use models::share_limits::ShareLimits;
+pub use models::speed_limits::SpeedLimits;
+pub use models::sync_event::SyncEvent;
 pub use models::sync_main_data::SyncMainData;
 pub use models::sync_result::SyncResult;
 pub use models::torrent::Torrent;
+pub use models::torrent_file::TorrentFile;
+pub use models::torrent_peer::TorrentPeer;
+pub use models::torrent_state::TorrentState;
+pub use models::torrent_tracker::TorrentTracker;
+pub use models::torrent_upload::TorrentUpload;
+pub use models::tracker_status::TrackerStatus;
+pub use models::transfer_info::TransferInfo;
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::sync::Arc;
 use surf::Client;
 use surf::{Config, Url};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
+use utils::proxy::{with_proxy, Proxy};
 use utils::surf_logging::SurfLogging;
 
 #[derive(Default)]
 struct SyncData {
     sync_rid: usize,
-    sync_main_data: Option<SyncMainData>,
+    sync_result: Option<SyncResult>,
 }
+/// Cheap to clone, clones share the same session and sync state
+#[derive(Clone)]
 pub struct QbittorrentClient {
     http: Client,
-    sync_data: Mutex<SyncData>,
+    sync_data: Arc<Mutex<SyncData>>,
+    api_version: Arc<OnceCell<ApiVersion>>,
+    recent_log: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+impl Debug for QbittorrentClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QbittorrentClient").finish_non_exhaustive()
+    }
 }
 
 impl QbittorrentClient {
@@ -35,19 +75,46 @@ impl QbittorrentClient {
         password: P,
         url: U,
     ) -> Self
+    where
+        U::Error: Debug,
+    {
+        Self::with_proxy(username, password, url, None)
+    }
+
+    /// Creates a client which reaches qBittorrent through `proxy`
+    pub fn with_proxy<S: Into<String>, P: Into<String>, U: TryInto<Url>>(
+        username: S,
+        password: P,
+        url: U,
+        proxy: Option<&Proxy>,
+    ) -> Self
+    where
+        U::Error: Debug,
+    {
+        let auth = QbittorrentAuth::Credentials {
+            username: username.into(),
+            password: password.into(),
+        };
+
+        Self::with_auth(auth, url, proxy)
+    }
+
+    pub fn with_auth<U: TryInto<Url>>(auth: QbittorrentAuth, url: U, proxy: Option<&Proxy>) -> Self
     where
         U::Error: Debug,
     {
         let url: Url = url.try_into().expect("Invalid url");
 
         let config = Config::new().set_base_url(url.to_owned());
-        let client: Client = config.try_into().unwrap();
+        let client: Client = with_proxy(config, proxy).try_into().unwrap();
 
         Self {
             http: client
-                .with(AuthMiddleware::new(username.into(), password.into(), url))
+                .with(AuthMiddleware::new(auth, url))
                 .with(SurfLogging),
-            sync_data: Mutex::new(SyncData::default()),
+            sync_data: Arc::new(Mutex::new(SyncData::default())),
+            api_version: Arc::new(OnceCell::new()),
+            recent_log: Arc::new(Mutex::new(VecDeque::new())),
         }
     }
 }
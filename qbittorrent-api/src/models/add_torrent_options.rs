@@ -1,3 +1,4 @@
+use crate::models::api_version::ApiVersion;
 use getset::Setters;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -12,7 +13,10 @@ pub struct AddTorrentOptions {
     category: Option<String>,
     tags: Option<String>,
     skip_checking: Option<bool>,
+    /// Sent as `stopped` to qBittorrent 5 and newer
     paused: Option<bool>,
+    #[cfg_attr(feature = "graphql", graphql(skip))]
+    stopped: Option<bool>,
     root_folder: Option<bool>,
     rename: Option<String>,
     #[serde(rename = "upLimit")]
@@ -26,3 +30,34 @@ pub struct AddTorrentOptions {
     #[serde(rename = "firstLastPiecePrio")]
     first_last_piece_prio: Option<bool>,
 }
+
+impl AddTorrentOptions {
+    /// WebUI API 2.11 renamed `paused` to `stopped`
+    pub(crate) fn for_api_version(mut self, version: ApiVersion) -> Self {
+        if version >= ApiVersion::STOP_START {
+            self.stopped = self.paused.take().or(self.stopped);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paused_for_api_version() {
+        let paused = || AddTorrentOptions {
+            paused: Some(true),
+            ..Default::default()
+        };
+
+        let old = serde_json::to_value(paused().for_api_version(ApiVersion::new(2, 9, 3))).unwrap();
+        assert_eq!(old["paused"], true);
+        assert!(old["stopped"].is_null());
+
+        let new = serde_json::to_value(paused().for_api_version(ApiVersion::STOP_START)).unwrap();
+        assert!(new["paused"].is_null());
+        assert_eq!(new["stopped"], true);
+    }
+}
@@ -0,0 +1,75 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Error, ErrorKind};
+
+/// Version of the WebUI API, as returned by `app/webapiVersion`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ApiVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl ApiVersion {
+    /// qBittorrent 5.0 renamed `pause`/`resume` to `stop`/`start`
+    pub const STOP_START: ApiVersion = ApiVersion::new(2, 11, 0);
+
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().trim_start_matches('v').split('.').map(str::parse);
+
+        let mut next = || -> Result<u32, Error> {
+            match parts.next() {
+                Some(Ok(part)) => Ok(part),
+                Some(Err(_)) => Err(Error::new(
+                    ErrorKind::RequestError,
+                    format!("Invalid API version: {s}"),
+                )),
+                None => Ok(0),
+            }
+        };
+
+        Ok(Self::new(next()?, next()?, next()?))
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "2.11.2".parse::<ApiVersion>().unwrap(),
+            ApiVersion::new(2, 11, 2)
+        );
+        assert_eq!(
+            "2.8".parse::<ApiVersion>().unwrap(),
+            ApiVersion::new(2, 8, 0)
+        );
+        assert!("Forbidden".parse::<ApiVersion>().is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(ApiVersion::new(2, 9, 3) < ApiVersion::STOP_START);
+        assert!(ApiVersion::new(2, 11, 2) >= ApiVersion::STOP_START);
+    }
+}
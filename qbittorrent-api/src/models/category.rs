@@ -2,10 +2,39 @@ use getset::Getters;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[derive(Serialize, Deserialize, Debug, Getters, Clone, Default)]
 #[get = "pub"]
 pub struct Category {
     name: String,
     #[serde(rename = "savePath")]
     save_path: String,
 }
+
+/// A category in a sync delta, only the changed fields are present
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct PartialCategory {
+    name: Option<String>,
+    #[serde(rename = "savePath")]
+    save_path: Option<String>,
+}
+
+impl Category {
+    pub(crate) fn from_partial(name: &str, partial: PartialCategory) -> Self {
+        let mut category = Self {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        category.apply(partial);
+        category
+    }
+
+    pub(crate) fn apply(&mut self, partial: PartialCategory) {
+        if let Some(name) = partial.name {
+            self.name = name;
+        }
+        if let Some(save_path) = partial.save_path {
+            self.save_path = save_path;
+        }
+    }
+}
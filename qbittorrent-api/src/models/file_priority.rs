@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(from = "u8", into = "u8")]
+pub enum FilePriority {
+    DoNotDownload,
+    Normal,
+    High,
+    Maximal,
+}
+
+impl From<u8> for FilePriority {
+    fn from(priority: u8) -> Self {
+        match priority {
+            0 => FilePriority::DoNotDownload,
+            6 => FilePriority::High,
+            7 => FilePriority::Maximal,
+            // Older versions also used 2 to 5 for a normal priority
+            _ => FilePriority::Normal,
+        }
+    }
+}
+
+impl From<FilePriority> for u8 {
+    fn from(priority: FilePriority) -> Self {
+        match priority {
+            FilePriority::DoNotDownload => 0,
+            FilePriority::Normal => 1,
+            FilePriority::High => 6,
+            FilePriority::Maximal => 7,
+        }
+    }
+}
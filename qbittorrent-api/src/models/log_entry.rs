@@ -0,0 +1,94 @@
+use chrono::{serde::ts_milliseconds, DateTime, Utc};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// The log levels are bit flags in qBittorrent
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum LogType {
+    Normal,
+    Info,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl From<u8> for LogType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => LogType::Normal,
+            2 => LogType::Info,
+            4 => LogType::Warning,
+            8 => LogType::Critical,
+            _ => LogType::Unknown,
+        }
+    }
+}
+
+impl From<LogType> for u8 {
+    fn from(value: LogType) -> Self {
+        match value {
+            LogType::Normal => 1,
+            LogType::Info => 2,
+            LogType::Warning => 4,
+            LogType::Critical => 8,
+            LogType::Unknown => 0,
+        }
+    }
+}
+
+impl LogType {
+    /// Warnings and critical messages, which is where qBittorrent reports failing torrents
+    pub fn is_problem(&self) -> bool {
+        matches!(self, LogType::Warning | LogType::Critical)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct LogEntry {
+    /// Increases with every message, until qBittorrent restarts
+    id: i64,
+    message: String,
+    #[serde(with = "ts_milliseconds")]
+    timestamp: DateTime<Utc>,
+    #[serde(rename = "type")]
+    log_type: LogType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct PeerLogEntry {
+    id: i64,
+    ip: String,
+    #[serde(with = "ts_milliseconds")]
+    timestamp: DateTime<Utc>,
+    /// Whether the peer was blocked, or banned
+    blocked: bool,
+    reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_log_entry() {
+        let entry: LogEntry = serde_json::from_str(
+            r#"{
+                "id": 7,
+                "message": "File error alert. Torrent: \"Movie\". Reason: \"No space left on device\"",
+                "timestamp": 1700000000123,
+                "type": 8
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(entry.log_type, LogType::Critical);
+        assert!(entry.log_type.is_problem());
+        assert_eq!(entry.timestamp.timestamp_millis(), 1700000000123);
+    }
+}
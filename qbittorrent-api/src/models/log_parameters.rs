@@ -0,0 +1,39 @@
+use getset::Setters;
+use serde::Serialize;
+
+/// Which log levels `log/main` returns, every level by default
+#[derive(Debug, Serialize, Setters, Clone)]
+#[set = "pub"]
+pub struct LogParameters {
+    normal: bool,
+    info: bool,
+    warning: bool,
+    critical: bool,
+    /// Only the messages after this id, `-1` for every message
+    last_known_id: i64,
+}
+
+impl Default for LogParameters {
+    fn default() -> Self {
+        Self {
+            normal: true,
+            info: true,
+            warning: true,
+            critical: true,
+            last_known_id: -1,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PeerLogParameters {
+    last_known_id: i64,
+}
+
+impl PeerLogParameters {
+    pub fn new(last_known_id: Option<i64>) -> Self {
+        Self {
+            last_known_id: last_known_id.unwrap_or(-1),
+        }
+    }
+}
@@ -1,10 +1,50 @@
+/// Overwrites every field of `$target` for which `$partial` has a value
+macro_rules! apply_partial {
+    ($target:expr, $partial:expr, $($field:ident),*) => {
+        $(
+            if let Some(value) = $partial.$field {
+                $target.$field = value;
+            }
+        )*
+    };
+}
+
 pub mod add_category_options;
 pub mod add_torrent_options;
+pub mod api_version;
 pub mod category;
 pub mod delete_torrents_parameters;
+pub mod file_priority;
 pub mod get_torrents_parameters;
+pub mod log_entry;
+pub mod log_parameters;
+pub mod preferences;
+pub mod queue_position;
+pub mod rename_path_parameters;
+pub mod rss_feed;
+pub mod rss_parameters;
+pub mod rss_rule;
+pub mod search;
+pub mod search_parameters;
+pub mod server_state;
 pub mod set_category_options;
+pub mod set_file_priority_parameters;
+pub mod set_force_start_parameters;
+pub mod set_limit_parameters;
+pub mod set_location_parameters;
+pub mod share_limits;
+pub mod speed_limits;
+pub mod sync_event;
 pub mod sync_main_data;
 pub mod sync_result;
+pub mod tags_parameters;
 pub mod torrent;
+pub mod torrent_file;
+pub mod torrent_hashes;
+pub mod torrent_peer;
 pub mod torrent_state;
+pub mod torrent_tracker;
+pub mod torrent_upload;
+pub mod tracker_parameters;
+pub mod tracker_status;
+pub mod transfer_info;
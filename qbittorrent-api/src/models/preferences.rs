@@ -0,0 +1,127 @@
+use getset::{Getters, Setters};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The qBittorrent application preferences, preferences without a field are kept in `other`.
+///
+/// Preferences missing from older or newer versions are left to their default.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Getters)]
+#[serde(default)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[cfg_attr(feature = "graphql", graphql(complex))]
+#[getset(get = "pub with_prefix")]
+pub struct Preferences {
+    save_path: String,
+    temp_path_enabled: bool,
+    temp_path: String,
+    queueing_enabled: bool,
+    max_active_downloads: i64,
+    max_active_torrents: i64,
+    max_active_uploads: i64,
+    max_ratio_enabled: bool,
+    max_ratio: f64,
+    max_seeding_time_enabled: bool,
+    /// In minutes
+    max_seeding_time: i64,
+    /// What to do when a limit is reached: `0` pause, `1` remove, `2` remove with files, `3` super seed
+    max_ratio_act: i64,
+    listen_port: i64,
+    autorun_enabled: bool,
+    autorun_program: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "graphql", graphql(skip))]
+    other: Map<String, Value>,
+}
+
+impl Preferences {
+    pub fn get_value(&self, key: &str) -> Option<&Value> {
+        self.other.get(key)
+    }
+}
+
+#[cfg(feature = "graphql")]
+#[async_graphql::ComplexObject]
+impl Preferences {
+    /// Every other preference, as returned by qBittorrent
+    async fn other(&self) -> async_graphql::Json<Map<String, Value>> {
+        async_graphql::Json(self.other.clone())
+    }
+}
+
+/// The preferences to change, unset fields are left as they are
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Setters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::InputObject))]
+#[set = "pub"]
+pub struct PreferencesUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    save_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temp_path_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temp_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    queueing_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_active_downloads: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_active_torrents: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_active_uploads: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_ratio_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_seeding_time_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_seeding_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_ratio_act: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    listen_port: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autorun_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autorun_program: Option<String>,
+    /// Any other preference, by its qBittorrent name
+    #[serde(flatten)]
+    #[cfg_attr(feature = "graphql", graphql(skip))]
+    other: Map<String, Value>,
+}
+
+impl PreferencesUpdate {
+    /// Sets a preference without a typed field
+    pub fn set_value<S: Into<String>>(&mut self, key: S, value: Value) -> &mut Self {
+        self.other.insert(key.into(), value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_update_skips_unset() {
+        let mut update = PreferencesUpdate::default();
+        update
+            .set_max_active_downloads(Some(3))
+            .set_value("dht", json!(false));
+
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "max_active_downloads": 3, "dht": false })
+        );
+    }
+
+    #[test]
+    fn test_missing_preferences() {
+        let preferences: Preferences =
+            serde_json::from_value(json!({ "save_path": "/downloads", "dht": true })).unwrap();
+
+        assert_eq!(preferences.get_save_path(), "/downloads");
+        assert!(!preferences.get_autorun_enabled());
+        assert_eq!(preferences.get_value("dht"), Some(&json!(true)));
+    }
+}
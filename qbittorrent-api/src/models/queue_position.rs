@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum QueuePosition {
+    Increase,
+    Decrease,
+    Top,
+    Bottom,
+}
+
+impl QueuePosition {
+    pub(crate) fn endpoint(&self) -> &'static str {
+        match self {
+            QueuePosition::Increase => "/api/v2/torrents/increasePrio",
+            QueuePosition::Decrease => "/api/v2/torrents/decreasePrio",
+            QueuePosition::Top => "/api/v2/torrents/topPrio",
+            QueuePosition::Bottom => "/api/v2/torrents/bottomPrio",
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamePathParameters {
+    hash: String,
+    old_path: String,
+    new_path: String,
+}
+
+impl RenamePathParameters {
+    pub fn new(hash: String, old_path: String, new_path: String) -> Self {
+        Self {
+            hash,
+            old_path,
+            new_path,
+        }
+    }
+}
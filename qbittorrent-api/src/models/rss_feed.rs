@@ -0,0 +1,140 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// qBittorrent separates the folders of RSS item paths with a backslash
+pub const RSS_PATH_SEPARATOR: &str = "\\";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct RssArticle {
+    id: String,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    /// As sent by the feed, usually in the RFC 2822 format
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default, rename = "torrentURL")]
+    torrent_url: Option<String>,
+    #[serde(default)]
+    link: Option<String>,
+    #[serde(default)]
+    is_read: bool,
+}
+
+/// The title, state and articles are only sent when requested with `with_data`
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct RssFeed {
+    /// The path of the feed in the folder tree, e.g. `Movies\YTS`
+    #[serde(skip)]
+    path: String,
+    uid: String,
+    url: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    last_build_date: Option<String>,
+    #[serde(default)]
+    is_loading: bool,
+    #[serde(default)]
+    has_error: bool,
+    #[serde(default)]
+    articles: Vec<RssArticle>,
+}
+
+/// The RSS folders and feeds, flattened from the tree `rss/items` returns
+#[derive(Serialize, Debug, Clone, Default, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct RssItems {
+    folders: Vec<String>,
+    feeds: Vec<RssFeed>,
+}
+
+impl RssItems {
+    /// Feeds are the objects with an `uid`, any other object is a folder
+    fn collect(&mut self, prefix: &str, tree: Map<String, Value>) -> Result<(), serde_json::Error> {
+        for (name, item) in tree {
+            let path = format!("{prefix}{name}");
+
+            match item {
+                Value::Object(object) if object.contains_key("uid") => {
+                    let mut feed: RssFeed = serde_json::from_value(Value::Object(object))?;
+                    feed.path = path;
+                    self.feeds.push(feed);
+                }
+                Value::Object(folder) => {
+                    self.collect(&format!("{path}{RSS_PATH_SEPARATOR}"), folder)?;
+                    self.folders.push(path);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn from_tree(tree: Map<String, Value>) -> Result<Self, serde_json::Error> {
+        let mut items = Self::default();
+        items.collect("", tree)?;
+
+        Ok(items)
+    }
+
+    pub fn feed(&self, path: &str) -> Option<&RssFeed> {
+        self.feeds.iter().find(|feed| feed.path == path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_tree() {
+        let tree = json!({
+            "Movies": {
+                "YTS": { "uid": "{1}", "url": "https://yts.example/rss" },
+                "4K": {},
+            },
+            "News": {
+                "uid": "{2}",
+                "url": "https://news.example/rss",
+                "title": "News",
+                "isLoading": false,
+                "hasError": true,
+                "articles": [{
+                    "id": "a1",
+                    "title": "The Matrix 1999 1080p",
+                    "torrentURL": "https://news.example/1.torrent",
+                    "date": "Mon, 01 Jan 2024 00:00:00 GMT",
+                    "isRead": true,
+                }],
+            },
+        });
+
+        let items = RssItems::from_tree(serde_json::from_value(tree).unwrap()).unwrap();
+
+        assert_eq!(items.get_folders(), &["Movies\\4K", "Movies"]);
+
+        let yts = items.feed("Movies\\YTS").unwrap();
+        assert_eq!(yts.get_url(), "https://yts.example/rss");
+        assert!(yts.get_articles().is_empty());
+
+        let news = items.feed("News").unwrap();
+        assert!(*news.get_has_error());
+        let article = &news.get_articles()[0];
+        assert_eq!(
+            article.get_torrent_url().as_deref(),
+            Some("https://news.example/1.torrent")
+        );
+        assert!(*article.get_is_read());
+    }
+}
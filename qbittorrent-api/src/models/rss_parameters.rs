@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct RssPathParameters {
+    path: String,
+}
+
+impl RssPathParameters {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddFeedParameters {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+impl AddFeedParameters {
+    pub fn new(url: String, path: Option<String>) -> Self {
+        Self { url, path }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveItemParameters {
+    item_path: String,
+    dest_path: String,
+}
+
+impl MoveItemParameters {
+    pub fn new(item_path: String, dest_path: String) -> Self {
+        Self {
+            item_path,
+            dest_path,
+        }
+    }
+}
+
+/// Marks a single article, or every article of the item when `article_id` is unset
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemParameters {
+    item_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    article_id: Option<String>,
+}
+
+impl ItemParameters {
+    pub fn new(item_path: String, article_id: Option<String>) -> Self {
+        Self {
+            item_path,
+            article_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetRuleParameters {
+    rule_name: String,
+    /// The rule as JSON
+    rule_def: String,
+}
+
+impl SetRuleParameters {
+    pub fn new(rule_name: String, rule_def: String) -> Self {
+        Self {
+            rule_name,
+            rule_def,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleParameters {
+    rule_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_rule_name: Option<String>,
+}
+
+impl RuleParameters {
+    pub fn new(rule_name: String, new_rule_name: Option<String>) -> Self {
+        Self {
+            rule_name,
+            new_rule_name,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use getset::{Getters, Setters};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// An RSS auto-downloading rule, fields without a typed field are kept in `other`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Getters, Setters)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject)
+)]
+#[cfg_attr(feature = "graphql", graphql(complex, input_name = "RssRuleInput"))]
+#[getset(get = "pub with_prefix", set = "pub")]
+pub struct RssRule {
+    /// The key of the rule in `rss/rules`
+    #[serde(skip)]
+    #[cfg_attr(feature = "graphql", graphql(skip_input))]
+    name: String,
+    enabled: bool,
+    /// Words separated by spaces must all appear, `|` separates alternatives
+    must_contain: String,
+    must_not_contain: String,
+    use_regex: bool,
+    episode_filter: String,
+    /// Only downloads an episode once, for series
+    smart_filter: bool,
+    previously_matched_episodes: Vec<String>,
+    /// The urls of the feeds the rule applies to
+    affected_feeds: Vec<String>,
+    /// Ignores matches for this many days after the last match, `0` to always download
+    ignore_days: i64,
+    #[cfg_attr(feature = "graphql", graphql(skip_input))]
+    last_match: String,
+    /// `None` uses the global setting
+    add_paused: Option<bool>,
+    assigned_category: String,
+    save_path: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "graphql", graphql(skip))]
+    other: Map<String, Value>,
+}
+
+impl RssRule {
+    pub fn get_value(&self, key: &str) -> Option<&Value> {
+        self.other.get(key)
+    }
+
+    /// Sets a field by its qBittorrent name, e.g. `torrentParams` of qBittorrent 5
+    pub fn set_value<S: Into<String>>(&mut self, key: S, value: Value) -> &mut Self {
+        self.other.insert(key.into(), value);
+        self
+    }
+
+    /// Keeps the fields of `other` this rule does not set, e.g. when editing a rule from a partial input
+    pub fn merge_other(&mut self, other: Map<String, Value>) -> &mut Self {
+        for (key, value) in other {
+            self.other.entry(key).or_insert(value);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "graphql")]
+#[async_graphql::ComplexObject]
+impl RssRule {
+    /// Every other field of the rule, as returned by qBittorrent
+    async fn other(&self) -> async_graphql::Json<Map<String, Value>> {
+        async_graphql::Json(self.other.clone())
+    }
+}
+
+/// The titles of the articles a rule matches in a feed
+#[derive(Serialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct RssMatchingArticles {
+    feed: String,
+    articles: Vec<String>,
+}
+
+impl RssMatchingArticles {
+    pub(crate) fn new(feed: String, articles: Vec<String>) -> Self {
+        Self { feed, articles }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_keeps_unknown_fields() {
+        let rule: RssRule = serde_json::from_value(json!({
+            "enabled": true,
+            "mustContain": "Matrix 1999",
+            "affectedFeeds": ["https://yts.example/rss"],
+            "addPaused": null,
+            "torrentParams": { "tags": ["tmdb:603"] },
+        }))
+        .unwrap();
+
+        assert!(*rule.get_enabled());
+        assert_eq!(rule.get_must_contain(), "Matrix 1999");
+        assert_eq!(rule.get_add_paused(), &None);
+        assert_eq!(
+            rule.get_value("torrentParams"),
+            Some(&json!({ "tags": ["tmdb:603"] }))
+        );
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert_eq!(json["torrentParams"]["tags"][0], "tmdb:603");
+        assert_eq!(json["affectedFeeds"][0], "https://yts.example/rss");
+        assert!(json.get("name").is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum SearchJobStatus {
+    Running,
+    Stopped,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchJob {
+    id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchStatus {
+    id: u64,
+    status: SearchJobStatus,
+    /// Results found so far
+    total: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchResult {
+    /// The page of the torrent on the site
+    descr_link: String,
+    file_name: String,
+    /// In bytes, -1 when the plugin does not know it
+    file_size: i64,
+    /// A magnet link or the url of the torrent file
+    file_url: String,
+    /// -1 when the plugin does not know it
+    nb_leechers: i64,
+    /// -1 when the plugin does not know it
+    nb_seeders: i64,
+    site_url: String,
+    /// Unix timestamp, sent since qBittorrent 4.5 and -1 when unknown
+    #[serde(default)]
+    pub_date: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchResults {
+    results: Vec<SearchResult>,
+    status: SearchJobStatus,
+    total: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchPluginCategory {
+    id: String,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SearchPlugin {
+    enabled: bool,
+    full_name: String,
+    name: String,
+    #[serde(default)]
+    supported_categories: Vec<SearchPluginCategory>,
+    url: String,
+    version: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_results() {
+        let results: SearchResults = serde_json::from_str(
+            r#"{
+                "results": [{
+                    "descrLink": "https://example.org/torrent/1",
+                    "fileName": "The Matrix 1999 1080p BluRay x264",
+                    "fileSize": 2147483648,
+                    "fileUrl": "magnet:?xt=urn:btih:abc",
+                    "nbLeechers": 3,
+                    "nbSeeders": -1,
+                    "siteUrl": "https://example.org"
+                }],
+                "status": "Stopped",
+                "total": 1
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(results.status, SearchJobStatus::Stopped);
+        assert_eq!(results.results[0].nb_seeders, -1);
+        assert_eq!(results.results[0].pub_date, None);
+    }
+}
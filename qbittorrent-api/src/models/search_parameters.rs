@@ -0,0 +1,46 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct StartSearchParameters {
+    pattern: String,
+    /// Plugin names separated by `|`, or `all` and `enabled`
+    plugins: String,
+    /// A category of the plugins or `all`
+    category: String,
+}
+
+impl StartSearchParameters {
+    pub fn new(pattern: String, plugins: String, category: String) -> Self {
+        Self {
+            pattern,
+            plugins,
+            category,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchIdParameters {
+    id: u64,
+}
+
+impl SearchIdParameters {
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchResultsParameters {
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<usize>,
+}
+
+impl SearchResultsParameters {
+    pub fn new(id: u64, limit: Option<usize>, offset: Option<usize>) -> Self {
+        Self { id, limit, offset }
+    }
+}
@@ -0,0 +1,76 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ConnectionStatus {
+    Connected,
+    Firewalled,
+    #[default]
+    Disconnected,
+}
+
+/// Global transfer info and status of the qBittorrent instance, speeds are in bytes per second
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct ServerState {
+    alltime_dl: u64,
+    alltime_ul: u64,
+    connection_status: ConnectionStatus,
+    dht_nodes: u64,
+    dl_info_data: u64,
+    dl_info_speed: u64,
+    dl_rate_limit: u64,
+    free_space_on_disk: u64,
+    queueing: bool,
+    total_peer_connections: u64,
+    up_info_data: u64,
+    up_info_speed: u64,
+    up_rate_limit: u64,
+    use_alt_speed_limits: bool,
+}
+
+/// The server state in a sync delta, only the changed fields are present
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct PartialServerState {
+    alltime_dl: Option<u64>,
+    alltime_ul: Option<u64>,
+    connection_status: Option<ConnectionStatus>,
+    dht_nodes: Option<u64>,
+    dl_info_data: Option<u64>,
+    dl_info_speed: Option<u64>,
+    dl_rate_limit: Option<u64>,
+    free_space_on_disk: Option<u64>,
+    queueing: Option<bool>,
+    total_peer_connections: Option<u64>,
+    up_info_data: Option<u64>,
+    up_info_speed: Option<u64>,
+    up_rate_limit: Option<u64>,
+    use_alt_speed_limits: Option<bool>,
+}
+
+impl ServerState {
+    pub(crate) fn apply(&mut self, partial: PartialServerState) {
+        apply_partial!(
+            self,
+            partial,
+            alltime_dl,
+            alltime_ul,
+            connection_status,
+            dht_nodes,
+            dl_info_data,
+            dl_info_speed,
+            dl_rate_limit,
+            free_space_on_disk,
+            queueing,
+            total_peer_connections,
+            up_info_data,
+            up_info_speed,
+            up_rate_limit,
+            use_alt_speed_limits
+        );
+    }
+}
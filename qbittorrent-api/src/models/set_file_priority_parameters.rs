@@ -0,0 +1,23 @@
+use super::file_priority::FilePriority;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SetFilePriorityParameters {
+    hash: String,
+    id: String,
+    priority: u8,
+}
+
+impl SetFilePriorityParameters {
+    pub fn new(hash: String, ids: &[usize], priority: FilePriority) -> Self {
+        Self {
+            hash,
+            id: ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("|"),
+            priority: priority.into(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::serialize_hashes::SerializeHashes;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SetForceStartParameters {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+    value: bool,
+}
+
+impl SetForceStartParameters {
+    pub fn new(hashes: Vec<String>, value: bool) -> Self {
+        Self { hashes, value }
+    }
+}
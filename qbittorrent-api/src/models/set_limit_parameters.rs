@@ -0,0 +1,16 @@
+use crate::serialize_hashes::SerializeHashes;
+use serde::Serialize;
+
+/// Speed limit in bytes per second, `0` or `-1` removes the limit
+#[derive(Debug, Serialize)]
+pub struct SetLimitParameters {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+    limit: i64,
+}
+
+impl SetLimitParameters {
+    pub fn new(hashes: Vec<String>, limit: i64) -> Self {
+        Self { hashes, limit }
+    }
+}
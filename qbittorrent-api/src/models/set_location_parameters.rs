@@ -0,0 +1,15 @@
+use crate::serialize_hashes::SerializeHashes;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SetLocationParameters {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+    location: String,
+}
+
+impl SetLocationParameters {
+    pub fn new(hashes: Vec<String>, location: String) -> Self {
+        Self { hashes, location }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::serialize_hashes::SerializeHashes;
+use getset::Getters;
+use serde::Serialize;
+
+/// Use `-2` for the global limit and `-1` for no limit, times are in minutes
+#[derive(Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::InputObject))]
+#[get = "pub"]
+pub struct ShareLimits {
+    #[cfg_attr(feature = "graphql", graphql(default = -2.0))]
+    ratio_limit: f64,
+    #[cfg_attr(feature = "graphql", graphql(default = -2))]
+    seeding_time_limit: i64,
+    #[cfg_attr(feature = "graphql", graphql(default = -2))]
+    inactive_seeding_time_limit: i64,
+}
+
+impl ShareLimits {
+    pub fn new(
+        ratio_limit: f64,
+        seeding_time_limit: i64,
+        inactive_seeding_time_limit: i64,
+    ) -> Self {
+        Self {
+            ratio_limit,
+            seeding_time_limit,
+            inactive_seeding_time_limit,
+        }
+    }
+}
+
+impl Default for ShareLimits {
+    fn default() -> Self {
+        Self::new(-2.0, -2, -2)
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetShareLimitsParameters {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+    ratio_limit: f64,
+    seeding_time_limit: i64,
+    inactive_seeding_time_limit: i64,
+}
+
+impl SetShareLimitsParameters {
+    pub fn new(hashes: Vec<String>, limits: ShareLimits) -> Self {
+        Self {
+            hashes,
+            ratio_limit: limits.ratio_limit,
+            seeding_time_limit: limits.seeding_time_limit,
+            inactive_seeding_time_limit: limits.inactive_seeding_time_limit,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Speed limits in bytes per second, `0` means unlimited
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct SpeedLimits {
+    download: i64,
+    upload: i64,
+}
+
+impl SpeedLimits {
+    pub fn new(download: i64, upload: i64) -> Self {
+        Self { download, upload }
+    }
+}
+
+/// The alternative limits as stored in the preferences, which are in KiB per second
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AlternativeSpeedLimitsPreferences {
+    alt_dl_limit: i64,
+    alt_up_limit: i64,
+}
+
+impl From<AlternativeSpeedLimitsPreferences> for SpeedLimits {
+    fn from(preferences: AlternativeSpeedLimitsPreferences) -> Self {
+        Self::new(
+            preferences.alt_dl_limit * 1024,
+            preferences.alt_up_limit * 1024,
+        )
+    }
+}
+
+impl From<SpeedLimits> for AlternativeSpeedLimitsPreferences {
+    fn from(limits: SpeedLimits) -> Self {
+        Self {
+            alt_dl_limit: limits.download / 1024,
+            alt_up_limit: limits.upload / 1024,
+        }
+    }
+}
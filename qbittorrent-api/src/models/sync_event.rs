@@ -0,0 +1,25 @@
+use super::torrent_state::TorrentState;
+
+/// A change between two sync snapshots, see `SyncResult::diff`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncEvent {
+    Added {
+        hash: String,
+    },
+    Removed {
+        hash: String,
+    },
+    StateChanged {
+        hash: String,
+        old: TorrentState,
+        new: TorrentState,
+    },
+    Completed {
+        hash: String,
+    },
+    CategoryChanged {
+        hash: String,
+        old: String,
+        new: String,
+    },
+}
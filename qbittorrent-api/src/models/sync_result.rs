@@ -1,29 +1,205 @@
 use getset::Getters;
+use std::collections::{HashMap, HashSet};
 
+use super::{server_state::ServerState, sync_event::SyncEvent};
 use crate::{Category, SyncMainData, Torrent};
 
-#[derive(Debug, Getters)]
+/// Snapshot of the qBittorrent state, built up from `sync/maindata` deltas
+#[derive(Debug, Default, Clone, Getters)]
 #[get = "pub"]
 pub struct SyncResult {
-    torrents: Vec<Torrent>,
-    categories: Vec<Category>,
+    torrents: HashMap<String, Torrent>,
+    categories: HashMap<String, Category>,
+    tags: HashSet<String>,
+    /// Tracker url to the hashes of the torrents using it
+    trackers: HashMap<String, Vec<String>>,
+    server_state: ServerState,
 }
 
-impl From<SyncMainData> for SyncResult {
-    fn from(sync_main_data: SyncMainData) -> Self {
-        Self {
-            torrents: sync_main_data
-                .torrents()
-                .values()
-                .cloned()
-                .map(|torrent| serde_json::from_value(torrent).unwrap())
-                .collect(),
-            categories: sync_main_data
-                .categories()
-                .values()
-                .cloned()
-                .map(|category| serde_json::from_value(category).unwrap())
-                .collect(),
+impl SyncResult {
+    pub fn update(&mut self, delta: SyncMainData) {
+        if *delta.full_update() {
+            *self = Self::default();
         }
+
+        for hash in delta.torrents_removed() {
+            self.torrents.remove(hash);
+        }
+        for (hash, partial) in delta.torrents().to_owned() {
+            match self.torrents.get_mut(&hash) {
+                Some(torrent) => torrent.apply(partial),
+                None => {
+                    let torrent = Torrent::from_partial(&hash, partial);
+                    self.torrents.insert(hash, torrent);
+                }
+            }
+        }
+
+        for name in delta.categories_removed() {
+            self.categories.remove(name);
+        }
+        for (name, partial) in delta.categories().to_owned() {
+            match self.categories.get_mut(&name) {
+                Some(category) => category.apply(partial),
+                None => {
+                    let category = Category::from_partial(&name, partial);
+                    self.categories.insert(name, category);
+                }
+            }
+        }
+
+        for tag in delta.tags_removed() {
+            self.tags.remove(tag);
+        }
+        self.tags.extend(delta.tags().iter().cloned());
+
+        for tracker in delta.trackers_removed() {
+            self.trackers.remove(tracker);
+        }
+        self.trackers.extend(delta.trackers().to_owned());
+
+        if let Some(server_state) = delta.server_state().to_owned() {
+            self.server_state.apply(server_state);
+        }
+    }
+
+    /// Lists the changes to the torrents from `self` to the `newer` snapshot
+    pub fn diff(&self, newer: &SyncResult) -> Vec<SyncEvent> {
+        let mut events = Vec::new();
+
+        for (hash, torrent) in &newer.torrents {
+            let Some(old) = self.torrents.get(hash) else {
+                events.push(SyncEvent::Added { hash: hash.clone() });
+                continue;
+            };
+
+            if old.get_state() != torrent.get_state() {
+                events.push(SyncEvent::StateChanged {
+                    hash: hash.clone(),
+                    old: *old.get_state(),
+                    new: *torrent.get_state(),
+                });
+            }
+
+            if *old.get_progress() < 1.0 && *torrent.get_progress() >= 1.0 {
+                events.push(SyncEvent::Completed { hash: hash.clone() });
+            }
+
+            if old.get_category() != torrent.get_category() {
+                events.push(SyncEvent::CategoryChanged {
+                    hash: hash.clone(),
+                    old: old.get_category().clone(),
+                    new: torrent.get_category().clone(),
+                });
+            }
+        }
+
+        for hash in self.torrents.keys() {
+            if !newer.torrents.contains_key(hash) {
+                events.push(SyncEvent::Removed { hash: hash.clone() });
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::torrent_state::TorrentState;
+
+    fn delta(json: &str) -> SyncMainData {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_update() {
+        let mut result = SyncResult::default();
+
+        result.update(delta(
+            r#"{
+                "rid": 1,
+                "full_update": true,
+                "torrents": { "abc": { "name": "Movie", "progress": 0.5, "state": "downloading" } },
+                "categories": { "movies": { "name": "movies", "savePath": "/movies" } },
+                "tags": ["a", "b"],
+                "server_state": { "dl_info_speed": 100, "connection_status": "connected" }
+            }"#,
+        ));
+
+        result.update(delta(
+            r#"{
+                "rid": 2,
+                "torrents": { "abc": { "progress": 1.0 } },
+                "tags_removed": ["a"],
+                "server_state": { "dl_info_speed": 0 }
+            }"#,
+        ));
+
+        let torrent = &result.torrents()["abc"];
+        assert_eq!(torrent.get_hash(), "abc");
+        assert_eq!(torrent.get_name(), "Movie");
+        assert_eq!(torrent.get_progress(), &1.0);
+        assert_eq!(torrent.get_state(), &TorrentState::Downloading);
+        assert_eq!(result.categories()["movies"].save_path(), "/movies");
+        assert_eq!(result.tags(), &HashSet::from(["b".to_string()]));
+        assert_eq!(result.server_state().get_dl_info_speed(), &0);
+        assert_eq!(
+            result.server_state().get_connection_status(),
+            &crate::ConnectionStatus::Connected
+        );
+    }
+
+    #[test]
+    fn test_malformed_delta() {
+        assert!(serde_json::from_str::<SyncMainData>(
+            r#"{ "rid": 3, "torrents": { "abc": { "progress": "done" } } }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut old = SyncResult::default();
+        old.update(delta(
+            r#"{
+                "rid": 1,
+                "full_update": true,
+                "torrents": {
+                    "abc": { "progress": 0.5, "state": "downloading", "category": "a" },
+                    "def": { "progress": 1.0, "state": "uploading" }
+                }
+            }"#,
+        ));
+
+        let mut new = old.clone();
+        new.update(delta(
+            r#"{
+                "rid": 2,
+                "torrents": {
+                    "abc": { "progress": 1.0, "state": "uploading", "category": "b" },
+                    "ghi": { "progress": 0.0, "state": "metaDL" }
+                },
+                "torrents_removed": ["def"]
+            }"#,
+        ));
+
+        let events = old.diff(&new);
+
+        assert_eq!(events.len(), 5);
+        assert!(events.contains(&SyncEvent::Added { hash: "ghi".into() }));
+        assert!(events.contains(&SyncEvent::Removed { hash: "def".into() }));
+        assert!(events.contains(&SyncEvent::Completed { hash: "abc".into() }));
+        assert!(events.contains(&SyncEvent::StateChanged {
+            hash: "abc".into(),
+            old: TorrentState::Downloading,
+            new: TorrentState::Uploading,
+        }));
+        assert!(events.contains(&SyncEvent::CategoryChanged {
+            hash: "abc".into(),
+            old: "a".into(),
+            new: "b".into(),
+        }));
     }
 }
@@ -0,0 +1,32 @@
+use crate::serialize_hashes::SerializeHashes;
+use serde::{Serialize, Serializer};
+
+fn serialize_tags<S: Serializer>(tags: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    tags.join(",").serialize(serializer)
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagsParameters {
+    #[serde(serialize_with = "serialize_tags")]
+    tags: Vec<String>,
+}
+
+impl TagsParameters {
+    pub fn new(tags: Vec<String>) -> Self {
+        Self { tags }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TorrentTagsParameters {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+    #[serde(serialize_with = "serialize_tags")]
+    tags: Vec<String>,
+}
+
+impl TorrentTagsParameters {
+    pub fn new(hashes: Vec<String>, tags: Vec<String>) -> Self {
+        Self { hashes, tags }
+    }
+}
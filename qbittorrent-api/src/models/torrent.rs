@@ -1,13 +1,18 @@
 use super::torrent_state::TorrentState;
-use chrono::{serde::ts_seconds::deserialize as ts_seconds, DateTime, Utc};
+use chrono::{
+    serde::{
+        ts_seconds::deserialize as ts_seconds, ts_seconds_option::deserialize as ts_seconds_option,
+    },
+    DateTime, Utc,
+};
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Getters, Clone)]
 #[get = "pub with_prefix"]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
-#[graphql(name = "QbittorrentTorrent")]
+#[cfg_attr(feature = "graphql", graphql(name = "QbittorrentTorrent", complex))]
 pub struct Torrent {
     #[serde(deserialize_with = "ts_seconds")]
     added_on: DateTime<Utc>,
@@ -58,4 +63,173 @@ pub struct Torrent {
     uploaded: u64,
     uploaded_session: u64,
     upspeed: u32,
+    /// The client the torrent was read from, the `files`, `trackers` and `peers` fields query it
+    #[serde(skip)]
+    #[getset(skip)]
+    #[cfg_attr(feature = "graphql", graphql(skip))]
+    client: Option<crate::QbittorrentClient>,
+}
+
+/// A torrent in a sync delta, only the changed fields are present
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct PartialTorrent {
+    #[serde(default, deserialize_with = "ts_seconds_option")]
+    added_on: Option<DateTime<Utc>>,
+    amount_left: Option<usize>,
+    auto_tmm: Option<bool>,
+    availability: Option<f64>,
+    category: Option<String>,
+    completed: Option<usize>,
+    #[serde(default, deserialize_with = "ts_seconds_option")]
+    completion_on: Option<DateTime<Utc>>,
+    content_path: Option<String>,
+    dl_limit: Option<i32>,
+    dlspeed: Option<u32>,
+    downloaded: Option<u64>,
+    downloaded_session: Option<u64>,
+    eta: Option<usize>,
+    f_l_piece_prio: Option<bool>,
+    force_start: Option<bool>,
+    #[serde(rename = "infohash_v1")]
+    hash: Option<String>,
+    #[serde(default, deserialize_with = "ts_seconds_option")]
+    last_activity: Option<DateTime<Utc>>,
+    magnet_uri: Option<String>,
+    max_ratio: Option<f64>,
+    max_seeding_time: Option<i32>,
+    name: Option<String>,
+    num_complete: Option<usize>,
+    num_incomplete: Option<usize>,
+    num_leechs: Option<usize>,
+    num_seeds: Option<usize>,
+    priority: Option<i32>,
+    progress: Option<f64>,
+    ratio: Option<f64>,
+    ratio_limit: Option<f64>,
+    save_path: Option<String>,
+    seeding_time_limit: Option<i32>,
+    #[serde(default, deserialize_with = "ts_seconds_option")]
+    seen_complete: Option<DateTime<Utc>>,
+    seq_dl: Option<bool>,
+    size: Option<u64>,
+    state: Option<TorrentState>,
+    super_seeding: Option<bool>,
+    tags: Option<String>,
+    time_active: Option<usize>,
+    total_size: Option<i64>,
+    tracker: Option<String>,
+    up_limit: Option<i32>,
+    uploaded: Option<u64>,
+    uploaded_session: Option<u64>,
+    upspeed: Option<u32>,
+}
+
+impl Torrent {
+    /// The tags split out of the comma separated `tags` field
+    pub fn tag_list(&self) -> Vec<&str> {
+        self.tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .collect()
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tag_list().contains(&tag)
+    }
+
+    /// Creates a torrent from the first delta it appears in, missing fields are left at their default
+    pub(crate) fn from_partial(hash: &str, partial: PartialTorrent) -> Self {
+        let mut torrent = Self::default();
+        torrent.apply(partial);
+
+        // Torrents without a v1 info hash have an empty `infohash_v1`
+        if torrent.hash.is_empty() {
+            torrent.hash = hash.to_string();
+        }
+
+        torrent
+    }
+
+    pub(crate) fn apply(&mut self, partial: PartialTorrent) {
+        apply_partial!(
+            self,
+            partial,
+            added_on,
+            amount_left,
+            auto_tmm,
+            availability,
+            category,
+            completed,
+            completion_on,
+            content_path,
+            dl_limit,
+            dlspeed,
+            downloaded,
+            downloaded_session,
+            eta,
+            f_l_piece_prio,
+            force_start,
+            hash,
+            last_activity,
+            magnet_uri,
+            max_ratio,
+            max_seeding_time,
+            name,
+            num_complete,
+            num_incomplete,
+            num_leechs,
+            num_seeds,
+            priority,
+            progress,
+            ratio,
+            ratio_limit,
+            save_path,
+            seeding_time_limit,
+            seen_complete,
+            seq_dl,
+            size,
+            state,
+            super_seeding,
+            tags,
+            time_active,
+            total_size,
+            tracker,
+            up_limit,
+            uploaded,
+            uploaded_session,
+            upspeed
+        );
+    }
+}
+
+impl Torrent {
+    pub(crate) fn with_client(mut self, client: &crate::QbittorrentClient) -> Self {
+        self.client = Some(client.clone());
+        self
+    }
+
+    #[cfg(feature = "graphql")]
+    fn client(&self) -> async_graphql::Result<&crate::QbittorrentClient> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| "The torrent was not read from a qBittorrent client".into())
+    }
+}
+
+#[cfg(feature = "graphql")]
+#[async_graphql::ComplexObject]
+impl Torrent {
+    async fn files(&self) -> async_graphql::Result<Vec<super::torrent_file::TorrentFile>> {
+        Ok(self.client()?.torrent_files(&self.hash).await?)
+    }
+
+    async fn trackers(&self) -> async_graphql::Result<Vec<super::torrent_tracker::TorrentTracker>> {
+        Ok(self.client()?.torrent_trackers(&self.hash).await?)
+    }
+
+    async fn peers(&self) -> async_graphql::Result<Vec<super::torrent_peer::TorrentPeer>> {
+        Ok(self.client()?.torrent_peers(&self.hash).await?)
+    }
 }
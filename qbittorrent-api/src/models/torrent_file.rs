@@ -0,0 +1,21 @@
+use super::file_priority::FilePriority;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct TorrentFile {
+    index: usize,
+    /// Path relative to the save path of the torrent
+    name: String,
+    size: u64,
+    progress: f64,
+    priority: FilePriority,
+    #[serde(default)]
+    is_seed: bool,
+    /// First and last piece of the file
+    piece_range: Vec<usize>,
+    /// Fraction of the file available in the swarm, `-1` when not known
+    availability: f64,
+}
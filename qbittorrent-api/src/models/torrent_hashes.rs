@@ -0,0 +1,15 @@
+use crate::serialize_hashes::SerializeHashes;
+use serde::Serialize;
+
+/// Form used by the calls that only take a list of hashes, `all` selects every torrent
+#[derive(Debug, Serialize)]
+pub struct TorrentHashes {
+    #[serde(serialize_with = "Vec::serialize_hashes")]
+    hashes: Vec<String>,
+}
+
+impl TorrentHashes {
+    pub fn new(hashes: Vec<String>) -> Self {
+        Self { hashes }
+    }
+}
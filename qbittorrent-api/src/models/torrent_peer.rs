@@ -0,0 +1,51 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone, Default)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+#[serde(default)]
+pub struct TorrentPeer {
+    /// `ip:port`, filled from the key of the peer
+    address: String,
+    ip: String,
+    port: u16,
+    client: String,
+    connection: String,
+    country: String,
+    country_code: String,
+    /// Speeds are in bytes per second
+    dl_speed: u64,
+    up_speed: u64,
+    downloaded: u64,
+    uploaded: u64,
+    progress: f64,
+    relevance: f64,
+    flags: String,
+    flags_desc: String,
+    files: String,
+}
+
+/// The response of `sync/torrentPeers`, only full updates are requested
+#[derive(Deserialize, Debug)]
+pub(crate) struct TorrentPeers {
+    #[serde(default)]
+    peers: HashMap<String, TorrentPeer>,
+}
+
+impl TorrentPeers {
+    pub(crate) fn into_peers(self) -> Vec<TorrentPeer> {
+        let mut peers: Vec<TorrentPeer> = self
+            .peers
+            .into_iter()
+            .map(|(address, mut peer)| {
+                peer.address = address;
+                peer
+            })
+            .collect();
+
+        peers.sort_by(|a, b| a.address.cmp(&b.address));
+        peers
+    }
+}
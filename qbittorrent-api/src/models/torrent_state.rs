@@ -1,10 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, Copy, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, Copy, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
-
 pub enum TorrentState {
     /// Some error occurred, applies to paused torrents
     Error,
@@ -12,7 +11,8 @@ pub enum TorrentState {
     MissingFiles,
     /// Torrent is being seeded and data is being transferred
     Uploading,
-    /// Torrent is paused and has finished downloading
+    /// Torrent is paused and has finished downloading, called `stoppedUP` since qBittorrent 5
+    #[serde(alias = "stoppedUP")]
     PausedUP,
     /// Queuing is enabled and torrent is queued for upload
     QueuedUP,
@@ -28,7 +28,8 @@ pub enum TorrentState {
     Downloading,
     /// Torrent has just started downloading and is fetching metadata
     MetaDL,
-    /// Torrent is paused and has NOT finished downloading
+    /// Torrent is paused and has NOT finished downloading, called `stoppedDL` since qBittorrent 5
+    #[serde(alias = "stoppedDL")]
     PausedDL,
     /// Queuing is enabled and torrent is queued for download
     QueuedDL,
@@ -42,10 +43,9 @@ pub enum TorrentState {
     CheckingResumeData,
     /// Torrent is moving to another location
     Moving,
-    /// Not in the wiki?
-    /// https://github.com/qbittorrent/qBittorrent/issues/21594
-    StoppedUP,
-    /// Unknown status
+    /// Unknown status, also used for states added in newer versions
+    #[default]
+    #[serde(other)]
     Unknown,
 }
 
@@ -63,3 +63,18 @@ impl TorrentState {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qbittorrent_5_states() {
+        let state = |name: &str| serde_json::from_value::<TorrentState>(name.into()).unwrap();
+
+        assert_eq!(state("stoppedUP"), TorrentState::PausedUP);
+        assert_eq!(state("stoppedDL"), TorrentState::PausedDL);
+        assert_eq!(state("pausedUP"), TorrentState::PausedUP);
+        assert_eq!(state("somethingNew"), TorrentState::Unknown);
+    }
+}
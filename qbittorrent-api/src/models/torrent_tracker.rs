@@ -0,0 +1,53 @@
+use super::tracker_status::TrackerStatus;
+use getset::Getters;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Older versions send an empty string as the tier of the DHT, PeX and LSD entries
+fn deserialize_tier<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Number(tier) => tier.as_i64().unwrap_or(-1),
+        Value::String(tier) => tier.parse().unwrap_or(-1),
+        _ => -1,
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct TorrentTracker {
+    /// The tracker url, or `** [DHT] **`, `** [PeX] **` and `** [LSD] **`
+    url: String,
+    status: TrackerStatus,
+    /// `-1` for the DHT, PeX and LSD entries
+    #[serde(deserialize_with = "deserialize_tier")]
+    tier: i64,
+    /// Counts are `-1` when the tracker did not report them
+    num_peers: i64,
+    num_seeds: i64,
+    num_leeches: i64,
+    num_downloaded: i64,
+    /// The message of the tracker, usually the reason it is not working
+    msg: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let trackers: Vec<TorrentTracker> = serde_json::from_str(
+            r#"[
+                { "url": "** [DHT] **", "status": 0, "tier": "", "num_peers": 12, "num_seeds": 0, "num_leeches": 0, "num_downloaded": 0, "msg": "" },
+                { "url": "udp://tracker.example:1337", "status": 4, "tier": 0, "num_peers": -1, "num_seeds": -1, "num_leeches": -1, "num_downloaded": -1, "msg": "Connection timed out" }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(trackers[0].tier, -1);
+        assert_eq!(trackers[0].status, TrackerStatus::Disabled);
+        assert_eq!(trackers[1].tier, 0);
+        assert!(trackers[1].status.is_failing());
+    }
+}
@@ -0,0 +1,23 @@
+/// A `.torrent` file to upload
+#[derive(Debug, Clone)]
+pub struct TorrentUpload {
+    filename: String,
+    content: Vec<u8>,
+}
+
+impl TorrentUpload {
+    pub fn new<S: Into<String>>(filename: S, content: Vec<u8>) -> Self {
+        Self {
+            filename: filename.into(),
+            content,
+        }
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+}
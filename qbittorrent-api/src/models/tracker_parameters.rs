@@ -0,0 +1,52 @@
+use serde::{Serialize, Serializer};
+
+fn serialize_urls<S: Serializer>(urls: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    urls.join("\n").serialize(serializer)
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddTrackersParameters {
+    hash: String,
+    #[serde(serialize_with = "serialize_urls")]
+    urls: Vec<String>,
+}
+
+impl AddTrackersParameters {
+    pub fn new(hash: String, urls: Vec<String>) -> Self {
+        Self { hash, urls }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTrackerParameters {
+    hash: String,
+    orig_url: String,
+    new_url: String,
+}
+
+impl EditTrackerParameters {
+    pub fn new(hash: String, orig_url: String, new_url: String) -> Self {
+        Self {
+            hash,
+            orig_url,
+            new_url,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemoveTrackersParameters {
+    hash: String,
+    /// Separated by `|` like hashes
+    urls: String,
+}
+
+impl RemoveTrackersParameters {
+    pub fn new(hash: String, urls: Vec<String>) -> Self {
+        Self {
+            hash,
+            urls: urls.join("|"),
+        }
+    }
+}
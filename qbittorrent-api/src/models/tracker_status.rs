@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(from = "u8", into = "u8")]
+pub enum TrackerStatus {
+    /// Used for the DHT, PeX and LSD entries
+    Disabled,
+    NotContacted,
+    Working,
+    Updating,
+    NotWorking,
+}
+
+impl TrackerStatus {
+    pub fn is_failing(&self) -> bool {
+        *self == TrackerStatus::NotWorking
+    }
+}
+
+impl From<u8> for TrackerStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => TrackerStatus::Disabled,
+            2 => TrackerStatus::Working,
+            3 => TrackerStatus::Updating,
+            4 => TrackerStatus::NotWorking,
+            _ => TrackerStatus::NotContacted,
+        }
+    }
+}
+
+impl From<TrackerStatus> for u8 {
+    fn from(status: TrackerStatus) -> Self {
+        match status {
+            TrackerStatus::Disabled => 0,
+            TrackerStatus::NotContacted => 1,
+            TrackerStatus::Working => 2,
+            TrackerStatus::Updating => 3,
+            TrackerStatus::NotWorking => 4,
+        }
+    }
+}
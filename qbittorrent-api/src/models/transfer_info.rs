@@ -0,0 +1,18 @@
+use super::server_state::ConnectionStatus;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Global transfer info, speeds and limits are in bytes per second
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct TransferInfo {
+    dl_info_speed: u64,
+    dl_info_data: u64,
+    up_info_speed: u64,
+    up_info_data: u64,
+    dl_rate_limit: u64,
+    up_rate_limit: u64,
+    dht_nodes: u64,
+    connection_status: ConnectionStatus,
+}
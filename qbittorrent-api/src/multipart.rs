@@ -0,0 +1,130 @@
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::Value;
+use surf::{http::Mime, Body};
+
+/// A `multipart/form-data` body, surf can only send url encoded forms
+pub struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    pub fn new() -> Self {
+        Self {
+            boundary: format!(
+                "qbittorrent-api-{}",
+                Utc::now().timestamp_nanos_opt().unwrap_or_default()
+            ),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn text(&mut self, name: &str, value: &str) -> &mut Self {
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n",
+                self.boundary
+            )
+            .as_bytes(),
+        );
+        self
+    }
+
+    pub fn file(&mut self, name: &str, filename: &str, content: &[u8]) -> &mut Self {
+        let filename = filename.replace('"', "\\\"");
+
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\nContent-Type: application/x-bittorrent\r\n\r\n",
+                self.boundary
+            )
+            .as_bytes(),
+        );
+        self.body.extend_from_slice(content);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Adds every field of `form` as a text part, skipping empty values
+    pub fn form<T: Serialize>(&mut self, form: &T) -> Result<&mut Self, serde_json::Error> {
+        if let Value::Object(fields) = serde_json::to_value(form)? {
+            for (name, value) in fields {
+                match value {
+                    Value::Null => {}
+                    Value::String(value) if value.is_empty() => {}
+                    Value::String(value) => {
+                        self.text(&name, &value);
+                    }
+                    value => {
+                        self.text(&name, &value.to_string());
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    pub fn into_body(self) -> Body {
+        let mime = self
+            .content_type()
+            .parse::<Mime>()
+            .expect("Valid multipart mime");
+
+        let mut body = Body::from_bytes(self.finish());
+        body.set_mime(mime);
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Form {
+        category: Option<String>,
+        paused: Option<bool>,
+        urls: String,
+    }
+
+    #[test]
+    fn test_multipart() {
+        let mut multipart = Multipart::new();
+        let boundary = multipart.boundary.clone();
+
+        multipart
+            .form(&Form {
+                category: Some("movies".into()),
+                paused: None,
+                urls: String::new(),
+            })
+            .unwrap()
+            .file("torrents", "a.torrent", b"d4:infoe");
+
+        assert_eq!(
+            multipart.content_type(),
+            format!("multipart/form-data; boundary={boundary}")
+        );
+
+        assert_eq!(
+            String::from_utf8(multipart.finish()).unwrap(),
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"category\"\r\n\r\nmovies\r\n\
+                 --{boundary}\r\nContent-Disposition: form-data; name=\"torrents\"; filename=\"a.torrent\"\r\nContent-Type: application/x-bittorrent\r\n\r\nd4:infoe\r\n\
+                 --{boundary}--\r\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use fake_qbittorrent::{FakeLogType, FakeQbittorrent, PASSWORD, USERNAME};
+use qbittorrent_api::{
+    AddTorrentOptions, ErrorKind, GetTorrentsParameters, LogParameters, LogType, QbittorrentClient,
+    SearchJobStatus, TorrentState, TorrentUpload,
+};
+
+const HASH: &str = "c9e15763f722f23e98a29decdfae341b98d53056";
+
+fn magnet(hash: &str, name: &str) -> String {
+    format!("magnet:?xt=urn:btih:{hash}&dn={name}")
+}
+
+async fn start() -> (FakeQbittorrent, QbittorrentClient) {
+    let server = FakeQbittorrent::start().await;
+    let client = QbittorrentClient::new(USERNAME, PASSWORD, server.url());
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_add_torrent() {
+    let (server, client) = start().await;
+
+    let mut options = AddTorrentOptions::default();
+    options
+        .set_category(Some("movies".to_string()))
+        .set_tags(Some("tmdb:603, hd".to_string()));
+    client
+        .add_torrent(magnet(HASH, "The+Matrix+(1999)"), options)
+        .await
+        .unwrap();
+
+    let mut parameters = GetTorrentsParameters::default();
+    parameters.set_category(Some("movies".to_string()));
+    let torrents = client.torrents(&parameters).await.unwrap();
+
+    assert_eq!(torrents.len(), 1);
+    let torrent = &torrents[0];
+    assert_eq!(torrent.get_hash(), HASH);
+    assert_eq!(torrent.get_name(), "The Matrix (1999)");
+    assert_eq!(torrent.get_category(), "movies");
+    assert!(torrent.has_tag("tmdb:603"));
+    assert!(server.tags().contains("hd"));
+    assert_eq!(server.logins(), 1);
+}
+
+#[tokio::test]
+async fn test_upload_torrent() {
+    let (server, client) = start().await;
+
+    let file = TorrentUpload::new("Movie.torrent", b"d4:infod4:name5:Moviee".to_vec());
+    client
+        .upload_torrent(file, AddTorrentOptions::default())
+        .await
+        .unwrap();
+
+    let torrents = server.torrents();
+    assert_eq!(torrents.len(), 1);
+    assert_eq!(torrents[0].name(), "Movie");
+}
+
+#[tokio::test]
+async fn test_sync_deltas() {
+    let (server, client) = start().await;
+    server.add_torrent(HASH, "Movie", "movies");
+
+    let sync = client.sync().await.unwrap();
+    let torrent = &sync.torrents()[HASH];
+    assert_eq!(*torrent.get_progress(), 0.0);
+    assert!(sync.categories().contains_key("movies"));
+
+    server.set_progress(HASH, 0.5);
+    server.set_download_speed(HASH, 1 << 20);
+    let sync = client.sync().await.unwrap();
+    let torrent = &sync.torrents()[HASH];
+    assert_eq!(*torrent.get_progress(), 0.5);
+    assert_eq!(*torrent.get_state(), TorrentState::Downloading);
+    assert_eq!(*torrent.get_eta(), 512);
+    // Fields missing from the delta are kept from the full update
+    assert_eq!(torrent.get_name(), "Movie");
+
+    server.complete(HASH);
+    let sync = client.sync().await.unwrap();
+    assert_eq!(*sync.torrents()[HASH].get_state(), TorrentState::StalledUP);
+
+    server.remove_torrent(HASH);
+    let sync = client.sync().await.unwrap();
+    assert!(sync.torrents().is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_resyncs_after_bad_delta() {
+    let (server, client) = start().await;
+    server.add_torrent(HASH, "Movie", "movies");
+
+    client.sync().await.unwrap();
+    server.set_progress(HASH, 0.5);
+    server.corrupt_next_sync_delta();
+
+    let sync = client.sync().await.unwrap();
+    let torrent = &sync.torrents()[HASH];
+    assert_eq!(*torrent.get_progress(), 0.5);
+    assert_eq!(torrent.get_name(), "Movie");
+    assert_eq!(server.sync_rids(), [0, 1, 0]);
+
+    // The full update restarted the deltas
+    server.complete(HASH);
+    let sync = client.sync().await.unwrap();
+    assert_eq!(*sync.torrents()[HASH].get_progress(), 1.0);
+    assert_eq!(server.sync_rids(), [0, 1, 0, 3]);
+}
+
+#[tokio::test]
+async fn test_categories() {
+    let (server, client) = start().await;
+    server.add_torrent(HASH, "Movie", "");
+
+    client.ensure_category("movies", "").await.unwrap();
+    client.ensure_category("movies", "/movies").await.unwrap();
+    assert_eq!(server.categories()["movies"], "/movies");
+
+    client
+        .set_category(HASH.to_string(), "movies".to_string())
+        .await
+        .unwrap();
+    assert_eq!(server.torrent(HASH).unwrap().category(), "movies");
+
+    let error = client
+        .set_category(HASH.to_string(), "missing".to_string())
+        .await
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::CategoryDoesNotExist));
+
+    client
+        .delete_torrent(HASH.to_string(), false)
+        .await
+        .unwrap();
+    assert!(server.torrents().is_empty());
+}
+
+#[tokio::test]
+async fn test_login_again_after_session_expired() {
+    let (server, client) = start().await;
+
+    client.version().await.unwrap();
+    server.expire_sessions();
+    client.version().await.unwrap();
+
+    assert_eq!(server.logins(), 2);
+}
+
+#[tokio::test]
+async fn test_incorrect_login() {
+    let server = FakeQbittorrent::start().await;
+    let client = QbittorrentClient::new(USERNAME, "wrong", server.url());
+
+    assert!(client.version().await.is_err());
+    assert_eq!(server.logins(), 0);
+}
+
+#[tokio::test]
+async fn test_search() {
+    let (server, client) = start().await;
+    server.add_search_result(
+        "The.Matrix.1999.1080p",
+        &magnet(HASH, "Matrix"),
+        1 << 30,
+        10,
+        2,
+    );
+    server.add_search_result(
+        "Inception.2010.720p",
+        &magnet(HASH, "Inception"),
+        1 << 30,
+        5,
+        1,
+    );
+
+    let plugins = client.search_plugins().await.unwrap();
+    assert!(plugins[0].get_enabled());
+
+    let job = client.start_search("matrix", &[], "movies").await.unwrap();
+    let status = client.search_status(*job.get_id()).await.unwrap();
+    assert_eq!(*status.get_status(), SearchJobStatus::Stopped);
+
+    let results = client
+        .search_results(*job.get_id(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(*results.get_total(), 1);
+    assert_eq!(
+        results.get_results()[0].get_file_name(),
+        "The.Matrix.1999.1080p"
+    );
+
+    client.delete_search(*job.get_id()).await.unwrap();
+    assert!(server.search_jobs().is_empty());
+}
+
+#[tokio::test]
+async fn test_main_log() {
+    let (server, client) = start().await;
+    server.add_log("qBittorrent v5.0.0 started", FakeLogType::Normal);
+    server.add_log(
+        "File error alert. Torrent: \"Movie\"",
+        FakeLogType::Critical,
+    );
+
+    let mut params = LogParameters::default();
+    params.set_normal(false);
+    let entries = client.main_log(&params).await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(*entries[0].get_log_type(), LogType::Critical);
+
+    server.add_peer_log("10.0.0.1", true, "IP filter");
+    let peers = client.peer_log(None).await.unwrap();
+    assert_eq!(peers[0].get_reason(), "IP filter");
+    assert!(client
+        .peer_log(Some(*peers[0].get_id()))
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_recent_log() {
+    let (server, client) = start().await;
+    server.add_log("first", FakeLogType::Info);
+    assert_eq!(client.recent_log().await.unwrap().len(), 1);
+
+    server.add_log("second", FakeLogType::Warning);
+    let entries = client.recent_log().await.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].get_message(), "second");
+
+    // The ids start over after a restart, the old messages are dropped
+    server.restart_log();
+    server.add_log("after restart", FakeLogType::Info);
+    let entries = client.recent_log().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].get_message(), "after restart");
+}
@@ -5,10 +5,12 @@ use crate::{
     movie_properties::MovieProperties,
     search_options::{movie_options::MovieOptions, sort_column::SortColumn, SearchOptions},
     torrent::Torrent,
-    utils::{parse_title::is_title_match, round_robin::RoundRobin},
+    utils::round_robin::RoundRobin,
     Category, TorrentProvider,
 };
 use crate::{Codec, ErrorKind, Quality, Source};
+
+use super::capabilities::ProviderCapabilities;
 use async_trait::async_trait;
 use bytesize::ByteSize;
 use chrono::{NaiveDateTime, Utc};
@@ -73,7 +75,8 @@ impl BitSearch {
             SortColumn::Added => "date",
             SortColumn::Leechers => "leechers",
             SortColumn::Size => "size",
-            SortColumn::Seeders => "seeders",
+            // Ranked afterwards, the best torrents are most likely among the most seeded
+            SortColumn::Seeders | SortColumn::Best => "seeders",
         }
     }
 
@@ -93,6 +96,26 @@ impl BitSearch {
 #[async_trait]
 impl TorrentProvider for BitSearch {
     const PROVIDER: Provider = Provider::BitSearch;
+    const HOSTS: &'static [&'static str] = &["bitsearch.to", "solidtorrents.to"];
+
+    fn capabilities() -> ProviderCapabilities {
+        ProviderCapabilities::new(Self::PROVIDER)
+            .with_categories(&[
+                Category::All,
+                Category::Applications,
+                Category::Audio,
+                Category::Video,
+                Category::Games,
+            ])
+            .with_sort_columns(&[
+                SortColumn::Added,
+                SortColumn::Leechers,
+                SortColumn::Size,
+                SortColumn::Seeders,
+            ])
+            .with_paging()
+            .with_tv()
+    }
 
     async fn search(search_options: &SearchOptions, http: &Client) -> Result<Vec<Torrent>, Error> {
         let url = BitSearch::format_url(search_options);
@@ -192,12 +215,15 @@ impl TorrentProvider for BitSearch {
                     .0,
                 provider: Provider::BitSearch.into(),
                 magnet,
+                torrent_url: None,
                 movie_properties: Some(MovieProperties::new(
                     String::new(),
                     Quality::from(&name),
                     Codec::from(&name),
                     Source::from(&name),
                 )),
+                trusted: false,
+                score: None,
 
                 name,
             })
@@ -210,22 +236,7 @@ impl TorrentProvider for BitSearch {
         movie_options: &MovieOptions,
         http: &Client,
     ) -> Result<Vec<Torrent>, Error> {
-        if let Some(title) = movie_options.title() {
-            let options = SearchOptions::new(
-                title.to_string(),
-                Category::Video,
-                *movie_options.sort(),
-                *movie_options.order(),
-            );
-
-            let mut torrents = Self::search(&options, http).await?;
-
-            torrents.retain(|t| is_title_match(title, &t.name));
-
-            Ok(torrents)
-        } else {
-            Ok(Vec::new())
-        }
+        Self::search_title(movie_options, http).await
     }
 }
 
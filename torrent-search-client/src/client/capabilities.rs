@@ -0,0 +1,99 @@
+use getset::Getters;
+
+use crate::{Category, Provider, SortColumn};
+
+#[derive(Debug, Clone, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct ProviderCapabilities {
+    provider: Provider,
+    categories: Vec<Category>,
+    sort_columns: Vec<SortColumn>,
+    imdb_search: bool,
+    tmdb_search: bool,
+    paging: bool,
+    tv: bool,
+}
+
+impl ProviderCapabilities {
+    pub fn new(provider: Provider) -> Self {
+        Self {
+            provider,
+            categories: vec![Category::All],
+            sort_columns: Vec::new(),
+            imdb_search: false,
+            tmdb_search: false,
+            paging: false,
+            tv: false,
+        }
+    }
+
+    pub fn with_categories(mut self, categories: &[Category]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+
+    pub fn with_sort_columns(mut self, sort_columns: &[SortColumn]) -> Self {
+        self.sort_columns = sort_columns.to_vec();
+        self
+    }
+
+    pub fn with_imdb_search(mut self) -> Self {
+        self.imdb_search = true;
+        self
+    }
+
+    pub fn with_tmdb_search(mut self) -> Self {
+        self.tmdb_search = true;
+        self
+    }
+
+    pub fn with_paging(mut self) -> Self {
+        self.paging = true;
+        self
+    }
+
+    pub fn with_tv(mut self) -> Self {
+        self.tv = true;
+        self
+    }
+
+    pub fn supports_category(&self, category: &Category) -> bool {
+        self.categories.contains(category)
+    }
+
+    pub fn supports_sort(&self, sort: &SortColumn) -> bool {
+        self.sort_columns.contains(sort)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let capabilities = ProviderCapabilities::new(Provider::Yts);
+
+        assert!(capabilities.supports_category(&Category::All));
+        assert!(!capabilities.supports_category(&Category::Video));
+        assert!(!capabilities.supports_sort(&SortColumn::Seeders));
+        assert!(!capabilities.get_imdb_search());
+    }
+
+    #[test]
+    fn test_provider_capabilities() {
+        let yts = Provider::Yts.capabilities();
+        assert!(!yts.supports_sort(&SortColumn::Size));
+        assert!(!yts.supports_category(&Category::Audio));
+        assert!(yts.get_imdb_search());
+
+        let piratebay = Provider::PirateBay.capabilities();
+        assert!(piratebay.get_sort_columns().is_empty());
+        assert!(piratebay.get_imdb_search());
+
+        let bitsearch = Provider::BitSearch.capabilities();
+        assert!(bitsearch.supports_sort(&SortColumn::Size));
+        assert!(!bitsearch.get_imdb_search());
+    }
+}
@@ -1,19 +1,54 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, future::Future};
 
 use crate::{
+    client::{
+        bitsearch::BitSearch, capabilities::ProviderCapabilities, piratebay::PirateBay, yts::Yts,
+    },
     error::Error,
     search_options::{movie_options::MovieOptions, SearchOptions},
     torrent::Torrent,
+    utils::parse_title::is_title_match,
+    Category,
 };
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use surf::Client;
 
 pub mod bitsearch;
+pub mod capabilities;
 pub mod piratebay;
+#[cfg(feature = "qbittorrent")]
+pub mod qbittorrent;
 pub mod yts;
 
+/// Runs `search` for the title of the movie in videos, keeping only torrents whose parsed title matches
+pub(crate) async fn search_movie_title<F, Fut>(
+    movie_options: &MovieOptions,
+    search: F,
+) -> Result<Vec<Torrent>, Error>
+where
+    F: FnOnce(SearchOptions) -> Fut,
+    Fut: Future<Output = Result<Vec<Torrent>, Error>>,
+{
+    let (Some(title), Some(query)) = (movie_options.title(), movie_options.title_query()) else {
+        return Ok(Vec::new());
+    };
+
+    let options = SearchOptions::new(
+        query,
+        Category::Video,
+        *movie_options.sort(),
+        *movie_options.order(),
+    );
+
+    let mut torrents = search(options).await?;
+
+    torrents.retain(|t| is_title_match(title, &t.name));
+
+    Ok(torrents)
+}
+
 pub struct ProviderResponse {
     pub provider: Provider,
     pub torrents: Result<Vec<Torrent>, Error>,
@@ -22,6 +57,9 @@ pub struct ProviderResponse {
 #[async_trait]
 pub trait TorrentProvider {
     const PROVIDER: Provider;
+    const HOSTS: &'static [&'static str];
+
+    fn capabilities() -> ProviderCapabilities;
 
     fn create_response(torrents: Result<Vec<Torrent>, Error>) -> ProviderResponse {
         ProviderResponse {
@@ -37,6 +75,17 @@ pub trait TorrentProvider {
         http: &Client,
     ) -> Result<Vec<Torrent>, Error>;
 
+    /// Searches for the movie title, keeping only torrents whose parsed title matches
+    async fn search_title(
+        movie_options: &MovieOptions,
+        http: &Client,
+    ) -> Result<Vec<Torrent>, Error> {
+        search_movie_title(movie_options, |options| async move {
+            Self::search(&options, http).await
+        })
+        .await
+    }
+
     async fn search_provider(search_options: &SearchOptions, http: &Client) -> ProviderResponse {
         let torrents = Self::search(search_options, http).await;
 
@@ -54,7 +103,7 @@ pub trait TorrentProvider {
 }
 
 use strum_macros::EnumIter;
-#[derive(EnumIter, Serialize, Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(EnumIter, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Copy)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 pub enum Provider {
     #[cfg_attr(feature = "graphql", graphql(name = "PIRATEBAY"))]
@@ -62,12 +111,35 @@ pub enum Provider {
     Yts,
     #[cfg_attr(feature = "graphql", graphql(name = "BITSEARCH"))]
     BitSearch,
+    /// The search plugins installed in qBittorrent, only searched when it is configured
+    #[cfg(feature = "qbittorrent")]
+    Qbittorrent,
 }
 
 impl Provider {
     pub fn all() -> HashSet<Provider> {
         Provider::iter().collect()
     }
+
+    pub fn hosts(&self) -> &'static [&'static str] {
+        match self {
+            Provider::PirateBay => PirateBay::HOSTS,
+            Provider::Yts => Yts::HOSTS,
+            Provider::BitSearch => BitSearch::HOSTS,
+            #[cfg(feature = "qbittorrent")]
+            Provider::Qbittorrent => qbittorrent::QbittorrentSearch::HOSTS,
+        }
+    }
+
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        match self {
+            Provider::PirateBay => PirateBay::capabilities(),
+            Provider::Yts => Yts::capabilities(),
+            Provider::BitSearch => BitSearch::capabilities(),
+            #[cfg(feature = "qbittorrent")]
+            Provider::Qbittorrent => qbittorrent::QbittorrentSearch::capabilities(),
+        }
+    }
 }
 
 impl From<Provider> for HashSet<Provider> {
@@ -1,4 +1,4 @@
-use super::Error;
+use super::{capabilities::ProviderCapabilities, Error};
 use crate::{
     search_options::{category::Category, movie_options::MovieOptions, SearchOptions},
     torrent::Torrent,
@@ -57,10 +57,10 @@ impl PirateBay {
         url
     }
 
-    fn format_movie_url(movie_options: &MovieOptions) -> Url {
+    fn format_movie_url(imdb: &str) -> Url {
         let mut url = PIRATE_BAY_URL.clone();
 
-        url.query_pairs_mut().append_pair("q", movie_options.imdb());
+        url.query_pairs_mut().append_pair("q", imdb);
 
         url
     }
@@ -89,6 +89,21 @@ impl PirateBay {
 #[async_trait]
 impl TorrentProvider for PirateBay {
     const PROVIDER: Provider = Provider::PirateBay;
+    const HOSTS: &'static [&'static str] = &["apibay.org"];
+
+    fn capabilities() -> ProviderCapabilities {
+        ProviderCapabilities::new(Self::PROVIDER)
+            .with_categories(&[
+                Category::All,
+                Category::Applications,
+                Category::Audio,
+                Category::Video,
+                Category::Games,
+                Category::Other,
+            ])
+            .with_imdb_search()
+            .with_tv()
+    }
 
     async fn search(search_options: &SearchOptions, http: &Client) -> Result<Vec<Torrent>, Error> {
         let url = PirateBay::format_url(search_options);
@@ -102,14 +117,18 @@ impl TorrentProvider for PirateBay {
         movie_options: &MovieOptions,
         http: &Client,
     ) -> Result<Vec<Torrent>, Error> {
-        let url = PirateBay::format_movie_url(movie_options);
+        let Some(movie_imdb) = movie_options.imdb() else {
+            return Self::search_title(movie_options, http).await;
+        };
+
+        let url = PirateBay::format_movie_url(movie_imdb);
 
         let mut torrents = PirateBay::search_request(url, http).await?;
 
         torrents.retain(|torrent| {
             if let Some(torrent_properties) = &torrent.movie_properties {
                 if let Some(imdb) = torrent_properties.get_imdb() {
-                    imdb == movie_options.imdb()
+                    imdb == movie_imdb
                 } else {
                     false
                 }
@@ -143,14 +162,7 @@ mod tests {
 
     #[test]
     fn test_format_movie_url() {
-        let movie_options = MovieOptions::new(
-            "tt1234567".into(),
-            None,
-            SortColumn::Seeders,
-            Order::Ascending,
-        );
-
-        let url = PirateBay::format_movie_url(&movie_options);
+        let url = PirateBay::format_movie_url("tt1234567");
         assert_eq!(url.as_str(), "https://apibay.org/q.php?q=tt1234567");
     }
 
@@ -0,0 +1,213 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    client::{capabilities::ProviderCapabilities, search_movie_title, Provider, ProviderResponse},
+    error::Error,
+    search_options::{movie_options::MovieOptions, SearchOptions},
+    torrent::Torrent,
+    Category,
+};
+use getset::{Getters, Setters};
+use log::warn;
+use qbittorrent_api::{QbittorrentClient, SearchJobStatus, SearchResult};
+use tokio::time::sleep;
+
+/// Searches with the plugins of qBittorrent's search engine
+#[derive(Clone, Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct QbittorrentSearchOptions {
+    client: QbittorrentClient,
+    /// Plugin names, every enabled plugin when empty
+    plugins: Vec<String>,
+    /// The search job is stopped after it, keeping the results found until then
+    timeout: Duration,
+    poll_interval: Duration,
+    /// The maximum number of results read from the job
+    limit: usize,
+}
+
+impl QbittorrentSearchOptions {
+    pub fn new(client: QbittorrentClient) -> Self {
+        Self {
+            client,
+            plugins: Vec::new(),
+            timeout: Duration::from_secs(20),
+            poll_interval: Duration::from_millis(500),
+            limit: 500,
+        }
+    }
+}
+
+impl fmt::Debug for QbittorrentSearchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QbittorrentSearchOptions")
+            .field("plugins", &self.plugins)
+            .field("timeout", &self.timeout)
+            .field("poll_interval", &self.poll_interval)
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Unlike the other providers it needs a qBittorrent instance, so it is not a [`crate::TorrentProvider`]
+pub struct QbittorrentSearch {
+    options: QbittorrentSearchOptions,
+}
+
+impl QbittorrentSearch {
+    pub const PROVIDER: Provider = Provider::Qbittorrent;
+    /// The requests go to the user's qBittorrent, host rate limits do not apply
+    pub const HOSTS: &'static [&'static str] = &[];
+
+    pub fn new(options: QbittorrentSearchOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn capabilities() -> ProviderCapabilities {
+        ProviderCapabilities::new(Self::PROVIDER).with_categories(&[
+            Category::All,
+            Category::Applications,
+            Category::Audio,
+            Category::Video,
+            Category::Games,
+        ])
+    }
+
+    fn format_category(category: &Category) -> &'static str {
+        match category {
+            Category::All | Category::Other => "all",
+            Category::Applications => "software",
+            Category::Audio => "music",
+            Category::Video => "movies",
+            Category::Games => "games",
+        }
+    }
+
+    /// Waits for the job to stop, or stops it once the timeout is reached
+    async fn wait_for_results(&self, id: u64) -> Result<Vec<SearchResult>, Error> {
+        let client = &self.options.client;
+        let started = Instant::now();
+
+        loop {
+            let status = client.search_status(id).await?;
+
+            if *status.get_status() == SearchJobStatus::Stopped {
+                break;
+            }
+            if started.elapsed() >= self.options.timeout {
+                client.stop_search(id).await?;
+                break;
+            }
+
+            sleep(self.options.poll_interval).await;
+        }
+
+        let results = client
+            .search_results(id, Some(self.options.limit), None)
+            .await?;
+
+        Ok(results.get_results().to_owned())
+    }
+
+    pub async fn search(&self, search_options: &SearchOptions) -> Result<Vec<Torrent>, Error> {
+        let client = &self.options.client;
+
+        let job = client
+            .start_search(
+                search_options.query(),
+                &self.options.plugins,
+                Self::format_category(search_options.category()),
+            )
+            .await?;
+        let id = *job.get_id();
+
+        let results = self.wait_for_results(id).await;
+
+        // qBittorrent only runs a few jobs at once, so they are deleted even when reading them failed
+        if let Err(error) = client.delete_search(id).await {
+            warn!("Could not delete the qBittorrent search job {id}: {error}");
+        }
+
+        Ok(results?.into_iter().map(Torrent::from).collect())
+    }
+
+    /// Searches for the movie title, keeping only torrents whose parsed title matches
+    pub async fn search_movie(&self, movie_options: &MovieOptions) -> Result<Vec<Torrent>, Error> {
+        search_movie_title(movie_options, |options| async move {
+            self.search(&options).await
+        })
+        .await
+    }
+
+    pub async fn search_provider(&self, search_options: &SearchOptions) -> ProviderResponse {
+        ProviderResponse {
+            provider: Self::PROVIDER,
+            torrents: self.search(search_options).await,
+        }
+    }
+
+    pub async fn search_movies_provider(&self, movie_options: &MovieOptions) -> ProviderResponse {
+        ProviderResponse {
+            provider: Self::PROVIDER,
+            torrents: self.search_movie(movie_options).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Order, SortColumn};
+    use fake_qbittorrent::{FakeQbittorrent, PASSWORD, USERNAME};
+
+    const HASH: &str = "c9e15763f722f23e98a29decdfae341b98d53056";
+
+    #[test]
+    fn test_format_category() {
+        assert_eq!(QbittorrentSearch::format_category(&Category::All), "all");
+        assert_eq!(
+            QbittorrentSearch::format_category(&Category::Video),
+            "movies"
+        );
+        assert_eq!(QbittorrentSearch::format_category(&Category::Other), "all");
+    }
+
+    #[tokio::test]
+    async fn test_search() {
+        let server = FakeQbittorrent::start().await;
+        server.add_search_result(
+            "The.Matrix.1999.1080p.BluRay.x264",
+            &format!("magnet:?xt=urn:btih:{HASH}&dn=The.Matrix"),
+            2 << 30,
+            42,
+            -1,
+        );
+        server.add_search_result("Inception.2010.720p", "https://fake.invalid/1", 1, 1, 1);
+
+        let client = QbittorrentClient::new(USERNAME, PASSWORD, server.url());
+        let search = QbittorrentSearch::new(QbittorrentSearchOptions::new(client));
+
+        let options = SearchOptions::new(
+            "matrix".into(),
+            Category::Video,
+            SortColumn::Seeders,
+            Order::Descending,
+        );
+        let torrents = search.search(&options).await.unwrap();
+
+        assert_eq!(torrents.len(), 1);
+        let torrent = &torrents[0];
+        assert_eq!(torrent.info_hash, HASH.to_uppercase());
+        assert_eq!(torrent.seeders, 42);
+        assert_eq!(torrent.leechers, 0);
+        assert_eq!(torrent.size, 2 << 30);
+        assert_eq!(
+            torrent.movie_properties.as_ref().unwrap().get_quality(),
+            &crate::Quality::P1080
+        );
+        assert!(server.search_jobs().is_empty());
+    }
+}
@@ -6,6 +6,8 @@ use crate::{
     utils::get_json::get_json,
     Category, Provider, SortColumn, TorrentProvider,
 };
+
+use super::capabilities::ProviderCapabilities;
 use async_trait::async_trait;
 use getset::Getters;
 use lazy_static::lazy_static;
@@ -70,12 +72,12 @@ lazy_static! {
 pub struct Yts {}
 
 impl Yts {
-    fn format_sort(column: &SortColumn) -> &str {
+    fn format_sort(column: &SortColumn) -> Option<&'static str> {
         match column {
-            SortColumn::Added => "date_added",
-            SortColumn::Leechers => "peers",
-            SortColumn::Size => "", // unsupported
-            SortColumn::Seeders => "seeds",
+            SortColumn::Added => Some("date_added"),
+            SortColumn::Leechers => Some("peers"),
+            SortColumn::Size => None,
+            SortColumn::Seeders | SortColumn::Best => Some("seeds"),
         }
     }
 
@@ -85,20 +87,23 @@ impl Yts {
         url.path_segments_mut().unwrap().push("list_movies.json");
 
         url.query_pairs_mut()
-            .append_pair("query_term", search_options.query())
-            .append_pair("sort_by", Self::format_sort(search_options.sort()))
-            .append_pair("order_by", &search_options.order().to_string());
+            .append_pair("query_term", search_options.query());
+
+        if let Some(sort) = Self::format_sort(search_options.sort()) {
+            url.query_pairs_mut()
+                .append_pair("sort_by", sort)
+                .append_pair("order_by", &search_options.order().to_string());
+        }
 
         url
     }
 
-    fn format_movie_url(movie_options: &MovieOptions) -> Url {
+    fn format_movie_url(imdb: &str) -> Url {
         let mut url = YTS_URL.clone();
 
         url.path_segments_mut().unwrap().push("movie_details.json");
 
-        url.query_pairs_mut()
-            .append_pair("imdb_id", movie_options.imdb());
+        url.query_pairs_mut().append_pair("imdb_id", imdb);
 
         url
     }
@@ -122,6 +127,15 @@ impl Yts {
 #[async_trait]
 impl TorrentProvider for Yts {
     const PROVIDER: Provider = Provider::Yts;
+    const HOSTS: &'static [&'static str] = &["yts.mx"];
+
+    fn capabilities() -> ProviderCapabilities {
+        ProviderCapabilities::new(Self::PROVIDER)
+            .with_categories(&[Category::All, Category::Video])
+            .with_sort_columns(&[SortColumn::Added, SortColumn::Leechers, SortColumn::Seeders])
+            .with_imdb_search()
+            .with_paging()
+    }
 
     async fn search(search_options: &SearchOptions, http: &Client) -> Result<Vec<Torrent>, Error> {
         if !matches!(search_options.category(), Category::All | Category::Video) {
@@ -147,7 +161,11 @@ impl TorrentProvider for Yts {
         movie_options: &MovieOptions,
         http: &Client,
     ) -> Result<Vec<Torrent>, Error> {
-        let url = Yts::format_movie_url(movie_options);
+        let Some(imdb) = movie_options.imdb() else {
+            return Self::search_title(movie_options, http).await;
+        };
+
+        let url = Yts::format_movie_url(imdb);
         let json: YtsMovieSearchResponse = get_json(url, http).await?;
 
         let yts_torrents = Yts::movie_to_torrents(json.data.movie);
@@ -166,10 +184,10 @@ mod tests {
 
     #[test]
     fn test_format_sort() {
-        assert_eq!(Yts::format_sort(&SortColumn::Added), "date_added");
-        assert_eq!(Yts::format_sort(&SortColumn::Leechers), "peers");
-        assert_eq!(Yts::format_sort(&SortColumn::Size), "");
-        assert_eq!(Yts::format_sort(&SortColumn::Seeders), "seeds");
+        assert_eq!(Yts::format_sort(&SortColumn::Added), Some("date_added"));
+        assert_eq!(Yts::format_sort(&SortColumn::Leechers), Some("peers"));
+        assert_eq!(Yts::format_sort(&SortColumn::Size), None);
+        assert_eq!(Yts::format_sort(&SortColumn::Seeders), Some("seeds"));
     }
 
     #[test]
@@ -189,15 +207,24 @@ mod tests {
     }
 
     #[test]
-    fn test_format_movie_url() {
-        let movie_options = MovieOptions::new(
-            "tt1234567".into(),
-            None,
-            SortColumn::Seeders,
+    fn test_format_search_url_unsupported_sort() {
+        let search_options = SearchOptions::new(
+            "query".into(),
+            Category::Video,
+            SortColumn::Size,
             Order::Ascending,
         );
 
-        let url = Yts::format_movie_url(&movie_options);
+        let url = Yts::format_search_url(&search_options);
+        assert_eq!(
+            url.as_str(),
+            "https://yts.mx/api/v2/list_movies.json?query_term=query"
+        );
+    }
+
+    #[test]
+    fn test_format_movie_url() {
+        let url = Yts::format_movie_url("tt1234567");
 
         assert_eq!(
             url.as_str(),
@@ -4,9 +4,13 @@ use std::{error, fmt};
 #[derive(Debug)]
 pub enum ErrorKind {
     HttpRequestError(surf::Error),
-    StatusCodeError(surf::Response),
+    StatusCodeError(Box<surf::Response>),
     ParsingError(serde_json::Error),
     ScrapingError,
+    /// The provider was requested but the client has no options for it
+    NotConfigured,
+    #[cfg(feature = "qbittorrent")]
+    QbittorrentError(qbittorrent_api::Error),
 }
 
 #[derive(Debug, Getters)]
@@ -37,6 +41,14 @@ impl From<surf::Error> for Error {
     }
 }
 
+#[cfg(feature = "qbittorrent")]
+impl From<qbittorrent_api::Error> for Error {
+    fn from(qbittorrent_error: qbittorrent_api::Error) -> Self {
+        let message = qbittorrent_error.to_string();
+        Self::new(ErrorKind::QbittorrentError(qbittorrent_error), message)
+    }
+}
+
 impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self.kind() {
@@ -44,6 +56,9 @@ impl error::Error for Error {
             ErrorKind::ParsingError(e) => e.source(),
             ErrorKind::ScrapingError => None,
             ErrorKind::StatusCodeError(_) => None,
+            ErrorKind::NotConfigured => None,
+            #[cfg(feature = "qbittorrent")]
+            ErrorKind::QbittorrentError(_) => None,
         }
     }
 }
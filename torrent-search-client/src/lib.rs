@@ -1,48 +1,89 @@
 mod client;
 mod error;
 mod movie_properties;
+mod ranking;
 mod search_options;
 mod r#static;
 mod torrent;
+mod torrent_client_options;
 mod utils;
 
+use ::utils::proxy::with_proxy;
+use ::utils::rate_limiter::RateLimiter;
 use ::utils::surf_logging::SurfLogging;
 use client::bitsearch::BitSearch;
+pub use client::capabilities::ProviderCapabilities;
 use client::piratebay::PirateBay;
+#[cfg(feature = "qbittorrent")]
+use client::qbittorrent::QbittorrentSearch;
+#[cfg(feature = "qbittorrent")]
+pub use client::qbittorrent::QbittorrentSearchOptions;
 use client::yts::Yts;
 pub use client::Provider;
 use client::ProviderResponse;
 use client::TorrentProvider;
 pub use error::Error;
 pub use error::ErrorKind;
-use futures::future::join_all;
+use futures::future::{join_all, BoxFuture};
 pub use movie_properties::codec::Codec;
 pub use movie_properties::quality::Quality;
 pub use movie_properties::source::Source;
 pub use movie_properties::MovieProperties;
+pub use ranking::{Ranking, RankingWeights, Score};
 pub use search_options::category::Category;
 pub use search_options::invalid_option_error::{InvalidOptionError, SearchOption};
 pub use search_options::movie_options::MovieOptions;
 pub use search_options::order::Order;
 pub use search_options::sort_column::SortColumn;
 pub use search_options::SearchOptions;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::vec;
-use surf::Client;
+use surf::{Client, Config};
 pub use torrent::Torrent;
+pub use torrent_client_options::TorrentClientOptions;
 
 #[derive(Default)]
 pub struct TorrentClient {
     http: Client,
+    provider_http: HashMap<Provider, Client>,
+    #[cfg(feature = "qbittorrent")]
+    qbittorrent: Option<QbittorrentSearch>,
 }
 
 impl TorrentClient {
+    /// The providers searched when none are given, providers which need options are left out without them
+    pub fn providers(&self) -> HashSet<Provider> {
+        let providers = Provider::all();
+
+        #[cfg(feature = "qbittorrent")]
+        let providers = {
+            let mut providers = providers;
+            if self.qbittorrent.is_none() {
+                providers.remove(&Provider::Qbittorrent);
+            }
+            providers
+        };
+
+        providers
+    }
+
     pub async fn search_all(&self, search_options: &SearchOptions) -> Vec<ProviderResponse> {
-        self.search(search_options, &Provider::all()).await
+        self.search(search_options, &self.providers()).await
     }
 
     pub async fn search_movie_all(&self, movie_options: &MovieOptions) -> Vec<ProviderResponse> {
-        self.search_movie(movie_options, &Provider::all()).await
+        self.search_movie(movie_options, &self.providers()).await
+    }
+
+    #[cfg(feature = "qbittorrent")]
+    fn qbittorrent_not_configured() -> ProviderResponse {
+        ProviderResponse {
+            provider: Provider::Qbittorrent,
+            torrents: Err(Error::new(
+                ErrorKind::NotConfigured,
+                "qBittorrent search is not configured",
+            )),
+        }
     }
 
     pub async fn search(
@@ -54,9 +95,9 @@ impl TorrentClient {
             return vec![];
         }
 
-        let mut futures = vec![];
+        let mut futures: Vec<BoxFuture<ProviderResponse>> = vec![];
 
-        let all_providers = Provider::all();
+        let all_providers = self.providers();
 
         let providers = if providers.is_empty() {
             &all_providers
@@ -66,17 +107,37 @@ impl TorrentClient {
 
         for provider in providers {
             match provider {
-                Provider::PirateBay => {
-                    futures.push(PirateBay::search_provider(search_options, &self.http))
-                }
-                Provider::BitSearch => {
-                    futures.push(BitSearch::search_provider(search_options, &self.http))
+                Provider::PirateBay => futures.push(PirateBay::search_provider(
+                    search_options,
+                    self.http(provider),
+                )),
+                Provider::BitSearch => futures.push(BitSearch::search_provider(
+                    search_options,
+                    self.http(provider),
+                )),
+                Provider::Yts => {
+                    futures.push(Yts::search_provider(search_options, self.http(provider)))
                 }
-                Provider::Yts => futures.push(Yts::search_provider(search_options, &self.http)),
+                #[cfg(feature = "qbittorrent")]
+                Provider::Qbittorrent => match &self.qbittorrent {
+                    Some(qbittorrent) => {
+                        futures.push(Box::pin(qbittorrent.search_provider(search_options)))
+                    }
+                    None => futures.push(Box::pin(async { Self::qbittorrent_not_configured() })),
+                },
             }
         }
 
-        join_all(futures).await
+        let mut responses = join_all(futures).await;
+
+        Self::apply_capabilities(
+            &mut responses,
+            Some(search_options.category()),
+            search_options.sort(),
+            search_options.order(),
+        );
+
+        responses
     }
 
     pub async fn search_movie(
@@ -84,13 +145,13 @@ impl TorrentClient {
         movie_options: &MovieOptions,
         providers: &HashSet<Provider>,
     ) -> Vec<ProviderResponse> {
-        if movie_options.imdb().is_empty() {
+        if movie_options.is_empty() {
             return vec![];
         }
 
-        let mut futures = vec![];
+        let mut futures: Vec<BoxFuture<ProviderResponse>> = vec![];
 
-        let all_providers = Provider::all();
+        let all_providers = self.providers();
 
         let providers = if providers.is_empty() {
             &all_providers
@@ -100,24 +161,116 @@ impl TorrentClient {
 
         for provider in providers {
             match provider {
-                Provider::PirateBay => {
-                    futures.push(PirateBay::search_movies_provider(movie_options, &self.http))
-                }
-                Provider::BitSearch => {
-                    futures.push(BitSearch::search_movies_provider(movie_options, &self.http))
+                Provider::PirateBay => futures.push(PirateBay::search_movies_provider(
+                    movie_options,
+                    self.http(provider),
+                )),
+                Provider::BitSearch => futures.push(BitSearch::search_movies_provider(
+                    movie_options,
+                    self.http(provider),
+                )),
+                Provider::Yts => futures.push(Yts::search_movies_provider(
+                    movie_options,
+                    self.http(provider),
+                )),
+                #[cfg(feature = "qbittorrent")]
+                Provider::Qbittorrent => match &self.qbittorrent {
+                    Some(qbittorrent) => {
+                        futures.push(Box::pin(qbittorrent.search_movies_provider(movie_options)))
+                    }
+                    None => futures.push(Box::pin(async { Self::qbittorrent_not_configured() })),
+                },
+            }
+        }
+
+        let mut responses = join_all(futures).await;
+
+        Self::apply_capabilities(
+            &mut responses,
+            None,
+            movie_options.sort(),
+            movie_options.order(),
+        );
+
+        responses
+    }
+
+    /// Filters and sorts the results client-side for providers that cannot do it themselves
+    fn apply_capabilities(
+        responses: &mut [ProviderResponse],
+        category: Option<&Category>,
+        sort: &SortColumn,
+        order: &Order,
+    ) {
+        // Best is ranked after merging the responses, until then order by seeders
+        let sort = match sort {
+            SortColumn::Best => &SortColumn::Seeders,
+            sort => sort,
+        };
+
+        for response in responses.iter_mut() {
+            let capabilities = response.provider.capabilities();
+
+            if let Ok(torrents) = response.torrents.as_mut() {
+                if category.is_some_and(|category| !capabilities.supports_category(category)) {
+                    torrents.clear();
                 }
-                Provider::Yts => {
-                    futures.push(Yts::search_movies_provider(movie_options, &self.http))
+
+                if !capabilities.supports_sort(sort) {
+                    sort.sort(torrents, order);
                 }
             }
         }
-
-        join_all(futures).await
     }
 
     pub fn new() -> Self {
+        Self::with_options(TorrentClientOptions::default())
+    }
+
+    pub fn with_options(options: TorrentClientOptions) -> Self {
+        let mut rate_limiter = RateLimiter::new();
+
+        // The mirrors of a provider share its limit
+        for (provider, limit) in options.rate_limits() {
+            rate_limiter = rate_limiter.with_group_limit(
+                format!("{provider:?}"),
+                provider.hosts().iter().copied(),
+                *limit,
+            );
+        }
+
+        for (host, limit) in options.host_rate_limits() {
+            rate_limiter = rate_limiter.with_host_limit(host, *limit);
+        }
+
+        let create_client = |config: Config| -> Client {
+            let client: Client = config.try_into().unwrap();
+            client.with(rate_limiter.clone()).with(SurfLogging)
+        };
+
+        let provider_http = options
+            .proxies()
+            .iter()
+            .map(|(provider, proxy)| {
+                (
+                    *provider,
+                    create_client(with_proxy(Config::new(), Some(proxy))),
+                )
+            })
+            .collect();
+
         Self {
-            http: Client::new().with(SurfLogging),
+            http: create_client(Config::new()),
+            provider_http,
+            #[cfg(feature = "qbittorrent")]
+            qbittorrent: options
+                .qbittorrent_search()
+                .clone()
+                .map(QbittorrentSearch::new),
         }
     }
+
+    fn http(&self, provider: &Provider) -> &Client {
+        self.provider_http.get(provider).unwrap_or(&self.http)
+    }
 }
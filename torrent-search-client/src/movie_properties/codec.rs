@@ -1,19 +1,18 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use strum_macros::EnumIter;
-#[derive(EnumIter, Debug, Clone, Serialize, Default, PartialEq, Copy, Eq)]
+#[derive(EnumIter, Debug, Clone, Serialize, Deserialize, Default, PartialEq, Copy, Eq)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
-#[cfg_attr(test, derive(serde::Deserialize))]
 pub enum Codec {
     #[default]
     #[serde(rename = "Unknown")]
     Unknown,
     AVC,
     HEVC,
-    #[graphql(name = "XVID")]
+    #[cfg_attr(feature = "graphql", graphql(name = "XVID"))]
     XVid,
 }
 
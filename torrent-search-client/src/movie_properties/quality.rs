@@ -1,11 +1,10 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use strum_macros::EnumIter;
-#[derive(EnumIter, Debug, Clone, Serialize, Default, PartialEq, Copy, Eq)]
+#[derive(EnumIter, Debug, Clone, Serialize, Deserialize, Default, PartialEq, Copy, Eq)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
-#[cfg_attr(test, derive(serde::Deserialize))]
 pub enum Quality {
     #[default]
     Unknown,
@@ -0,0 +1,291 @@
+use crate::{Codec, Provider, Quality, Source, Torrent};
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// How much each component contributes to the `Best` score, a weight of 0 disables the component
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[serde(default)]
+#[get = "pub"]
+pub struct RankingWeights {
+    seeders: f64,
+    quality: f64,
+    source: f64,
+    codec: f64,
+    size: f64,
+    age: f64,
+    providers: f64,
+    trusted: f64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        Self {
+            seeders: 3.0,
+            quality: 2.0,
+            source: 2.0,
+            codec: 0.5,
+            size: 1.0,
+            age: 0.5,
+            providers: 1.0,
+            trusted: 1.0,
+        }
+    }
+}
+
+/// Weights and preferences used to rank torrents, preferences are ordered from most to least preferred
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[serde(default)]
+#[get = "pub"]
+pub struct Ranking {
+    weights: RankingWeights,
+    qualities: Vec<Quality>,
+    sources: Vec<Source>,
+    codecs: Vec<Codec>,
+}
+
+impl Default for Ranking {
+    fn default() -> Self {
+        Self {
+            weights: RankingWeights::default(),
+            qualities: vec![Quality::P1080, Quality::P2160, Quality::P720, Quality::P576],
+            sources: vec![Source::BluRay, Source::WebDL, Source::WebRip, Source::Hdrip],
+            codecs: vec![Codec::HEVC, Codec::AVC],
+        }
+    }
+}
+
+/// The weighted contribution of every component, `total` is the sum of all components
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Getters)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[getset(get = "pub with_prefix")]
+pub struct Score {
+    total: f64,
+    seeders: f64,
+    quality: f64,
+    source: f64,
+    codec: f64,
+    size: f64,
+    age: f64,
+    providers: f64,
+    trusted: f64,
+}
+
+/// Seeder count at which the seeders component is maxed out
+const MAX_SEEDERS: f64 = 1000.0;
+
+const GB: f64 = 1_000_000_000.0;
+
+impl Ranking {
+    /// Calculates and stores the score of every torrent
+    pub fn score_all(&self, torrents: &mut [Torrent]) {
+        let now = Utc::now();
+
+        for torrent in torrents {
+            torrent.score = Some(self.score(torrent, now));
+        }
+    }
+
+    pub fn score(&self, torrent: &Torrent, now: DateTime<Utc>) -> Score {
+        let props = torrent.movie_properties.as_ref();
+        let quality = props.map(|p| *p.get_quality()).unwrap_or_default();
+        let weights = &self.weights;
+
+        let mut score = Score {
+            total: 0.0,
+            seeders: weights.seeders * seeders_score(torrent.seeders),
+            quality: weights.quality * preference_score(&self.qualities, &quality),
+            source: weights.source
+                * props.map_or(0.0, |p| preference_score(&self.sources, p.get_source())),
+            codec: weights.codec
+                * props.map_or(0.0, |p| preference_score(&self.codecs, p.get_codec())),
+            size: weights.size * size_score(torrent.size, &quality),
+            age: weights.age * age_score(&torrent.added, &now),
+            providers: weights.providers * providers_score(torrent.provider.len()),
+            trusted: if torrent.trusted {
+                weights.trusted
+            } else {
+                0.0
+            },
+        };
+
+        score.total = score.seeders
+            + score.quality
+            + score.source
+            + score.codec
+            + score.size
+            + score.age
+            + score.providers
+            + score.trusted;
+
+        score
+    }
+}
+
+/// Logarithmic, so the difference between 0 and 10 seeders matters more than between 500 and 510
+fn seeders_score(seeders: usize) -> f64 {
+    ((seeders as f64).ln_1p() / MAX_SEEDERS.ln_1p()).min(1.0)
+}
+
+fn preference_score<T: PartialEq>(preferences: &[T], value: &T) -> f64 {
+    preferences
+        .iter()
+        .position(|preference| preference == value)
+        .map_or(0.0, |index| 1.0 - index as f64 / preferences.len() as f64)
+}
+
+/// Expected size range in GB of a movie in the given quality
+fn plausible_size(quality: &Quality) -> (f64, f64) {
+    match quality {
+        Quality::P480 => (0.3, 2.0),
+        Quality::P540 | Quality::P576 => (0.5, 3.0),
+        Quality::P720 => (0.7, 6.0),
+        Quality::P1080 => (1.4, 20.0),
+        Quality::P2160 => (4.0, 90.0),
+        Quality::Unknown => (0.3, 90.0),
+    }
+}
+
+/// 1 inside the plausible range, decreasing the further the size is outside of it
+fn size_score(size: u64, quality: &Quality) -> f64 {
+    if size == 0 {
+        return 0.0;
+    }
+
+    let size = size as f64 / GB;
+    let (min, max) = plausible_size(quality);
+
+    if size < min {
+        size / min
+    } else if size > max {
+        max / size
+    } else {
+        1.0
+    }
+}
+
+/// Halves after a year, unknown dates score 0
+fn age_score(added: &DateTime<Utc>, now: &DateTime<Utc>) -> f64 {
+    if added.timestamp_millis() == 0 {
+        return 0.0;
+    }
+
+    let days = (*now - *added).num_days().max(0) as f64;
+
+    1.0 / (1.0 + days / 365.0)
+}
+
+fn providers_score(count: usize) -> f64 {
+    let max = Provider::all().len();
+
+    if max <= 1 {
+        return 0.0;
+    }
+
+    (count.saturating_sub(1) as f64 / (max - 1) as f64).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MovieProperties, Order, SortColumn};
+    use chrono::Duration;
+
+    fn torrent(name: &str, seeders: usize, size: u64) -> Torrent {
+        Torrent {
+            added: Utc::now(),
+            category: String::new(),
+            file_count: 0,
+            id: name.into(),
+            info_hash: name.into(),
+            leechers: 0,
+            name: name.into(),
+            seeders,
+            size,
+            provider: Provider::PirateBay.into(),
+            magnet: String::new(),
+            torrent_url: None,
+            movie_properties: Some(MovieProperties::new(
+                String::new(),
+                Quality::from(name),
+                Codec::from(name),
+                Source::from(name),
+            )),
+            trusted: false,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_component_scores() {
+        assert_eq!(seeders_score(0), 0.0);
+        assert_eq!(seeders_score(1000), 1.0);
+        assert!(seeders_score(10) < seeders_score(100));
+
+        let qualities = [Quality::P1080, Quality::P720];
+        assert_eq!(preference_score(&qualities, &Quality::P1080), 1.0);
+        assert_eq!(preference_score(&qualities, &Quality::P720), 0.5);
+        assert_eq!(preference_score(&qualities, &Quality::P480), 0.0);
+
+        assert_eq!(size_score(0, &Quality::P1080), 0.0);
+        assert_eq!(size_score(2_000_000_000, &Quality::P1080), 1.0);
+        assert_eq!(size_score(700_000_000, &Quality::P1080), 0.5);
+
+        let now = Utc::now();
+        assert_eq!(age_score(&now, &now), 1.0);
+        assert_eq!(age_score(&(now - Duration::days(365)), &now), 0.5);
+        assert_eq!(age_score(&DateTime::default(), &now), 0.0);
+
+        assert_eq!(providers_score(1), 0.0);
+        assert_eq!(providers_score(Provider::all().len()), 1.0);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let ranking = Ranking::default();
+        let mut torrents = vec![
+            torrent("Movie.2020.720p.HDTV.x264", 50, 900_000_000),
+            torrent("Movie.2020.1080p.BluRay.x265", 40, 3_000_000_000),
+            torrent("Movie.2020.1080p.CAM", 5, 100_000_000),
+        ];
+
+        ranking.score_all(&mut torrents);
+
+        let scores: Vec<Score> = torrents.iter().map(|t| t.score.unwrap()).collect();
+        assert!(scores[1].total > scores[0].total);
+        assert!(scores[0].total > scores[2].total);
+
+        let score = scores[1];
+        assert_eq!(score.quality, 2.0);
+        assert_eq!(score.source, 2.0);
+        assert_eq!(score.trusted, 0.0);
+        assert_eq!(
+            score.total,
+            score.seeders
+                + score.quality
+                + score.source
+                + score.codec
+                + score.size
+                + score.age
+                + score.providers
+        );
+    }
+
+    #[test]
+    fn test_sort_best_unscored_last() {
+        let mut torrents = vec![
+            torrent("Movie.2020.720p.HDTV.x264", 50, 900_000_000),
+            torrent("Movie.2020.1080p.BluRay.x265", 40, 3_000_000_000),
+        ];
+        Ranking::default().score_all(&mut torrents);
+        torrents.insert(0, torrent("Movie.2020.1080p.CAM", 5, 100_000_000));
+
+        for order in [Order::Ascending, Order::Descending] {
+            SortColumn::Best.sort(&mut torrents, &order);
+            assert!(torrents[0].score.is_some());
+            assert!(torrents[1].score.is_some());
+            assert!(torrents[2].score.is_none());
+        }
+        assert_eq!(torrents[0].name, "Movie.2020.1080p.BluRay.x265");
+    }
+}
@@ -4,19 +4,58 @@ use getset::Getters;
 #[derive(Getters)]
 #[get = "pub"]
 pub struct MovieOptions {
-    imdb: String,
+    imdb: Option<String>,
+    /// Formatted as `Title (Year)`, used by providers that cannot search by IMDb id
     title: Option<String>,
     sort: SortColumn,
     order: Order,
 }
 
 impl MovieOptions {
-    pub fn new(imdb: String, title: Option<String>, sort: SortColumn, order: Order) -> Self {
+    pub fn new(
+        imdb: Option<String>,
+        title: Option<String>,
+        sort: SortColumn,
+        order: Order,
+    ) -> Self {
         Self {
-            imdb,
-            title,
+            imdb: imdb.filter(|imdb| !imdb.is_empty()),
+            title: title.filter(|title| !title.is_empty()),
             sort,
             order,
         }
     }
+
+    /// The title as a search query, without the parentheses around the year
+    pub fn title_query(&self) -> Option<String> {
+        self.title
+            .as_ref()
+            .map(|title| title.replace(['(', ')'], ""))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.imdb.is_none() && self.title.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_movie_options() {
+        let options = MovieOptions::new(
+            Some(String::new()),
+            Some("The Matrix (1999)".into()),
+            SortColumn::Seeders,
+            Order::Descending,
+        );
+
+        assert_eq!(options.imdb(), &None);
+        assert_eq!(options.title_query(), Some("The Matrix 1999".into()));
+        assert!(!options.is_empty());
+
+        let options = MovieOptions::new(None, None, SortColumn::Seeders, Order::Descending);
+        assert!(options.is_empty());
+    }
 }
@@ -1,5 +1,9 @@
-use super::invalid_option_error::{InvalidOptionError, SearchOption};
-use std::str::FromStr;
+use super::{
+    invalid_option_error::{InvalidOptionError, SearchOption},
+    order::Order,
+};
+use crate::Torrent;
+use std::{cmp::Ordering, str::FromStr};
 
 #[derive(Debug, Default, Clone, Eq, Copy, PartialEq)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
@@ -9,6 +13,8 @@ pub enum SortColumn {
     Added,
     Size,
     Leechers,
+    /// Weighted score calculated by `Ranking`, torrents without a score are ranked last
+    Best,
 }
 
 impl FromStr for SortColumn {
@@ -22,6 +28,7 @@ impl FromStr for SortColumn {
 
             "leechers" => SortColumn::Leechers,
             "seeders" => SortColumn::Seeders,
+            "best" => SortColumn::Best,
 
             _ => Err(InvalidOptionError::new(SearchOption::Sort))?,
         };
@@ -29,3 +36,31 @@ impl FromStr for SortColumn {
         Ok(sort_column)
     }
 }
+
+impl SortColumn {
+    pub fn sort(&self, torrents: &mut [Torrent], order: &Order) {
+        torrents.sort_unstable_by(|a, b| {
+            let ordering = match self {
+                SortColumn::Added => a.added.cmp(&b.added),
+                SortColumn::Leechers => a.leechers.cmp(&b.leechers),
+                SortColumn::Seeders => a.seeders.cmp(&b.seeders),
+                SortColumn::Size => a.size.cmp(&b.size),
+                SortColumn::Best => {
+                    let score = |torrent: &Torrent| torrent.score.map(|score| *score.get_total());
+                    // Unscored torrents go last whatever the order, so they skip the reversal
+                    match (score(a), score(b)) {
+                        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                        (Some(_), None) => return Ordering::Less,
+                        (None, Some(_)) => return Ordering::Greater,
+                        (None, None) => return Ordering::Equal,
+                    }
+                }
+            };
+
+            match order {
+                Order::Ascending => ordering,
+                Order::Descending => ordering.reverse(),
+            }
+        });
+    }
+}
@@ -4,12 +4,24 @@ use crate::{
     client::{piratebay::PirateBayTorrent, yts::YtsTorrent, Provider},
     movie_properties::MovieProperties,
     r#static::trackers::{piratebay::PIRATEBAY_TRACKERS, yts::YTS_TRACKERS},
+    ranking::Score,
     Codec, Quality, Source,
 };
 use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "qbittorrent")]
+use lazy_static::lazy_static;
+#[cfg(feature = "qbittorrent")]
+use qbittorrent_api::SearchResult;
+#[cfg(feature = "qbittorrent")]
+use regex::Regex;
 use serde::Serialize;
 use urlencoding::encode;
 
+#[cfg(feature = "qbittorrent")]
+lazy_static! {
+    static ref MAGNET_INFO_HASH: Regex = Regex::new("(?i)urn:btih:([a-z\\d]+)").unwrap();
+}
+
 #[derive(Serialize, Debug, Clone)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
 pub struct Torrent {
@@ -17,6 +29,7 @@ pub struct Torrent {
     pub category: String,
     pub file_count: usize,
     pub id: String,
+    /// Empty for results only linking to a `.torrent` file, their hash is unknown
     pub info_hash: String,
     pub leechers: usize,
     pub name: String,
@@ -24,7 +37,13 @@ pub struct Torrent {
     pub size: u64,
     pub provider: HashSet<Provider>,
     pub magnet: String,
+    /// The `.torrent` file of results without a magnet link, accepted by `addTorrents` and `trackMovie`
+    pub torrent_url: Option<String>,
     pub movie_properties: Option<MovieProperties>,
+    /// Uploaded by a trusted or vip uploader, or by a curated provider
+    pub trusted: bool,
+    /// Filled in by `Ranking::score_all`
+    pub score: Option<Score>,
 }
 
 impl Torrent {
@@ -63,6 +82,10 @@ impl Torrent {
         if self.magnet.is_empty() {
             self.magnet = other.magnet
         }
+        if self.torrent_url.is_none() {
+            self.torrent_url = other.torrent_url
+        }
+        self.trusted |= other.trusted;
         self.provider.extend(&other.provider)
     }
 }
@@ -99,12 +122,15 @@ impl From<PirateBayTorrent> for Torrent {
             size: value.size().parse().unwrap_or(0),
             provider: Provider::PirateBay.into(),
             magnet: format_magnet(value.info_hash(), value.name(), PIRATEBAY_TRACKERS),
+            torrent_url: None,
             movie_properties: Some(MovieProperties::new(
                 value.imdb().to_owned(),
                 Quality::from(value.name()),
                 Codec::from(value.name()),
                 Source::from(value.name()),
             )),
+            trusted: matches!(value.status().as_str(), "trusted" | "vip"),
+            score: None,
         }
     }
 }
@@ -134,18 +160,72 @@ impl From<YtsTorrent> for Torrent {
             size: torrent.size_bytes().to_owned(),
             provider: Provider::Yts.into(),
             magnet: format_magnet(torrent.hash(), &name, YTS_TRACKERS),
+            torrent_url: None,
             movie_properties: Some(MovieProperties::new(
                 value.imdb().to_owned(),
                 Quality::from(&name),
                 Codec::from(&name),
                 Source::from(&name),
             )),
+            trusted: true,
+            score: None,
 
             name,
         }
     }
 }
 
+/// Plugins report -1 for unknown numbers
+#[cfg(feature = "qbittorrent")]
+fn known(number: i64) -> u64 {
+    number.max(0) as u64
+}
+
+#[cfg(feature = "qbittorrent")]
+impl From<SearchResult> for Torrent {
+    fn from(value: SearchResult) -> Self {
+        let name = value.get_file_name();
+
+        let url = value.get_file_url();
+        let info_hash = MAGNET_INFO_HASH
+            .captures(url)
+            .map(|captures| captures[1].to_uppercase())
+            .unwrap_or_default();
+        let (magnet, torrent_url) = if url.starts_with("magnet:") {
+            (url.to_owned(), None)
+        } else {
+            (String::new(), Some(url.to_owned()))
+        };
+
+        Self {
+            added: value
+                .get_pub_date()
+                .filter(|date| *date > 0)
+                .and_then(|date| Utc.timestamp_opt(date, 0).single())
+                .unwrap_or_default(),
+            category: String::new(),
+            file_count: 0,
+            id: value.get_descr_link().to_owned(),
+            info_hash,
+            leechers: known(*value.get_nb_leechers()) as usize,
+            name: name.to_owned(),
+            seeders: known(*value.get_nb_seeders()) as usize,
+            size: known(*value.get_file_size()),
+            provider: Provider::Qbittorrent.into(),
+            magnet,
+            torrent_url,
+            movie_properties: Some(MovieProperties::new(
+                String::new(),
+                Quality::from(name),
+                Codec::from(name),
+                Source::from(name),
+            )),
+            trusted: false,
+            score: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -165,7 +245,10 @@ mod tests {
             size: 1,
             provider: Provider::PirateBay.into(),
             magnet: "1".into(),
+            torrent_url: None,
             movie_properties: None,
+            trusted: false,
+            score: None,
         };
 
         let torrent2 = Torrent {
@@ -182,12 +265,15 @@ mod tests {
                 .into_iter()
                 .collect(),
             magnet: "2".into(),
+            torrent_url: Some("2".into()),
             movie_properties: Some(MovieProperties::new(
                 "2".into(),
                 Quality::Unknown,
                 Codec::Unknown,
                 Source::Unknown,
             )),
+            trusted: true,
+            score: None,
         };
 
         torrent1.merge(torrent2);
@@ -205,6 +291,8 @@ mod tests {
                 .collect()
         );
         assert_eq!(torrent1.magnet, "1");
+        assert_eq!(torrent1.torrent_url.as_deref(), Some("2"));
+        assert!(torrent1.trusted);
         assert_eq!(
             torrent1.movie_properties.unwrap(),
             MovieProperties::new(
@@ -215,4 +303,35 @@ mod tests {
             )
         );
     }
+
+    #[cfg(feature = "qbittorrent")]
+    #[test]
+    fn test_from_search_result() {
+        let result = |file_url: &str| -> SearchResult {
+            serde_json::from_value(serde_json::json!({
+                "descrLink": "https://example.com/1",
+                "fileName": "The.Matrix.1999.1080p.BluRay.x264",
+                "fileSize": -1,
+                "fileUrl": file_url,
+                "nbLeechers": 1,
+                "nbSeeders": -1,
+                "siteUrl": "https://example.com",
+            }))
+            .unwrap()
+        };
+
+        let torrent = Torrent::from(result("magnet:?xt=urn:btih:abc123&dn=The.Matrix"));
+        assert_eq!(torrent.info_hash, "ABC123");
+        assert_eq!(torrent.magnet, "magnet:?xt=urn:btih:abc123&dn=The.Matrix");
+        assert_eq!(torrent.torrent_url, None);
+        assert_eq!(torrent.seeders, 0);
+
+        let torrent = Torrent::from(result("https://example.com/1.torrent"));
+        assert!(torrent.info_hash.is_empty());
+        assert!(torrent.magnet.is_empty());
+        assert_eq!(
+            torrent.torrent_url.as_deref(),
+            Some("https://example.com/1.torrent")
+        );
+    }
 }
@@ -0,0 +1,36 @@
+#[cfg(feature = "qbittorrent")]
+use crate::client::qbittorrent::QbittorrentSearchOptions;
+use crate::Provider;
+use getset::{Getters, Setters};
+use std::collections::HashMap;
+use utils::{proxy::Proxy, rate_limiter::RateLimit};
+
+#[derive(Debug, Default, Clone, Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct TorrentClientOptions {
+    /// Rate limit applied to every host of a provider
+    rate_limits: HashMap<Provider, RateLimit>,
+    /// Rate limit for a single (mirror) host, takes precedence over `rate_limits`
+    host_rate_limits: HashMap<String, RateLimit>,
+    /// Proxy used for every request to a provider, providers without one are reached directly
+    proxies: HashMap<Provider, Proxy>,
+    /// Enables the qBittorrent provider
+    #[cfg(feature = "qbittorrent")]
+    #[getset(skip)]
+    qbittorrent_search: Option<QbittorrentSearchOptions>,
+}
+
+#[cfg(feature = "qbittorrent")]
+impl TorrentClientOptions {
+    pub fn qbittorrent_search(&self) -> &Option<QbittorrentSearchOptions> {
+        &self.qbittorrent_search
+    }
+
+    pub fn set_qbittorrent_search(
+        &mut self,
+        qbittorrent_search: Option<QbittorrentSearchOptions>,
+    ) -> &mut Self {
+        self.qbittorrent_search = qbittorrent_search;
+        self
+    }
+}
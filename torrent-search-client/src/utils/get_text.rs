@@ -8,7 +8,7 @@ pub async fn get_text(url: Url, http: &Client) -> Result<String, Error> {
     let status = response.status();
     if !status.is_success() {
         return Err(Error::new(
-            ErrorKind::StatusCodeError(response),
+            ErrorKind::StatusCodeError(Box::new(response)),
             format!("Request to \"{url}\" failed with {status}"),
         ));
     }
@@ -0,0 +1,5 @@
+mod rpc;
+pub mod torrent_add;
+pub mod torrent_get;
+pub mod torrent_remove;
+pub mod torrent_set_labels;
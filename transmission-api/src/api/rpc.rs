@@ -0,0 +1,100 @@
+use crate::{
+    models::rpc::{RpcRequest, RpcResponse},
+    Error, ErrorKind, TransmissionClient,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use surf::StatusCode;
+
+impl TransmissionClient {
+    /// Calls `method`, failing when Transmission does not answer with `success`
+    pub(crate) async fn rpc<A: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        arguments: A,
+    ) -> Result<R, Error> {
+        let mut res = self
+            .http
+            .post(&self.url)
+            .body_json(&RpcRequest::new(method, arguments))?
+            .send()
+            .await?;
+
+        match res.status() {
+            StatusCode::Unauthorized => {
+                return Err(Error::new(ErrorKind::IncorrectLogin, "Incorrect login"))
+            }
+            status if !status.is_success() => {
+                return Err(Error::new(
+                    ErrorKind::RpcError,
+                    format!("Transmission answered {status}"),
+                ))
+            }
+            _ => {}
+        }
+
+        let response: RpcResponse<Value> = res.body_json().await?;
+
+        if response.result != "success" {
+            return Err(Error::new(ErrorKind::RpcError, response.result));
+        }
+
+        Ok(serde_json::from_value(
+            response.arguments.unwrap_or(Value::Null),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Answers every request on a local port with `response`
+    async fn stand_in(response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/transmission/rpc", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).await;
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_rpc_unauthorized() {
+        let url = stand_in(
+            "HTTP/1.1 401 Unauthorized\r\nContent-Length: 12\r\nConnection: close\r\n\r\nUnauthorized",
+        )
+        .await;
+
+        let client = TransmissionClient::new(url.as_str(), None);
+        let error = client.rpc::<_, Value>("session-get", ()).await.unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorKind::IncorrectLogin));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_result() {
+        let body = r#"{"result":"no such method","arguments":{}}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let url = stand_in(response.leak()).await;
+
+        let client = TransmissionClient::new(url.as_str(), None);
+        let error = client.rpc::<_, Value>("session-get", ()).await.unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorKind::RpcError));
+        assert_eq!(error.message(), "no such method");
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    models::{
+        add_torrent_options::{AddTorrentArguments, AddTorrentOptions},
+        torrent_added::{TorrentAddResponse, TorrentAdded},
+    },
+    Error, ErrorKind, TransmissionClient,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+impl TransmissionClient {
+    async fn torrent_add(&self, arguments: AddTorrentArguments<'_>) -> Result<TorrentAdded, Error> {
+        let labels = arguments.options.labels().to_vec();
+
+        let response: TorrentAddResponse = self.rpc("torrent-add", arguments).await?;
+
+        let torrent = response.added.or(response.duplicate).ok_or(Error::new(
+            ErrorKind::RpcError,
+            "Transmission did not return the added torrent",
+        ))?;
+
+        if !labels.is_empty() {
+            self.set_labels(vec![torrent.get_hash().to_owned()], labels)
+                .await?;
+        }
+
+        Ok(torrent)
+    }
+
+    /// Adds a magnet or a url to a `.torrent` file
+    pub async fn add_torrent(
+        &self,
+        url: String,
+        options: &AddTorrentOptions,
+    ) -> Result<TorrentAdded, Error> {
+        self.torrent_add(AddTorrentArguments {
+            filename: Some(url),
+            metainfo: None,
+            options,
+        })
+        .await
+    }
+
+    /// Adds the content of a `.torrent` file
+    pub async fn upload_torrent(
+        &self,
+        content: &[u8],
+        options: &AddTorrentOptions,
+    ) -> Result<TorrentAdded, Error> {
+        self.torrent_add(AddTorrentArguments {
+            filename: None,
+            metainfo: Some(STANDARD.encode(content)),
+            options,
+        })
+        .await
+    }
+}
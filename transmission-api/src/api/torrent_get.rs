@@ -0,0 +1,45 @@
+use crate::{
+    models::torrent::{Torrent, Torrents, TORRENT_FIELDS},
+    Error, ErrorKind, TransmissionClient,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct TorrentGetArguments<'a> {
+    fields: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+}
+
+impl TransmissionClient {
+    pub async fn torrents(&self) -> Result<Vec<Torrent>, Error> {
+        let response: Torrents = self
+            .rpc(
+                "torrent-get",
+                TorrentGetArguments {
+                    fields: TORRENT_FIELDS,
+                    ids: None,
+                },
+            )
+            .await?;
+
+        Ok(response.torrents)
+    }
+
+    pub async fn torrent(&self, hash: &str) -> Result<Torrent, Error> {
+        let response: Torrents = self
+            .rpc(
+                "torrent-get",
+                TorrentGetArguments {
+                    fields: TORRENT_FIELDS,
+                    ids: Some(vec![hash.to_string()]),
+                },
+            )
+            .await?;
+
+        response.torrents.into_iter().next().ok_or(Error::new(
+            ErrorKind::TorrentNotFound,
+            format!("Torrent {hash} not found"),
+        ))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{Error, TransmissionClient};
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct TorrentRemoveArguments {
+    ids: Vec<String>,
+    delete_local_data: bool,
+}
+
+impl TransmissionClient {
+    pub async fn remove_torrents(
+        &self,
+        hashes: Vec<String>,
+        delete_local_data: bool,
+    ) -> Result<(), Error> {
+        self.rpc::<_, serde_json::Value>(
+            "torrent-remove",
+            TorrentRemoveArguments {
+                ids: hashes,
+                delete_local_data,
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{Error, TransmissionClient};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct TorrentSetLabelsArguments {
+    ids: Vec<String>,
+    labels: Vec<String>,
+}
+
+impl TransmissionClient {
+    /// Replaces the labels of the torrents, labels cannot contain commas
+    pub async fn set_labels(&self, hashes: Vec<String>, labels: Vec<String>) -> Result<(), Error> {
+        self.rpc::<_, serde_json::Value>(
+            "torrent-set",
+            TorrentSetLabelsArguments {
+                ids: hashes,
+                labels,
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+}
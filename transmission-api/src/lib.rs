@@ -0,0 +1,53 @@
+mod api;
+mod error;
+mod models;
+mod session_middleware;
+pub use error::Error;
+pub use error::ErrorKind;
+pub use models::add_torrent_options::AddTorrentOptions;
+pub use models::torrent::Torrent;
+pub use models::torrent_added::TorrentAdded;
+pub use models::torrent_status::TorrentStatus;
+use session_middleware::SessionMiddleware;
+use std::fmt::Debug;
+use surf::{Client, Config, Url};
+use utils::proxy::{with_proxy, Proxy};
+use utils::surf_logging::SurfLogging;
+
+/// Cheap to clone, clones share the same session
+#[derive(Clone)]
+pub struct TransmissionClient {
+    http: Client,
+    url: Url,
+}
+
+impl TransmissionClient {
+    /// `url` is the full RPC url, e.g. `http://localhost:9091/transmission/rpc`
+    pub fn new<U: TryInto<Url>>(url: U, credentials: Option<(String, String)>) -> Self
+    where
+        U::Error: Debug,
+    {
+        Self::with_proxy(url, credentials, None)
+    }
+
+    /// Creates a client which reaches Transmission through `proxy`
+    pub fn with_proxy<U: TryInto<Url>>(
+        url: U,
+        credentials: Option<(String, String)>,
+        proxy: Option<&Proxy>,
+    ) -> Self
+    where
+        U::Error: Debug,
+    {
+        let url: Url = url.try_into().expect("Invalid url");
+
+        let client: Client = with_proxy(Config::new(), proxy).try_into().unwrap();
+
+        Self {
+            http: client
+                .with(SessionMiddleware::new(credentials))
+                .with(SurfLogging),
+            url,
+        }
+    }
+}
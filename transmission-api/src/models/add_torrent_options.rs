@@ -0,0 +1,32 @@
+use getset::Setters;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Default, Setters, Clone)]
+#[serde(rename_all = "kebab-case")]
+#[set = "pub"]
+pub struct AddTorrentOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paused: Option<bool>,
+    /// Set with `torrent-set` after adding, `torrent-add` only supports labels since Transmission 4
+    #[serde(skip)]
+    labels: Vec<String>,
+}
+
+impl AddTorrentOptions {
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+}
+
+/// A magnet or url in `filename`, or the base64 encoded content of a `.torrent` file in `metainfo`
+#[derive(Debug, Serialize)]
+pub(crate) struct AddTorrentArguments<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metainfo: Option<String>,
+    #[serde(flatten)]
+    pub options: &'a AddTorrentOptions,
+}
@@ -0,0 +1,5 @@
+pub mod add_torrent_options;
+pub mod rpc;
+pub mod torrent;
+pub mod torrent_added;
+pub mod torrent_status;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct RpcRequest<'a, A> {
+    method: &'a str,
+    arguments: A,
+}
+
+impl<'a, A> RpcRequest<'a, A> {
+    pub fn new(method: &'a str, arguments: A) -> Self {
+        Self { method, arguments }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RpcResponse<R> {
+    /// `success`, or the error otherwise
+    pub result: String,
+    pub arguments: Option<R>,
+}
@@ -0,0 +1,99 @@
+use super::torrent_status::TorrentStatus;
+use chrono::{serde::ts_seconds::deserialize as ts_seconds, DateTime, Utc};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// The fields requested by `torrent-get`, they must match the fields of `Torrent`
+pub(crate) const TORRENT_FIELDS: &[&str] = &[
+    "id",
+    "hashString",
+    "name",
+    "status",
+    "error",
+    "errorString",
+    "percentDone",
+    "eta",
+    "downloadDir",
+    "labels",
+    "totalSize",
+    "sizeWhenDone",
+    "leftUntilDone",
+    "rateDownload",
+    "rateUpload",
+    "uploadRatio",
+    "addedDate",
+    "doneDate",
+    "isFinished",
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+#[cfg_attr(feature = "graphql", graphql(name = "TransmissionTorrent"))]
+#[get = "pub with_prefix"]
+pub struct Torrent {
+    id: i64,
+    #[serde(rename = "hashString")]
+    hash: String,
+    name: String,
+    status: TorrentStatus,
+    /// `0` when there is no error
+    error: i64,
+    error_string: String,
+    percent_done: f64,
+    /// In seconds, `-1` when not available and `-2` when unknown
+    eta: i64,
+    download_dir: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    total_size: u64,
+    size_when_done: u64,
+    left_until_done: u64,
+    /// Speeds are in bytes per second
+    rate_download: u64,
+    rate_upload: u64,
+    upload_ratio: f64,
+    #[serde(deserialize_with = "ts_seconds")]
+    added_date: DateTime<Utc>,
+    #[serde(deserialize_with = "ts_seconds")]
+    done_date: DateTime<Utc>,
+    is_finished: bool,
+}
+
+impl Torrent {
+    /// The path of the file, or of the folder for torrents with several files
+    pub fn content_path(&self) -> String {
+        format!("{}/{}", self.download_dir.trim_end_matches('/'), self.name)
+    }
+
+    pub fn has_error(&self) -> bool {
+        self.error != 0
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Torrents {
+    pub torrents: Vec<Torrent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let torrent: Torrent = serde_json::from_str(
+            r#"{
+                "id": 1, "hashString": "abc", "name": "Movie", "status": 4, "error": 0, "errorString": "",
+                "percentDone": 0.5, "eta": -1, "downloadDir": "/downloads/", "labels": ["movies"],
+                "totalSize": 100, "sizeWhenDone": 100, "leftUntilDone": 50, "rateDownload": 10, "rateUpload": 0,
+                "uploadRatio": 0, "addedDate": 1700000000, "doneDate": 0, "isFinished": false
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(torrent.status, TorrentStatus::Download);
+        assert_eq!(torrent.content_path(), "/downloads/Movie");
+        assert_eq!(torrent.labels, vec!["movies"]);
+    }
+}
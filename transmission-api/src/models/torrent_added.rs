@@ -0,0 +1,21 @@
+use getset::Getters;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[get = "pub with_prefix"]
+pub struct TorrentAdded {
+    id: i64,
+    name: String,
+    #[serde(rename = "hashString")]
+    hash: String,
+}
+
+/// Adding a torrent which already exists succeeds with `torrent-duplicate`
+#[derive(Deserialize, Debug)]
+pub(crate) struct TorrentAddResponse {
+    #[serde(rename = "torrent-added")]
+    pub added: Option<TorrentAdded>,
+    #[serde(rename = "torrent-duplicate")]
+    pub duplicate: Option<TorrentAdded>,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(from = "u8", into = "u8")]
+pub enum TorrentStatus {
+    #[default]
+    Stopped,
+    /// Queued to verify the local data
+    CheckWait,
+    Check,
+    /// Queued to download
+    DownloadWait,
+    Download,
+    /// Queued to seed
+    SeedWait,
+    Seed,
+}
+
+impl From<u8> for TorrentStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            1 => TorrentStatus::CheckWait,
+            2 => TorrentStatus::Check,
+            3 => TorrentStatus::DownloadWait,
+            4 => TorrentStatus::Download,
+            5 => TorrentStatus::SeedWait,
+            6 => TorrentStatus::Seed,
+            _ => TorrentStatus::Stopped,
+        }
+    }
+}
+
+impl From<TorrentStatus> for u8 {
+    fn from(status: TorrentStatus) -> Self {
+        match status {
+            TorrentStatus::Stopped => 0,
+            TorrentStatus::CheckWait => 1,
+            TorrentStatus::Check => 2,
+            TorrentStatus::DownloadWait => 3,
+            TorrentStatus::Download => 4,
+            TorrentStatus::SeedWait => 5,
+            TorrentStatus::Seed => 6,
+        }
+    }
+}
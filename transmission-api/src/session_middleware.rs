@@ -0,0 +1,80 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::debug;
+use surf::{
+    middleware::{Middleware, Next},
+    Body, Client, Request, Response, Result, StatusCode,
+};
+use tokio::sync::Mutex;
+
+const SESSION_ID_HEADER: &str = "X-Transmission-Session-Id";
+
+/// Sends the CSRF session id Transmission requires, and basic auth when credentials are set
+pub struct SessionMiddleware {
+    session_id: Mutex<Option<String>>,
+    authorization: Option<String>,
+}
+
+impl SessionMiddleware {
+    pub fn new(credentials: Option<(String, String)>) -> Self {
+        Self {
+            session_id: Mutex::new(None),
+            authorization: credentials.map(|(username, password)| {
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{username}:{password}"))
+                )
+            }),
+        }
+    }
+
+    /// Reads the body into memory so the request can be sent a second time
+    async fn buffer_body(req: &mut Request) -> Result<()> {
+        let body = req.take_body();
+        let mime = body.mime().clone();
+
+        let mut buffered = Body::from_bytes(body.into_bytes().await?);
+        buffered.set_mime(mime);
+        req.set_body(buffered);
+
+        Ok(())
+    }
+
+    async fn prepare(&self, req: &mut Request) {
+        if let Some(authorization) = &self.authorization {
+            req.insert_header("Authorization", authorization.as_str());
+        }
+
+        if let Some(session_id) = self.session_id.lock().await.as_deref() {
+            req.insert_header(SESSION_ID_HEADER, session_id);
+        }
+    }
+}
+
+#[surf::utils::async_trait]
+impl Middleware for SessionMiddleware {
+    async fn handle(&self, mut req: Request, client: Client, next: Next<'_>) -> Result<Response> {
+        Self::buffer_body(&mut req).await?;
+        let mut retry = req.clone();
+
+        self.prepare(&mut req).await;
+        let res = next.run(req, client.clone()).await?;
+
+        if res.status() != StatusCode::Conflict {
+            return Ok(res);
+        }
+
+        // Transmission answers 409 with a new session id when it is missing or outdated
+        let Some(session_id) = res
+            .header(SESSION_ID_HEADER)
+            .map(|id| id.as_str().to_string())
+        else {
+            return Ok(res);
+        };
+
+        debug!("Transmission session id changed");
+        *self.session_id.lock().await = Some(session_id);
+
+        self.prepare(&mut retry).await;
+        next.run(retry, client).await
+    }
+}
@@ -1,2 +1,4 @@
 pub mod magnet;
+pub mod proxy;
+pub mod rate_limiter;
 pub mod surf_logging;
@@ -1,5 +1,6 @@
 use getset::{Getters, Setters};
 use multimap::MultiMap;
+use percent_encoding::percent_decode_str;
 use url::Url;
 
 #[derive(Getters, Debug, Setters)]
@@ -45,6 +46,24 @@ impl Magnet {
     }
 }
 
+/// The name of the torrent `url` links to, the display name of a magnet link or the file name of a `.torrent` url
+pub fn torrent_name(url: &str) -> Result<String, String> {
+    let parsed = Url::parse(url).map_err(|e| e.to_string())?;
+
+    match parsed.scheme() {
+        "magnet" => Ok(Magnet::from_url(url)?.display_name),
+        "http" | "https" => Ok(parsed
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .map(|file| {
+                let file = percent_decode_str(file).decode_utf8_lossy();
+                file.trim_end_matches(".torrent").to_owned()
+            })
+            .unwrap_or_default()),
+        _ => Err("Not a magnet link or a torrent url".into()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +120,15 @@ mod tests {
         .unwrap();
         assert_eq!(magnet.trackers(), &["udp://test.com", "udp://test2.com"]);
     }
+
+    #[test]
+    fn test_torrent_name() {
+        assert_eq!(torrent_name(TEST_MAGNET).unwrap(), "Test");
+        assert_eq!(
+            torrent_name("https://example.com/dl/The%20Matrix%201080p.torrent?key=1").unwrap(),
+            "The Matrix 1080p"
+        );
+        assert!(torrent_name("ftp://example.com/a.torrent").is_err());
+        assert!(torrent_name("not a url").is_err());
+    }
 }
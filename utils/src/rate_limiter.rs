@@ -0,0 +1,237 @@
+use getset::Getters;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use surf::middleware::{Middleware, Next};
+use surf::{Client, Request, Response};
+use tokio::time::sleep;
+
+/// Allows `requests` requests every `per_seconds` seconds, bursting up to `requests` at once
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct RateLimit {
+    requests: u32,
+    per_seconds: f64,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, per_seconds: f64) -> Self {
+        Self {
+            requests,
+            per_seconds,
+        }
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.requests == 0 || self.per_seconds <= 0.0
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_rate: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: &RateLimit) -> Self {
+        let capacity = f64::from(limit.requests);
+
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_rate: capacity / limit.per_seconds,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes a token from the bucket, returning how long the caller has to wait before using it.
+    ///
+    /// The token count is allowed to go negative, so concurrent callers queue up behind each other
+    /// instead of all waking up at the same time.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+        self.tokens -= 1.0;
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_rate)
+        }
+    }
+}
+
+/// Token bucket rate limiter keyed by host or group of hosts, which can be shared between multiple surf clients.
+///
+/// Requests exceeding the limit are delayed rather than rejected.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    default_limit: Option<RateLimit>,
+    host_limits: HashMap<String, RateLimit>,
+    /// The group each host belongs to, with the limit its hosts share
+    host_groups: HashMap<String, (String, RateLimit)>,
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies `limit` to every host that has no limit of its own
+    pub fn with_default_limit(mut self, limit: RateLimit) -> Self {
+        self.default_limit = Some(limit);
+        self
+    }
+
+    pub fn with_host_limit<S: Into<String>>(mut self, host: S, limit: RateLimit) -> Self {
+        self.host_limits.insert(host.into(), limit);
+        self
+    }
+
+    /// Applies `limit` to all of `hosts` together, e.g. the mirrors of a site.
+    ///
+    /// A host with a limit of its own keeps using it.
+    pub fn with_group_limit<S, H>(mut self, group: S, hosts: H, limit: RateLimit) -> Self
+    where
+        S: Into<String>,
+        H: IntoIterator,
+        H::Item: Into<String>,
+    {
+        let group = group.into();
+        for host in hosts {
+            self.host_groups.insert(host.into(), (group.clone(), limit));
+        }
+        self
+    }
+
+    /// The key of the bucket `host` takes its tokens from, and the limit of that bucket
+    fn limit<'a>(&'a self, host: &'a str) -> Option<(&'a str, &'a RateLimit)> {
+        let limit = match (self.host_limits.get(host), self.host_groups.get(host)) {
+            (Some(limit), _) => Some((host, limit)),
+            (None, Some((group, limit))) => Some((group.as_str(), limit)),
+            (None, None) => self.default_limit.as_ref().map(|limit| (host, limit)),
+        };
+
+        limit.filter(|(_, limit)| !limit.is_unlimited())
+    }
+
+    /// Waits until a request to `host` is allowed, returning how long it was queued
+    pub async fn acquire(&self, host: &str) -> Duration {
+        let Some((key, limit)) = self.limit(host) else {
+            return Duration::ZERO;
+        };
+
+        let wait = self
+            .buckets
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::new(limit))
+            .reserve(Instant::now());
+
+        if !wait.is_zero() {
+            debug!("Rate limited \"{host}\", queued for {}ms", wait.as_millis());
+            sleep(wait).await;
+        }
+
+        wait
+    }
+}
+
+#[surf::utils::async_trait]
+impl Middleware for RateLimiter {
+    async fn handle(&self, req: Request, client: Client, next: Next<'_>) -> surf::Result<Response> {
+        if let Some(host) = req.url().host_str() {
+            self.acquire(host).await;
+        }
+
+        next.run(req, client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_burst() {
+        let mut bucket = TokenBucket::new(&RateLimit::new(2, 1.0));
+        let now = bucket.last_refill;
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(now), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_token_bucket_refill() {
+        let mut bucket = TokenBucket::new(&RateLimit::new(1, 1.0));
+        let now = bucket.last_refill;
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(
+            bucket.reserve(now + Duration::from_millis(250)),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            bucket.reserve(now + Duration::from_secs(10)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_host_limits() {
+        let limiter = RateLimiter::new()
+            .with_default_limit(RateLimit::new(1, 1.0))
+            .with_host_limit("example.com", RateLimit::new(5, 1.0))
+            .with_host_limit("unlimited.com", RateLimit::new(0, 1.0));
+
+        assert_eq!(
+            limiter.limit("example.com"),
+            Some(("example.com", &RateLimit::new(5, 1.0)))
+        );
+        assert_eq!(
+            limiter.limit("other.com"),
+            Some(("other.com", &RateLimit::new(1, 1.0)))
+        );
+        assert_eq!(limiter.limit("unlimited.com"), None);
+        assert_eq!(RateLimiter::new().limit("example.com"), None);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_queues() {
+        let limiter = RateLimiter::new().with_host_limit("example.com", RateLimit::new(1, 0.05));
+
+        assert_eq!(limiter.acquire("example.com").await, Duration::ZERO);
+        assert!(limiter.acquire("example.com").await > Duration::ZERO);
+        assert_eq!(limiter.acquire("other.com").await, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_group_shares_bucket() {
+        let limiter = RateLimiter::new()
+            .with_group_limit(
+                "mirrors",
+                ["a.example.com", "b.example.com", "c.example.com"],
+                RateLimit::new(1, 0.05),
+            )
+            .with_host_limit("c.example.com", RateLimit::new(1, 0.05));
+
+        assert_eq!(limiter.acquire("a.example.com").await, Duration::ZERO);
+        assert!(limiter.acquire("b.example.com").await > Duration::ZERO);
+        assert_eq!(limiter.acquire("c.example.com").await, Duration::ZERO);
+    }
+}